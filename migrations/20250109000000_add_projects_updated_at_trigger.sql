@@ -0,0 +1,17 @@
+-- Migration: Maintain projects.updated_at at the database layer
+-- Any UPDATE on a project row bumps updated_at automatically, so callers no longer
+-- need to remember to set it (e.g. association-only changes that just touch the row).
+--
+-- The WHEN clause only fires when the statement left updated_at untouched, which:
+--   - respects explicit sets (imports, backfills, tests seeding old timestamps)
+--   - prevents the trigger's own UPDATE from bumping the row a second time
+
+CREATE TRIGGER IF NOT EXISTS trg_projects_updated_at
+AFTER UPDATE ON projects
+FOR EACH ROW
+WHEN NEW.updated_at = OLD.updated_at
+BEGIN
+    UPDATE projects
+    SET updated_at = strftime('%Y-%m-%dT%H:%M:%f+00:00', 'now')
+    WHERE id = NEW.id;
+END;
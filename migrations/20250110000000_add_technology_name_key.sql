@@ -0,0 +1,43 @@
+-- Migration: Case-insensitive storage key for technology names
+-- `name` keeps the casing the client sent for display, while `name_key` holds the
+-- lowercased form used for uniqueness and lookups, so "React" and "react" collide.
+-- Keys are folded with SQLite's ASCII-only lower(), the same folding as
+-- `Technology::name_key`.
+
+-- Existing case variants ("React" and "react") would break the unique index: the
+-- oldest row of each group is kept and the others' project links move onto it
+CREATE TEMP TABLE technology_merges AS
+SELECT t.id AS duplicate_id,
+       (SELECT k.id FROM technologies k
+        WHERE lower(k.name) = lower(t.name)
+        ORDER BY k.created_at, k.id
+        LIMIT 1) AS keeper_id
+FROM technologies t;
+
+DELETE FROM technology_merges WHERE duplicate_id = keeper_id;
+
+INSERT OR IGNORE INTO project_technologies (project_id, technology_id, created_at)
+SELECT pt.project_id, m.keeper_id, pt.created_at
+FROM project_technologies pt
+JOIN technology_merges m ON pt.technology_id = m.duplicate_id;
+
+DELETE FROM project_technologies WHERE technology_id IN (SELECT duplicate_id FROM technology_merges);
+DELETE FROM technologies WHERE id IN (SELECT duplicate_id FROM technology_merges);
+
+DROP TABLE technology_merges;
+
+ALTER TABLE technologies ADD COLUMN name_key TEXT;
+
+-- Backfill existing rows
+UPDATE technologies SET name_key = lower(name);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_technologies_name_key ON technologies(name_key);
+
+-- Rows inserted without a key (raw SQL, seed scripts) still get one
+CREATE TRIGGER IF NOT EXISTS trg_technologies_name_key
+AFTER INSERT ON technologies
+FOR EACH ROW
+WHEN NEW.name_key IS NULL
+BEGIN
+    UPDATE technologies SET name_key = lower(NEW.name) WHERE id = NEW.id;
+END;
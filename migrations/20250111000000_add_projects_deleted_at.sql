@@ -0,0 +1,8 @@
+-- Migration: Soft delete for projects
+-- DELETE /projects/{id} now stamps deleted_at instead of removing the row, so the
+-- project and its associations stay around for auditing and can be restored.
+-- Live projects are the rows where deleted_at IS NULL.
+
+ALTER TABLE projects ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_projects_deleted_at ON projects(deleted_at);
@@ -0,0 +1,43 @@
+-- Migration: Full-text search index for projects
+-- `GET /projects?search=...&sort=relevance` matches against this FTS5 table and
+-- ranks hits with bm25() instead of a LIKE substring scan.
+--
+-- The index stores its own copy of name/description keyed by project_id rather than
+-- using external content on the rowid: projects has a TEXT primary key, so its rowid
+-- is not stable across VACUUM. Triggers keep the copy in sync; the update trigger only
+-- rewrites the index when the text actually changed, since PUT /projects sets every
+-- column and concurrent no-op rewrites would otherwise contend on the FTS tables.
+
+CREATE VIRTUAL TABLE IF NOT EXISTS projects_fts USING fts5(
+    project_id UNINDEXED,
+    name,
+    description
+);
+
+INSERT INTO projects_fts (project_id, name, description)
+SELECT id, name, description FROM projects;
+
+CREATE TRIGGER IF NOT EXISTS trg_projects_fts_insert
+AFTER INSERT ON projects
+FOR EACH ROW
+BEGIN
+    INSERT INTO projects_fts (project_id, name, description)
+    VALUES (NEW.id, NEW.name, NEW.description);
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_projects_fts_update
+AFTER UPDATE OF name, description ON projects
+FOR EACH ROW
+WHEN NEW.name IS NOT OLD.name OR NEW.description IS NOT OLD.description
+BEGIN
+    UPDATE projects_fts
+    SET name = NEW.name, description = NEW.description
+    WHERE project_id = OLD.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_projects_fts_delete
+AFTER DELETE ON projects
+FOR EACH ROW
+BEGIN
+    DELETE FROM projects_fts WHERE project_id = OLD.id;
+END;
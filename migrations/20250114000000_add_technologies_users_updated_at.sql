@@ -0,0 +1,27 @@
+-- Migration: Track when technologies and users last changed
+-- Projects already carry updated_at; technologies and users now do too so clients
+-- can tell when those rows were modified.
+
+ALTER TABLE technologies ADD COLUMN updated_at TEXT;
+ALTER TABLE users ADD COLUMN updated_at TEXT;
+
+-- Backfill existing rows: nothing has changed since they were created
+UPDATE technologies SET updated_at = created_at;
+UPDATE users SET updated_at = created_at;
+
+-- Rows inserted without a timestamp (raw SQL, seed scripts) start out unchanged
+CREATE TRIGGER IF NOT EXISTS trg_technologies_updated_at_default
+AFTER INSERT ON technologies
+FOR EACH ROW
+WHEN NEW.updated_at IS NULL
+BEGIN
+    UPDATE technologies SET updated_at = NEW.created_at WHERE id = NEW.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_users_updated_at_default
+AFTER INSERT ON users
+FOR EACH ROW
+WHEN NEW.updated_at IS NULL
+BEGIN
+    UPDATE users SET updated_at = NEW.created_at WHERE id = NEW.id;
+END;
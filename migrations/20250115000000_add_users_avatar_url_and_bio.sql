@@ -0,0 +1,5 @@
+-- Migration: Optional profile fields for users
+-- Both stay NULL unless a client sends them, so existing users and clients are unaffected.
+
+ALTER TABLE users ADD COLUMN avatar_url TEXT;
+ALTER TABLE users ADD COLUMN bio TEXT;
@@ -0,0 +1,5 @@
+-- Migration: Free-form tags on projects
+-- Stored as a JSON array of trimmed, lowercased strings; `?tag=` filters through
+-- json_each(), so no separate table is needed for the small per-project lists.
+
+ALTER TABLE projects ADD COLUMN tags TEXT NOT NULL DEFAULT '[]';
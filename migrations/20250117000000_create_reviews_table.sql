@@ -0,0 +1,20 @@
+-- Migration: Project reviews and review-derived ratings
+-- `projects.rating` becomes the effective rating: the average review score, or
+-- `manual_rating` (the value clients set directly) while a project has no reviews.
+
+ALTER TABLE projects ADD COLUMN manual_rating REAL CHECK (manual_rating IS NULL OR (manual_rating >= 0.0 AND manual_rating <= 5.0));
+UPDATE projects SET manual_rating = rating;
+
+CREATE TABLE IF NOT EXISTS reviews (
+    id TEXT PRIMARY KEY NOT NULL,
+    project_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    score INTEGER NOT NULL CHECK (score >= 1 AND score <= 5),
+    comment TEXT,
+    created_at TEXT NOT NULL,
+    UNIQUE (project_id, user_id),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_reviews_user_id ON reviews(user_id);
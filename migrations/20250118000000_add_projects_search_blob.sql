@@ -0,0 +1,11 @@
+-- Migration: Case- and accent-insensitive project search
+-- `search_blob` holds the project's name and description lowercased with accents
+-- stripped ("Café" -> "cafe"). SQLite cannot fold accents itself, so the value is
+-- computed by the application on every insert and update, and `search` terms are
+-- folded the same way before matching.
+--
+-- Existing rows are left NULL rather than backfilled with an UPDATE, which would
+-- bump every project's updated_at; searches fall back to lower(name/description)
+-- for them until they are next written.
+
+ALTER TABLE projects ADD COLUMN search_blob TEXT;
@@ -0,0 +1,15 @@
+-- Migration: Persistent rate limit buckets
+-- Backs the database rate limiter (RATE_LIMIT_BACKEND=database): token buckets
+-- survive restarts and are shared by every instance using this database.
+-- `refilled_at` is Unix time in seconds, since instances share no monotonic clock.
+
+CREATE TABLE IF NOT EXISTS rate_limits (
+    client TEXT NOT NULL,
+    class TEXT NOT NULL CHECK (class IN ('read', 'write')),
+    tokens REAL NOT NULL,
+    refilled_at REAL NOT NULL,
+    PRIMARY KEY (client, class)
+);
+
+-- Idle buckets are swept by age
+CREATE INDEX IF NOT EXISTS idx_rate_limits_refilled_at ON rate_limits(refilled_at);
@@ -0,0 +1,6 @@
+-- Migration: Record whether a bucket's last request got a token
+-- Lets the database rate limiter refill, spend and report a bucket in a single
+-- upsert: `RETURNING` only sees the updated row, and `tokens` alone cannot tell
+-- a request that spent its last token from one that found none.
+
+ALTER TABLE rate_limits ADD COLUMN allowed INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,23 @@
+-- Migration: Write projects.updated_at in the same text format as the handlers
+-- sqlx stores chrono timestamps as RFC 3339 with nanoseconds (e.g.
+-- 2025-01-09T12:34:56.123456789+00:00), while trg_projects_updated_at wrote
+-- milliseconds. The updated_after/updated_before filters and updated_at sorting
+-- compare the column as text, so both writers now use nine fractional digits.
+
+DROP TRIGGER IF EXISTS trg_projects_updated_at;
+
+-- Pad timestamps the old trigger wrote; setting updated_at explicitly keeps the
+-- trigger from firing on these rows
+UPDATE projects
+SET updated_at = substr(updated_at, 1, 23) || '000000+00:00'
+WHERE updated_at GLOB '????-??-??T??:??:??.???+00:00';
+
+CREATE TRIGGER IF NOT EXISTS trg_projects_updated_at
+AFTER UPDATE ON projects
+FOR EACH ROW
+WHEN NEW.updated_at = OLD.updated_at
+BEGIN
+    UPDATE projects
+    SET updated_at = strftime('%Y-%m-%dT%H:%M:%f', 'now') || '000000+00:00'
+    WHERE id = NEW.id;
+END;
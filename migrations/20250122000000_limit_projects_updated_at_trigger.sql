@@ -0,0 +1,31 @@
+-- Migration: Bump projects.updated_at only on content changes, in sqlx's text format
+-- sqlx encodes chrono timestamps with to_rfc3339_opts(SecondsFormat::AutoSi, false),
+-- which writes 0, 3, 6 or 9 fractional digits: as few as keep every non-zero digit
+-- (2025-01-01T00:00:00+00:00, never ...00.000000000+00:00). The previous migration
+-- padded the trigger's milliseconds to nine digits on the wrong assumption that sqlx
+-- always writes nanoseconds, so a trigger-written instant and the same instant bound
+-- as an updated_after/updated_before filter still compared unequal as text.
+-- The trigger now writes its milliseconds the way AutoSi does (no fraction for .000),
+-- so every instant has one text form and the column compares correctly as text.
+--
+-- The trigger also used to fire on any UPDATE that left updated_at unchanged, which
+-- cannot tell "not set" from an explicit set to the stored value (re-importing an
+-- export into the same database bumped every row). It now fires only when content
+-- columns are assigned; statements that assign updated_at alone (imports,
+-- association changes, backfills) keep the value they write.
+
+DROP TRIGGER IF EXISTS trg_projects_updated_at;
+
+-- Rewrite timestamps the previous trigger wrote (milliseconds padded with six zeros)
+UPDATE projects
+SET updated_at = replace(substr(updated_at, 1, 23), '.000', '') || '+00:00'
+WHERE updated_at GLOB '????-??-??T??:??:??.???000000+00:00';
+
+CREATE TRIGGER IF NOT EXISTS trg_projects_updated_at
+AFTER UPDATE OF name, description, repository_url, language, rating, manual_rating, tags, deleted_at ON projects
+FOR EACH ROW
+BEGIN
+    UPDATE projects
+    SET updated_at = replace(strftime('%Y-%m-%dT%H:%M:%f', 'now'), '.000', '') || '+00:00'
+    WHERE id = NEW.id;
+END;
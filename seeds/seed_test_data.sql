@@ -1,6 +1,7 @@
--- Migration: Seed test data
--- This migration populates the database with test data for development and testing purposes
--- To skip this migration in production, you can delete this file before deployment
+-- Seed test data
+-- Populates an empty database with sample data for development and testing.
+-- Not a migration: it only runs through `AppState::seed_test_data` (SEED_DATA=true),
+-- so production databases start empty.
 
 -- Insert Technologies
 INSERT INTO technologies (id, name, description, created_at) VALUES
@@ -1,18 +1,77 @@
 use axum::{
     Json,
+    extract::rejection::JsonRejection,
     http::StatusCode,
     response::{IntoResponse, Response},
 };
 use serde::Serialize;
+use std::collections::HashMap;
+use std::str::FromStr;
 use thiserror::Error;
 use utoipa::ToSchema;
-use validator::ValidationErrors;
+use validator::{ValidationErrors, ValidationErrorsKind};
 
 /// Error response schema for OpenAPI documentation
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
     /// Error message
     pub error: String,
+    /// Machine-readable error code (e.g. `PROJECT_NOT_FOUND`)
+    pub code: String,
+    /// SQL state of the underlying database error (only with `ERROR_DETAIL=full`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql_state: Option<String>,
+    /// Per-field validation messages, keyed by field name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<HashMap<String, Vec<String>>>,
+}
+
+/// How much detail error responses expose, configured via `ERROR_DETAIL`
+///
+/// Applied to every route by [`crate::middleware::error_detail_middleware`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorDetail {
+    /// Underlying messages and database SQL states are included (development)
+    #[default]
+    Full,
+    /// Errors only carry their code and a generic message for it (production)
+    Minimal,
+}
+
+impl FromStr for ErrorDetail {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "full" => Ok(ErrorDetail::Full),
+            "minimal" => Ok(ErrorDetail::Minimal),
+            _ => Err(format!("Invalid error detail level: {}", s)),
+        }
+    }
+}
+
+/// Code and generic message of an error response
+///
+/// Inserted into the extensions of every `AppError` response so the body can be
+/// reduced to these two at the `Minimal` detail level after the fact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorSummary {
+    /// Machine-readable error code
+    pub code: &'static str,
+    /// Message shared by every error with this code
+    pub message: &'static str,
+}
+
+impl ErrorSummary {
+    /// Error body exposing nothing but the code and its generic message
+    pub fn body(&self) -> ErrorResponse {
+        ErrorResponse {
+            error: self.message.to_string(),
+            code: self.code.to_string(),
+            sql_state: None,
+            fields: None,
+        }
+    }
 }
 
 /// Application-specific error types
@@ -34,54 +93,360 @@ pub enum AppError {
     #[error("Duplicate resource: {0}")]
     DuplicateResource(String),
 
+    /// Request conflicts with the current state of a resource
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// A conditional request's precondition (e.g. `If-Unmodified-Since`) does not hold
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+
     /// Validation error
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    /// Request payload failed field validation; maps field name to its messages
+    #[error("Validation error: invalid fields {}", field_names(.0))]
+    FieldValidation(HashMap<String, Vec<String>>),
+
+    /// Request lacks valid authentication credentials
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    /// Caller is authenticated but not allowed to perform the action
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    /// A configured quota would be exceeded
+    #[error("Quota exceeded: {0}")]
+    QuotaExceeded(String),
+
+    /// Client exceeded its request rate limit
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    /// Request body exceeds the allowed size
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+
     /// Database error
-    #[error("Database error: {0}")]
-    DatabaseError(String),
+    #[error("Database error: {message}")]
+    DatabaseError {
+        /// Underlying driver message
+        message: String,
+        /// SQL state / extended result code reported by the database, if any
+        sql_state: Option<String>,
+    },
+
+    /// A dependency (e.g. the database) is currently unavailable
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
+
+    /// The request took longer than the configured timeout to handle
+    #[error("Gateway timeout: {0}")]
+    GatewayTimeout(String),
 
     /// Internal server error
     #[error("Internal server error: {0}")]
     InternalError(String),
 }
 
+impl AppError {
+    /// Machine-readable code identifying the error kind
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::ProjectNotFound(_) => "PROJECT_NOT_FOUND",
+            AppError::TechnologyNotFound(_) => "TECHNOLOGY_NOT_FOUND",
+            AppError::UserNotFound(_) => "USER_NOT_FOUND",
+            AppError::DuplicateResource(_) => "DUPLICATE_RESOURCE",
+            AppError::Conflict(_) => "CONFLICT",
+            AppError::PreconditionFailed(_) => "PRECONDITION_FAILED",
+            AppError::ValidationError(_) | AppError::FieldValidation(_) => "VALIDATION_ERROR",
+            AppError::Unauthorized(_) => "UNAUTHORIZED",
+            AppError::Forbidden(_) => "FORBIDDEN",
+            AppError::QuotaExceeded(_) => "QUOTA_EXCEEDED",
+            AppError::RateLimited(_) => "RATE_LIMITED",
+            AppError::PayloadTooLarge(_) => "PAYLOAD_TOO_LARGE",
+            AppError::DatabaseError { .. } => "DATABASE_ERROR",
+            AppError::ServiceUnavailable(_) => "SERVICE_UNAVAILABLE",
+            AppError::GatewayTimeout(_) => "GATEWAY_TIMEOUT",
+            AppError::InternalError(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    /// Message shared by every error of this kind, free of ids and internals
+    pub fn generic_message(&self) -> &'static str {
+        match self {
+            AppError::ProjectNotFound(_) => "Project not found",
+            AppError::TechnologyNotFound(_) => "Technology not found",
+            AppError::UserNotFound(_) => "User not found",
+            AppError::DuplicateResource(_) => "Resource already exists",
+            AppError::Conflict(_) => "Request conflicts with the current state of the resource",
+            AppError::PreconditionFailed(_) => "Precondition failed",
+            AppError::ValidationError(_) | AppError::FieldValidation(_) => "Invalid request",
+            AppError::Unauthorized(_) => "Authentication required",
+            AppError::Forbidden(_) => "Forbidden",
+            AppError::QuotaExceeded(_) => "Quota exceeded",
+            AppError::RateLimited(_) => "Too many requests",
+            AppError::PayloadTooLarge(_) => "Payload too large",
+            AppError::ServiceUnavailable(_) => "Service unavailable",
+            AppError::GatewayTimeout(_) => "Request timed out",
+            AppError::DatabaseError { .. } | AppError::InternalError(_) => "An internal error occurred",
+        }
+    }
+
+    /// Builds the HTTP response for this error at the given detail level
+    ///
+    /// `Minimal` replaces the message of every error, client errors included, with
+    /// the generic one for its code. The response carries an [`ErrorSummary`]
+    /// extension either way.
+    pub fn into_response_with_detail(self, detail: ErrorDetail) -> Response {
+        let summary = ErrorSummary {
+            code: self.code(),
+            message: self.generic_message(),
+        };
+        let code = summary.code.to_string();
+
+        let (status, error_message, sql_state, fields) = match self {
+            AppError::ProjectNotFound(msg) => (StatusCode::NOT_FOUND, msg, None, None),
+            AppError::TechnologyNotFound(msg) => (StatusCode::NOT_FOUND, msg, None, None),
+            AppError::UserNotFound(msg) => (StatusCode::NOT_FOUND, msg, None, None),
+            AppError::DuplicateResource(msg) => (StatusCode::CONFLICT, msg, None, None),
+            AppError::Conflict(msg) => (StatusCode::CONFLICT, msg, None, None),
+            AppError::PreconditionFailed(msg) => (StatusCode::PRECONDITION_FAILED, msg, None, None),
+            AppError::ValidationError(msg) => (StatusCode::BAD_REQUEST, msg, None, None),
+            AppError::FieldValidation(fields) => (
+                StatusCode::BAD_REQUEST,
+                format!("Validation failed for fields: {}", field_names(&fields)),
+                None,
+                Some(fields),
+            ),
+            AppError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg, None, None),
+            AppError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg, None, None),
+            AppError::QuotaExceeded(msg) => (StatusCode::FORBIDDEN, msg, None, None),
+            AppError::RateLimited(msg) => (StatusCode::TOO_MANY_REQUESTS, msg, None, None),
+            AppError::PayloadTooLarge(msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg, None, None),
+            AppError::DatabaseError { message, sql_state } => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Database error: {}", message),
+                sql_state,
+                None,
+            ),
+            AppError::ServiceUnavailable(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg, None, None),
+            AppError::GatewayTimeout(msg) => (StatusCode::GATEWAY_TIMEOUT, msg, None, None),
+            AppError::InternalError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg, None, None),
+        };
+
+        let body = match detail {
+            ErrorDetail::Minimal => summary.body(),
+            ErrorDetail::Full => ErrorResponse {
+                error: error_message,
+                code,
+                sql_state,
+                fields,
+            },
+        };
+
+        let mut response = (status, Json(body)).into_response();
+        response.extensions_mut().insert(summary);
+        response
+    }
+}
+
+/// Field names of a `FieldValidation` error, sorted and comma-separated
+fn field_names(fields: &HashMap<String, Vec<String>>) -> String {
+    let mut names: Vec<&str> = fields.keys().map(String::as_str).collect();
+    names.sort_unstable();
+    names.join(", ")
+}
+
+/// Adds the messages of `errors` to `fields`, keyed by their path below `prefix`
+///
+/// Nested structs become `parent.field` and list items `list[0].field`; a list
+/// validated at the top level (a JSON array body) yields `[0].field`.
+fn collect_field_errors(prefix: &str, errors: &ValidationErrors, fields: &mut HashMap<String, Vec<String>>) {
+    for (name, kind) in errors.errors() {
+        // validator files the errors of a top-level list under a placeholder name
+        let path = match (prefix, *name) {
+            (_, "_tmp_validator") => prefix.to_string(),
+            ("", name) => name.to_string(),
+            (prefix, name) => format!("{}.{}", prefix, name),
+        };
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                let messages = field_errors.iter().map(|error| match &error.message {
+                    Some(message) => message.to_string(),
+                    None => error.code.to_string(),
+                });
+                fields.entry(path).or_default().extend(messages);
+            }
+            ValidationErrorsKind::Struct(nested) => collect_field_errors(&path, nested, fields),
+            ValidationErrorsKind::List(items) => {
+                for (index, item) in items {
+                    collect_field_errors(&format!("{}[{}]", path, index), item, fields);
+                }
+            }
+        }
+    }
+}
+
 impl From<ValidationErrors> for AppError {
     fn from(errors: ValidationErrors) -> Self {
-        AppError::ValidationError(errors.to_string())
+        let mut fields = HashMap::new();
+        collect_field_errors("", &errors, &mut fields);
+        AppError::FieldValidation(fields)
+    }
+}
+
+impl From<JsonRejection> for AppError {
+    fn from(rejection: JsonRejection) -> Self {
+        match rejection {
+            // Well-formed JSON that does not fit the payload (e.g. an unknown role):
+            // report the offending field and serde's message without axum's preamble
+            // or the line/column position
+            JsonRejection::JsonDataError(error) => {
+                let text = error.body_text();
+                let message = text
+                    .strip_prefix("Failed to deserialize the JSON body into the target type: ")
+                    .unwrap_or(&text);
+                let message = match message.rsplit_once(" at line ") {
+                    Some((message, position)) if position.contains(" column ") => message,
+                    _ => message,
+                };
+                AppError::ValidationError(message.to_string())
+            }
+            rejection => AppError::ValidationError(rejection.body_text()),
+        }
     }
 }
 
 impl From<sqlx::Error> for AppError {
     fn from(error: sqlx::Error) -> Self {
+        // Unique violations are the client's doing (e.g. two creates racing past a
+        // pre-check), so they map to 409 rather than a server error
+        if let Some(db_error) = error.as_database_error()
+            && db_error.is_unique_violation()
+        {
+            tracing::warn!("Unique constraint violation: {}", db_error.message());
+            let target = db_error
+                .message()
+                .strip_prefix("UNIQUE constraint failed: ")
+                .unwrap_or("resource");
+            return AppError::DuplicateResource(format!("A record with the same {} already exists", target));
+        }
+
         tracing::error!("Database error: {:?}", error);
-        AppError::DatabaseError(error.to_string())
+        let sql_state = error
+            .as_database_error()
+            .and_then(|db_error| db_error.code())
+            .map(|code| code.into_owned());
+
+        AppError::DatabaseError {
+            message: error.to_string(),
+            sql_state,
+        }
     }
 }
 
 /// Converts AppError into an HTTP response
 ///
-/// This implementation allows AppError to be used directly as a handler return type
+/// This implementation allows AppError to be used directly as a handler return type.
+/// Responses are built in full; the router trims them to the configured
+/// [`ErrorDetail`] (see [`crate::middleware::error_detail_middleware`]).
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            AppError::ProjectNotFound(msg) => (StatusCode::NOT_FOUND, msg),
-            AppError::TechnologyNotFound(msg) => (StatusCode::NOT_FOUND, msg),
-            AppError::UserNotFound(msg) => (StatusCode::NOT_FOUND, msg),
-            AppError::DuplicateResource(msg) => (StatusCode::CONFLICT, msg),
-            AppError::ValidationError(msg) => (StatusCode::BAD_REQUEST, msg),
-            AppError::DatabaseError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Database error: {}", msg)),
-            AppError::InternalError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
-        };
-
-        let body = Json(ErrorResponse {
-            error: error_message,
-        });
-
-        (status, body).into_response()
+        self.into_response_with_detail(ErrorDetail::Full)
     }
 }
 
 /// Type alias for Results using AppError
 pub type Result<T> = std::result::Result<T, AppError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::Value;
+
+    fn database_error() -> AppError {
+        AppError::DatabaseError {
+            message: "UNIQUE constraint failed: users.email".to_string(),
+            sql_state: Some("2067".to_string()),
+        }
+    }
+
+    async fn body_json(response: Response) -> Value {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_database_error_full_detail() {
+        let response = database_error().into_response_with_detail(ErrorDetail::Full);
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body = body_json(response).await;
+        assert_eq!(body["code"], "DATABASE_ERROR");
+        assert!(body["error"].as_str().unwrap().contains("UNIQUE constraint failed"));
+        assert_eq!(body["sql_state"], "2067");
+    }
+
+    #[tokio::test]
+    async fn test_database_error_minimal_detail() {
+        let response = database_error().into_response_with_detail(ErrorDetail::Minimal);
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body = body_json(response).await;
+        assert_eq!(body["code"], "DATABASE_ERROR");
+        assert_eq!(body["error"], "An internal error occurred");
+        assert!(body.get("sql_state").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_auth_error_responses() {
+        let cases = [
+            (AppError::Unauthorized("Missing bearer token".to_string()), StatusCode::UNAUTHORIZED, "UNAUTHORIZED"),
+            (AppError::Forbidden("Requires the owner role".to_string()), StatusCode::FORBIDDEN, "FORBIDDEN"),
+        ];
+
+        for (error, status, code) in cases {
+            let message = match &error {
+                AppError::Unauthorized(msg) | AppError::Forbidden(msg) => msg.clone(),
+                _ => unreachable!(),
+            };
+            assert_eq!(error.code(), code);
+
+            let response = error.into_response_with_detail(ErrorDetail::Full);
+            assert_eq!(response.status(), status);
+
+            let body = body_json(response).await;
+            assert_eq!(body["code"], code);
+            assert_eq!(body["error"], message);
+            assert!(body.get("sql_state").is_none());
+            assert!(body.get("fields").is_none());
+        }
+
+        // The minimal detail level hides client error messages too
+        let response = AppError::Forbidden("User 42 is not a member".to_string()).into_response_with_detail(ErrorDetail::Minimal);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = body_json(response).await;
+        assert_eq!(body["code"], "FORBIDDEN");
+        assert_eq!(body["error"], "Forbidden");
+
+        assert_eq!(
+            AppError::Forbidden("not a member".to_string()).to_string(),
+            "Forbidden: not a member"
+        );
+        assert_eq!(
+            AppError::Unauthorized("expired".to_string()).to_string(),
+            "Unauthorized: expired"
+        );
+    }
+
+    #[test]
+    fn test_error_detail_parsing() {
+        assert_eq!("full".parse::<ErrorDetail>().unwrap(), ErrorDetail::Full);
+        assert_eq!(" Minimal ".parse::<ErrorDetail>().unwrap(), ErrorDetail::Minimal);
+        assert!("verbose".parse::<ErrorDetail>().is_err());
+    }
+}
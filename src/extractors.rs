@@ -1,24 +1,30 @@
+use axum::{
+    Json,
+    extract::{FromRequest, FromRequestParts, OptionalFromRequestParts, Path, Request},
+    http::request::Parts,
+};
+use serde::de::DeserializeOwned;
 use uuid::Uuid;
+use validator::Validate;
 
-/// Custom UUID wrapper for validated path parameters
+use crate::{error::AppError, middleware::auth::Claims, state::ADMIN_ROLE};
+
+/// UUID taken from a single-parameter path such as `/projects/{id}`
 ///
-/// This module provides utilities for UUID validation in path parameters.
-/// In Axum 0.8+, the built-in `Path<Uuid>` extractor already provides
-/// good error messages, so this is primarily for future customization needs.
+/// Drop-in replacement for `Path<Uuid>` whose rejection is a JSON
+/// [`crate::error::ErrorResponse`] (`400`, `VALIDATION_ERROR`) instead of axum's
+/// plain-text one when the segment is not a valid UUID.
 ///
 /// # Example
 ///
 /// ```rust,ignore
-/// use axum::{Router, routing::get, Json, extract::Path};
-/// use uuid::Uuid;
-///
-/// async fn get_project(Path(id): Path<Uuid>) -> Json<String> {
-///     // id is already validated by Axum
+/// async fn get_project(ValidatedUuid(id): ValidatedUuid) -> Json<String> {
 ///     Json(format!("Project ID: {}", id))
 /// }
 ///
-/// let app = Router::new().route("/projects/:id", get(get_project));
+/// let app = Router::new().route("/projects/{id}", get(get_project));
 /// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ValidatedUuid(pub Uuid);
 
 impl ValidatedUuid {
@@ -45,9 +51,230 @@ impl From<ValidatedUuid> for Uuid {
     }
 }
 
+impl<S: Send + Sync> FromRequestParts<S> for ValidatedUuid {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(segment) = <Path<String> as FromRequestParts<S>>::from_request_parts(parts, state)
+            .await
+            .map_err(|rejection| AppError::ValidationError(rejection.body_text()))?;
+
+        Uuid::parse_str(&segment)
+            .map(ValidatedUuid)
+            .map_err(|e| AppError::ValidationError(format!("Invalid UUID '{}': {}", segment, e)))
+    }
+}
+
+/// JSON request body that is deserialized and validated before the handler runs
+///
+/// Unlike axum's [`Json`], a malformed body, a wrong `Content-Type` or a failed
+/// [`Validate`] check is rejected with a JSON [`crate::error::ErrorResponse`]
+/// (`400`, `VALIDATION_ERROR`), the same shape as every other API error.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedJson<T>(pub T);
+
+impl<S, T> FromRequest<S> for ValidatedJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Validate,
+{
+    type Rejection = AppError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(request, state).await?;
+        value.validate()?;
+        Ok(ValidatedJson(value))
+    }
+}
+
+/// Authenticated caller, attached by the auth middleware from a verified bearer
+/// token or API key
+///
+/// Extracting it on a request without a valid bearer token yields `401`; use
+/// `Option<AuthUser>` where anonymous callers are allowed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthUser {
+    /// Id of the authenticated user (the token's `sub`)
+    pub user_id: Uuid,
+    /// Caller role from the token
+    pub role: String,
+}
+
+impl AuthUser {
+    /// Fails with `403 Forbidden` unless the caller has the `admin` role
+    pub fn require_admin(&self) -> Result<(), AppError> {
+        if self.role == ADMIN_ROLE {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden(format!("Requires the {} role", ADMIN_ROLE)))
+        }
+    }
+}
+
+impl From<&Claims> for AuthUser {
+    fn from(claims: &Claims) -> Self {
+        AuthUser {
+            user_id: claims.sub,
+            role: claims.role.clone(),
+        }
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for AuthUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<AuthUser>()
+            .cloned()
+            .ok_or_else(|| AppError::Unauthorized("Authentication required".to_string()))
+    }
+}
+
+impl<S: Send + Sync> OptionalFromRequestParts<S> for AuthUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Option<Self>, Self::Rejection> {
+        Ok(parts.extensions.get::<AuthUser>().cloned())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{routes::create_router, state::tests::new_test_db};
+    use axum::{
+        body::{Body, to_bytes},
+        http::{StatusCode, header},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    /// Posts `body` to `uri` and returns the status and JSON error body
+    async fn post(uri: &str, content_type: &str, body: &str) -> (StatusCode, Value) {
+        let response = create_router(new_test_db().await)
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header(header::CONTENT_TYPE, content_type)
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).expect("error body is JSON"))
+    }
+
+    async fn get(uri: &str) -> (StatusCode, Value) {
+        let response = create_router(new_test_db().await)
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).expect("error body is JSON"))
+    }
+
+    #[tokio::test]
+    async fn test_validated_uuid_extracts_path_id() {
+        let id = Uuid::new_v4();
+        let app = axum::Router::new().route(
+            "/projects/{id}",
+            axum::routing::get(|ValidatedUuid(id): ValidatedUuid| async move { id.to_string() }),
+        );
+        let response = app
+            .oneshot(Request::builder().uri(format!("/projects/{}", id)).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, id.to_string());
+
+        // A well-formed id reaches the handler, which reports the missing project
+        let (status, body) = get(&format!("/projects/{}", id)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "PROJECT_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn test_garbage_uuid_returns_error_response() {
+        let (status, body) = get("/projects/not-a-uuid").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        assert!(body["error"].as_str().unwrap().contains("not-a-uuid"), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn test_malformed_json_returns_error_response() {
+        for uri in ["/projects", "/users", "/technologies"] {
+            let (status, body) = post(uri, "application/json", "{\"name\": ").await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", uri);
+            assert_eq!(body["code"], "VALIDATION_ERROR");
+            assert!(body["error"].as_str().unwrap().contains("parse"), "{}", body);
+
+            // Well-formed JSON missing required fields is a parse failure too
+            let (status, body) = post(uri, "application/json", "{}").await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", uri);
+            assert_eq!(body["code"], "VALIDATION_ERROR");
+        }
+
+        let (status, body) = post("/users", "text/plain", "{}").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_fields_return_field_errors() {
+        let (status, body) = post("/users", "application/json", r#"{"name": "Alice", "email": "not-an-email"}"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        assert!(body["fields"]["email"].is_array(), "{}", body);
+        assert!(body["fields"].get("name").is_none());
+
+        let (status, body) = post("/technologies", "application/json", r#"{"name": ""}"#).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["fields"]["name"].is_array(), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_role_names_accepted_roles() {
+        let expected = "invalid role 'admin', expected one of: owner, contributor, viewer";
+        let member = format!(r#"{{"user_id": "{}", "role": "admin"}}"#, Uuid::new_v4());
+
+        let project = format!(
+            r#"{{"name": "Atlas", "description": "Maps", "repository_url": "https://github.com/test/atlas", "language": "Rust", "users": [{}]}}"#,
+            member
+        );
+        let (status, body) = post("/projects", "application/json", &project).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        assert_eq!(body["error"], format!("users[0].role: {}", expected));
+
+        let (status, body) = post("/projects/bulk", "application/json", &format!("[{}]", project)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        assert_eq!(body["error"], format!("[0].users[0].role: {}", expected));
+
+        let response = create_router(new_test_db().await)
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/projects/{}/users/{}/role", Uuid::new_v4(), Uuid::new_v4()))
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(r#"{"role": "admin"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["error"], format!("role: {}", expected));
+    }
 
     #[test]
     fn test_uuid_validation() {
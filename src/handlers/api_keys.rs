@@ -0,0 +1,197 @@
+use axum::{Json, extract::State, http::StatusCode};
+use validator::Validate;
+
+use crate::{
+    error::{AppError, ErrorResponse, Result},
+    extractors::AuthUser,
+    models::{ApiKey, CreateApiKeyRequest, CreatedApiKey},
+    state::AppState,
+};
+
+/// List API keys
+///
+/// # Endpoint
+/// GET /api-keys
+///
+/// Only key metadata is returned; plaintext keys are never stored.
+///
+/// # Returns
+/// - `200 OK` - All API keys, oldest first
+/// - `401 Unauthorized` - No authenticated caller
+/// - `403 Forbidden` - Caller is not an admin
+#[utoipa::path(
+    get,
+    path = "/api-keys",
+    tag = "api-keys",
+    responses(
+        (status = 200, description = "List of API keys", body = [ApiKey]),
+        (status = 401, description = "Authentication required", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn list_api_keys(State(state): State<AppState>, caller: AuthUser) -> Result<Json<Vec<ApiKey>>> {
+    caller.require_admin()?;
+
+    let api_keys = sqlx::query_as::<_, ApiKey>(
+        "SELECT id, name, user_id, role, created_at, last_used_at FROM api_keys ORDER BY created_at ASC",
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    tracing::info!("Listed {} API keys", api_keys.len());
+    Ok(Json(api_keys))
+}
+
+/// Mint a new API key
+///
+/// # Endpoint
+/// POST /api-keys
+///
+/// # Request Body
+/// ```json
+/// {
+///   "name": "CI pipeline",
+///   "user_id": "uuid",
+///   "role": "integration"
+/// }
+/// ```
+///
+/// The plaintext key is returned in this response only; just its SHA-256 hash is
+/// stored. Requests sending it in `X-API-Key` act as `user_id` with `role`.
+///
+/// # Returns
+/// - `201 Created` - Key metadata plus the plaintext `key`
+/// - `400 Bad Request` - Validation error
+/// - `401 Unauthorized` - No authenticated caller
+/// - `403 Forbidden` - Caller is not an admin
+/// - `404 Not Found` - User not found
+#[utoipa::path(
+    post,
+    path = "/api-keys",
+    tag = "api-keys",
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 201, description = "API key created; the plaintext key is shown only once", body = CreatedApiKey),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 401, description = "Authentication required", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state, request))]
+pub async fn create_api_key(
+    State(state): State<AppState>,
+    caller: AuthUser,
+    Json(request): Json<CreateApiKeyRequest>,
+) -> Result<(StatusCode, Json<CreatedApiKey>)> {
+    caller.require_admin()?;
+    request.validate()?;
+
+    let user_exists = sqlx::query("SELECT 1 FROM users WHERE id = ?")
+        .bind(request.user_id.to_string())
+        .fetch_optional(&state.db)
+        .await?;
+    if user_exists.is_none() {
+        return Err(AppError::UserNotFound(request.user_id.to_string()));
+    }
+
+    let (api_key, key) = ApiKey::generate(request);
+
+    sqlx::query(
+        "INSERT INTO api_keys (id, name, key_hash, user_id, role, created_at) VALUES (?, ?, ?, ?, ?, ?)"
+    )
+    .bind(api_key.id.to_string())
+    .bind(&api_key.name)
+    .bind(ApiKey::hash(&key))
+    .bind(api_key.user_id.to_string())
+    .bind(&api_key.role)
+    .bind(api_key.created_at)
+    .execute(&state.db)
+    .await?;
+
+    tracing::info!("Created API key {} for user {}", api_key.id, api_key.user_id);
+    Ok((StatusCode::CREATED, Json(CreatedApiKey { api_key, key })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::tests::new_test_db;
+    use uuid::Uuid;
+
+    fn admin() -> AuthUser {
+        AuthUser {
+            user_id: Uuid::new_v4(),
+            role: "admin".to_string(),
+        }
+    }
+
+    async fn insert_user(state: &AppState) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, CURRENT_TIMESTAMP)")
+            .bind(id.to_string())
+            .bind("Integrator")
+            .bind("integrator@example.com")
+            .execute(&state.db)
+            .await
+            .unwrap();
+        id
+    }
+
+    fn request(user_id: Uuid) -> CreateApiKeyRequest {
+        CreateApiKeyRequest {
+            name: "CI".to_string(),
+            user_id,
+            role: "integration".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_api_key_returns_plaintext_once() {
+        let state = new_test_db().await;
+        let user_id = insert_user(&state).await;
+
+        let (status, Json(created)) = create_api_key(State(state.clone()), admin(), Json(request(user_id)))
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created.api_key.user_id, user_id);
+
+        // Only the hash is stored
+        let stored: String = sqlx::query_scalar("SELECT key_hash FROM api_keys")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(stored, ApiKey::hash(&created.key));
+        assert_ne!(stored, created.key);
+
+        // Later reads expose metadata only
+        let Json(keys) = list_api_keys(State(state), admin()).await.unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].id, created.api_key.id);
+        let body = serde_json::to_string(&keys).unwrap();
+        assert!(!body.contains(&created.key));
+        assert!(serde_json::to_value(&keys[0]).unwrap().get("key").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_api_keys_are_admin_only() {
+        let state = new_test_db().await;
+        let user_id = insert_user(&state).await;
+        let member = AuthUser {
+            user_id,
+            role: "integration".to_string(),
+        };
+
+        let result = create_api_key(State(state.clone()), member.clone(), Json(request(user_id))).await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+        let result = list_api_keys(State(state.clone()), member).await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+
+        let result = create_api_key(State(state), admin(), Json(request(Uuid::new_v4()))).await;
+        assert!(matches!(result, Err(AppError::UserNotFound(_))));
+    }
+}
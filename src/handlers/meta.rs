@@ -0,0 +1,81 @@
+use axum::{Json, extract::State};
+
+use crate::{models::ApiMetadata, state::AppState};
+
+/// Get validation metadata
+///
+/// # Endpoint
+/// GET /meta
+///
+/// `max_page_size` reports the cap this server is configured with.
+///
+/// # Returns
+/// - `200 OK` - Valid roles, sortable fields, page size and rating bounds, and field length limits
+#[utoipa::path(
+    get,
+    path = "/meta",
+    tag = "meta",
+    responses(
+        (status = 200, description = "API validation metadata", body = ApiMetadata)
+    )
+)]
+pub async fn get_meta(State(state): State<AppState>) -> Json<ApiMetadata> {
+    let mut meta = ApiMetadata::current();
+    meta.max_page_size = state.config.max_page_size;
+    Json(meta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::UserRole;
+    use crate::state::{AppConfig, tests::new_test_db};
+
+    #[tokio::test]
+    async fn test_meta_roles_match_user_role_variants() {
+        let Json(meta) = get_meta(State(new_test_db().await)).await;
+
+        let roles: Vec<&str> = meta.roles.iter().map(|role| role.as_str()).collect();
+        assert_eq!(roles, vec!["owner", "contributor", "viewer"]);
+
+        // Every advertised role must round-trip through the parser
+        for role in &meta.roles {
+            assert_eq!(role.as_str().parse::<UserRole>().unwrap(), *role);
+        }
+
+        // Exhaustive match: adding a variant without updating `UserRole::ALL` fails here
+        for role in UserRole::ALL {
+            match role {
+                UserRole::Owner | UserRole::Contributor | UserRole::Viewer => {}
+            }
+        }
+        assert_eq!(meta.roles.len(), UserRole::ALL.len());
+    }
+
+    #[tokio::test]
+    async fn test_meta_limits_match_validators() {
+        let Json(meta) = get_meta(State(new_test_db().await)).await;
+
+        assert_eq!(meta.max_page_size, 100);
+        assert_eq!(meta.rating.max, 5.0);
+        assert!(meta.sortable_fields["projects"].contains(&"rating".to_string()));
+
+        let name_limit = meta
+            .field_limits
+            .iter()
+            .find(|limit| limit.resource == "projects" && limit.field == "name")
+            .unwrap();
+        assert_eq!(name_limit.max_length, 255);
+    }
+
+    #[tokio::test]
+    async fn test_meta_reports_configured_max_page_size() {
+        let state = new_test_db().await.with_app_config(AppConfig {
+            max_page_size: 500,
+            ..Default::default()
+        });
+
+        let Json(meta) = get_meta(State(state)).await;
+        assert_eq!(meta.max_page_size, 500);
+    }
+}
@@ -1,7 +1,36 @@
+use axum::http::{HeaderMap, HeaderValue, header::LOCATION};
+
+use crate::error::{AppError, Result};
+
+pub mod api_keys;
+pub mod meta;
 pub mod projects;
+pub mod reviews;
 pub mod technologies;
 pub mod users;
 
-pub use projects::{create_project, delete_project, get_project, list_projects, update_project};
-pub use technologies::{create_technology, list_technologies};
-pub use users::{create_user, list_users};
+pub use api_keys::{create_api_key, list_api_keys};
+pub use meta::get_meta;
+pub use projects::{
+    add_project_technology, copy_project_technologies, create_project, create_projects_bulk,
+    delete_project, export_projects, get_project, get_project_stats, get_similar_projects, import_projects, list_projects, remove_project_technology, replace_project_users,
+    restore_project, update_project, update_project_ratings, update_project_user_role, validate_project,
+};
+pub use reviews::{create_project_review, list_project_reviews};
+pub use technologies::{
+    create_technology, delete_technology, get_technology, get_technology_projects, list_technologies,
+    list_unused_technologies, merge_technologies, preview_merge_technologies, resolve_technologies,
+    update_technology,
+};
+pub use users::{create_user, delete_user, get_user, get_user_projects, list_users, update_user};
+
+/// Headers carrying `Location: {path}` for a `201 Created` response
+///
+/// `path` is the relative URI of the new resource, e.g. `/projects/{id}`.
+pub(crate) fn created_location(path: &str) -> Result<HeaderMap> {
+    let value = HeaderValue::from_str(path)
+        .map_err(|e| AppError::InternalError(format!("Invalid Location header '{}': {}", path, e)))?;
+    let mut headers = HeaderMap::new();
+    headers.insert(LOCATION, value);
+    Ok(headers)
+}
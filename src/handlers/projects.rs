@@ -1,21 +1,31 @@
 use axum::{
-    Json,
+    Extension, Json,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, HeaderValue, StatusCode, Uri, header},
+    response::{IntoResponse, Response},
 };
-use chrono::Utc;
-use sqlx::Row;
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+use sqlx::{FromRow, Row, SqliteConnection, SqlitePool};
 use std::str::FromStr;
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
     error::{AppError, ErrorResponse, Result},
+    extractors::{AuthUser, ValidatedJson, ValidatedUuid},
+    handlers::created_location,
     models::{
-        CreateProjectRequest, ListQueryParams, PaginatedResponse, Project, ProjectWithRelations,
-        Technology, UpdateProjectRequest, User, UserRole, UserWithRole,
+        AttachTechnologyRequest, BulkRatingUpdateResult, CreateProjectOptions, CreateProjectRequest, ImportOptions,
+        ImportRecordError, ImportSummary, LanguageCount,
+        ListQueryParams, PaginatedResponse, Project, ProjectMemberRequest, ProjectStats, ProjectTechnology, ProjectValidation,
+        ProjectWithRelations, RatingUpdate, RoleUpdateRequest, SimilarProjectsQuery, Technology, TechnologyCount,
+        UpdateProjectRequest, User, UserRole, UserWithRole,
+        jsonapi::{Document, JSON_API_MEDIA_TYPE, Resource, ResourceIdentifier},
+        pagination::{LIKE_ESCAPE, ListQuery, PROJECT_FIELDS, PROJECT_INCLUDES, PROJECT_SEARCH_BLOB, TECH_ALIAS_SUNSET, contains_pattern, search_key},
+        project::{MAX_BULK_PROJECTS, decode_tags, encode_tags, normalize_repository_url, normalize_tags, search_blob},
     },
-    state::AppState,
+    state::{AppState, CallerRole},
 };
 
 /// Helper struct for parsing joined query results from get_project
@@ -29,6 +39,7 @@ struct ProjectWithRelationsRow {
     repository_url: String,
     language: String,
     rating: Option<f64>,
+    project_tags: String,
     project_created_at: chrono::DateTime<Utc>,
     project_updated_at: chrono::DateTime<Utc>,
     // Technology fields (nullable from LEFT JOIN)
@@ -36,12 +47,461 @@ struct ProjectWithRelationsRow {
     tech_name: Option<String>,
     tech_description: Option<String>,
     tech_created_at: Option<chrono::DateTime<Utc>>,
+    tech_updated_at: Option<chrono::DateTime<Utc>>,
+    tech_associated_at: Option<chrono::DateTime<Utc>>,
     // User fields (nullable from LEFT JOIN)
     user_id: Option<String>,
     user_name: Option<String>,
     user_email: Option<String>,
+    user_avatar_url: Option<String>,
+    user_bio: Option<String>,
     user_created_at: Option<chrono::DateTime<Utc>>,
+    user_updated_at: Option<chrono::DateTime<Utc>>,
     role: Option<String>,
+    user_associated_at: Option<chrono::DateTime<Utc>>,
+}
+
+/// Project list body, negotiated from the request's `Accept` header
+pub enum ProjectListResponse {
+    /// Default `application/json` paginated envelope
+    Json(PaginatedResponse<Project>),
+    /// JSON envelope whose projects embed the relations named in `include`
+    WithRelations(PaginatedResponse<ProjectWithRelations>),
+    /// JSON envelope narrowed to the requested `fields`
+    Fields(PaginatedResponse<Map<String, Value>>),
+    /// `text/csv` rendering of the requested page (header row plus one row per project)
+    Csv(String),
+    /// `application/vnd.api+json` document with technology and user linkage
+    JsonApi(Document<Vec<Resource>>),
+}
+
+impl IntoResponse for ProjectListResponse {
+    fn into_response(self) -> Response {
+        match self {
+            ProjectListResponse::Json(page) => Json(page).into_response(),
+            ProjectListResponse::WithRelations(page) => Json(page).into_response(),
+            ProjectListResponse::Fields(page) => Json(page).into_response(),
+            ProjectListResponse::Csv(body) => {
+                ([(header::CONTENT_TYPE, "text/csv; charset=utf-8")], body).into_response()
+            }
+            ProjectListResponse::JsonApi(document) => json_api_response(document),
+        }
+    }
+}
+
+/// Body formats a project response can be negotiated into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResponseFormat {
+    Json,
+    Csv,
+    JsonApi,
+}
+
+impl ResponseFormat {
+    /// Media type of a body in this format
+    fn media_type(self) -> &'static str {
+        match self {
+            ResponseFormat::Json => "application/json",
+            ResponseFormat::Csv => "text/csv",
+            ResponseFormat::JsonApi => JSON_API_MEDIA_TYPE,
+        }
+    }
+}
+
+/// Format requested by the `Accept` header
+///
+/// Media ranges are taken in the order listed; anything without `text/csv` or
+/// `application/vnd.api+json` (including a missing header or `*/*`) falls back to JSON.
+fn response_format(headers: &HeaderMap) -> ResponseFormat {
+    let Some(accept) = headers.get(header::ACCEPT).and_then(|v| v.to_str().ok()) else {
+        return ResponseFormat::Json;
+    };
+
+    accept
+        .split(',')
+        .map(|range| range.split(';').next().unwrap_or_default().trim().to_ascii_lowercase())
+        .find_map(|media| match media.as_str() {
+            "application/json" => Some(ResponseFormat::Json),
+            "text/csv" => Some(ResponseFormat::Csv),
+            JSON_API_MEDIA_TYPE => Some(ResponseFormat::JsonApi),
+            _ => None,
+        })
+        .unwrap_or(ResponseFormat::Json)
+}
+
+/// Serializes a JSON:API document with its `application/vnd.api+json` content type
+fn json_api_response<T: serde::Serialize>(document: Document<T>) -> Response {
+    ([(header::CONTENT_TYPE, JSON_API_MEDIA_TYPE)], Json(document)).into_response()
+}
+
+/// JSON:API resources for a page of projects, linked to their technologies and users
+///
+/// The linkage of the whole page is loaded with one query per relationship.
+async fn project_resources(state: &AppState, projects: &[Project], fields: &[&str]) -> Result<Vec<Resource>> {
+    use std::collections::HashMap;
+
+    let mut technologies: HashMap<String, Vec<ResourceIdentifier>> = HashMap::new();
+    let mut users: HashMap<String, Vec<ResourceIdentifier>> = HashMap::new();
+    if !projects.is_empty() {
+        let linkage = |query: &'static str| {
+            let mut builder: sqlx::QueryBuilder<sqlx::Sqlite> = sqlx::QueryBuilder::new(query);
+            let mut separated = builder.separated(", ");
+            for project in projects {
+                separated.push_bind(project.id.to_string());
+            }
+            separated.push_unseparated(") ORDER BY created_at ASC");
+            builder
+        };
+
+        let rows: Vec<(String, String)> = linkage(
+            "SELECT project_id, technology_id FROM project_technologies WHERE project_id IN (",
+        )
+        .build_query_as()
+        .fetch_all(&state.db)
+        .await?;
+        for (project_id, technology_id) in rows {
+            let id = Uuid::parse_str(&technology_id).map_err(|e| AppError::InternalError(e.to_string()))?;
+            technologies.entry(project_id).or_default().push(ResourceIdentifier::technology(id));
+        }
+
+        let rows: Vec<(String, String, String)> = linkage(
+            "SELECT project_id, user_id, role FROM project_users WHERE project_id IN (",
+        )
+        .build_query_as()
+        .fetch_all(&state.db)
+        .await?;
+        for (project_id, user_id, role) in rows {
+            let id = Uuid::parse_str(&user_id).map_err(|e| AppError::InternalError(e.to_string()))?;
+            users.entry(project_id).or_default().push(ResourceIdentifier::member(id, &role));
+        }
+    }
+
+    projects
+        .iter()
+        .map(|project| {
+            let key = project.id.to_string();
+            let mut resource = Resource::project(project)
+                .map_err(|e| AppError::InternalError(format!("Failed to serialize project: {}", e)))?
+                .with_relationship("technologies", technologies.remove(&key).unwrap_or_default())
+                .with_relationship("users", users.remove(&key).unwrap_or_default());
+            resource.retain_attributes(fields);
+            Ok(resource)
+        })
+        .collect()
+}
+
+/// Quotes a CSV field when it contains a delimiter, quote or line break (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders projects as CSV with a header row
+///
+/// `fields` selects and orders the columns; an empty slice renders all of them.
+fn projects_to_csv(projects: &[Project], fields: &[&str]) -> String {
+    let columns: Vec<usize> = PROJECT_FIELDS
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| fields.is_empty() || fields.contains(field))
+        .map(|(index, _)| index)
+        .collect();
+
+    let header: Vec<&str> = columns.iter().map(|&index| PROJECT_FIELDS[index]).collect();
+    let mut csv = header.join(",");
+    csv.push('\n');
+    for project in projects {
+        // Same order as `PROJECT_FIELDS`
+        let values = [
+            project.id.to_string(),
+            project.name.clone(),
+            project.description.clone(),
+            project.repository_url.clone(),
+            project.language.clone(),
+            project.rating.map(|r| r.to_string()).unwrap_or_default(),
+            project.tags.join(";"),
+            project.created_at.to_rfc3339(),
+            project.updated_at.to_rfc3339(),
+            project.deleted_at.map(|d| d.to_rfc3339()).unwrap_or_default(),
+        ];
+        let row: Vec<String> = columns.iter().map(|&index| csv_field(&values[index])).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Keeps only the requested `fields` of a serialized project
+///
+/// Fields the full object omits (such as `deleted_at` on a live project) stay omitted.
+fn sparse_project(project: &impl serde::Serialize, fields: &[&str]) -> Result<Map<String, Value>> {
+    let Value::Object(mut object) = serde_json::to_value(project)
+        .map_err(|e| AppError::InternalError(format!("Failed to serialize project: {}", e)))?
+    else {
+        return Err(AppError::InternalError("Project did not serialize to an object".to_string()));
+    };
+    object.retain(|key, _| fields.contains(&key.as_str()));
+    Ok(object)
+}
+
+/// Sorts and de-duplicates technology ids before inserting association rows
+///
+/// Inserting in a deterministic order means concurrent transactions touching
+/// overlapping technology sets acquire their locks in the same order, which
+/// reduces lock contention stalls.
+fn ordered_technology_ids(tech_ids: &[Uuid]) -> Vec<Uuid> {
+    let mut ordered = tech_ids.to_vec();
+    ordered.sort();
+    ordered.dedup();
+    ordered
+}
+
+/// Assigns positional roles (first user is Owner, the rest Contributors) and
+/// returns the memberships sorted and de-duplicated by user id
+///
+/// Roles are decided from the request order before sorting, so the ordering
+/// only affects insertion, not who becomes the owner.
+fn ordered_memberships(user_ids: &[Uuid]) -> Vec<(Uuid, UserRole)> {
+    let mut memberships: Vec<(Uuid, UserRole)> = user_ids
+        .iter()
+        .enumerate()
+        .map(|(idx, user_id)| {
+            let role = if idx == 0 {
+                UserRole::Owner
+            } else {
+                UserRole::Contributor
+            };
+            (*user_id, role)
+        })
+        .collect();
+
+    // Stable sort keeps the first occurrence of a duplicated id (and its role)
+    memberships.sort_by_key(|(user_id, _)| *user_id);
+    memberships.dedup_by_key(|(user_id, _)| *user_id);
+    memberships
+}
+
+/// Rejects member lists that repeat a user or, when non-empty, lack exactly one owner
+fn validate_members(members: &[ProjectMemberRequest]) -> Result<()> {
+    // Each user may appear once
+    let mut user_ids: Vec<Uuid> = members.iter().map(|member| member.user_id).collect();
+    user_ids.sort();
+    if let Some(pair) = user_ids.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(AppError::ValidationError(format!(
+            "User {} is listed more than once",
+            pair[0]
+        )));
+    }
+
+    // A project with members has exactly one owner
+    let owners = members
+        .iter()
+        .filter(|member| member.role == UserRole::Owner)
+        .count();
+    if !members.is_empty() && owners != 1 {
+        return Err(AppError::ValidationError(format!(
+            "A project must have exactly one owner, got {}",
+            owners
+        )));
+    }
+
+    Ok(())
+}
+
+/// Memberships requested by a create payload, sorted by user id for insertion
+///
+/// `users` carries explicit roles (see `validate_members`); the legacy `user_ids`
+/// form assigns them positionally (see `ordered_memberships`).
+fn requested_memberships(request: &CreateProjectRequest) -> Result<Vec<(Uuid, UserRole)>> {
+    match (&request.user_ids, &request.users) {
+        (Some(_), Some(_)) => Err(AppError::ValidationError(
+            "Provide either user_ids or users, not both".to_string(),
+        )),
+        (Some(user_ids), None) => Ok(ordered_memberships(user_ids)),
+        (None, Some(members)) => {
+            validate_members(members)?;
+            let mut memberships: Vec<(Uuid, UserRole)> =
+                members.iter().map(|member| (member.user_id, member.role)).collect();
+            memberships.sort_by_key(|(user_id, _)| *user_id);
+            Ok(memberships)
+        }
+        (None, None) => Ok(Vec::new()),
+    }
+}
+
+/// Fails with `403 Forbidden` unless `user_id` is a member of the project with at least `min_role`
+async fn require_project_role(state: &AppState, user_id: Uuid, project_id: Uuid, min_role: UserRole) -> Result<UserRole> {
+    let role: Option<String> = sqlx::query_scalar("SELECT role FROM project_users WHERE project_id = ? AND user_id = ?")
+        .bind(project_id.to_string())
+        .bind(user_id.to_string())
+        .fetch_optional(&state.db)
+        .await?;
+
+    let Some(role) = role else {
+        return Err(AppError::Forbidden(format!("User {} is not a member of project {}", user_id, project_id)));
+    };
+    let role = UserRole::from_str(&role).map_err(AppError::InternalError)?;
+    if !role.at_least(min_role) {
+        return Err(AppError::Forbidden(format!(
+            "Requires the {} role on project {}, user {} is {}",
+            min_role.as_str(),
+            project_id,
+            user_id,
+            role.as_str()
+        )));
+    }
+    Ok(role)
+}
+
+/// Applies [`require_project_role`] to authenticated callers
+///
+/// With authentication enabled the auth middleware rejects writes without a
+/// token, so an anonymous caller here means authentication is turned off.
+/// A missing project is reported as `404 Not Found` before any role check;
+/// soft-deleted projects still count as existing so they can be restored.
+async fn authorize_project_write(state: &AppState, caller: Option<&AuthUser>, project_id: Uuid, min_role: UserRole) -> Result<()> {
+    if let Some(caller) = caller {
+        let exists = sqlx::query("SELECT 1 FROM projects WHERE id = ?")
+            .bind(project_id.to_string())
+            .fetch_optional(&state.db)
+            .await?;
+        if exists.is_none() {
+            return Err(AppError::ProjectNotFound(project_id.to_string()));
+        }
+        require_project_role(state, caller.user_id, project_id, min_role).await?;
+    }
+    Ok(())
+}
+
+/// Fails with `409 Conflict` when a project that has an owner would lose all its members
+///
+/// Only owners may write a project, so emptying its member list would leave it
+/// writable by nobody.
+async fn ensure_owner_kept(conn: &mut SqliteConnection, project_id: Uuid) -> Result<()> {
+    let has_owner = sqlx::query("SELECT 1 FROM project_users WHERE project_id = ? AND role = ? LIMIT 1")
+        .bind(project_id.to_string())
+        .bind(UserRole::Owner.as_str())
+        .fetch_optional(&mut *conn)
+        .await?;
+    if has_owner.is_some() {
+        return Err(AppError::Conflict(format!(
+            "Project {} must keep an owner; an empty member list would remove it",
+            project_id
+        )));
+    }
+    Ok(())
+}
+
+/// Makes an authenticated creator the owner of a project created without members
+///
+/// Otherwise the creator could not update or delete the project afterwards.
+fn default_owner(request: &mut CreateProjectRequest, caller: Option<&AuthUser>) -> Result<()> {
+    let memberships = requested_memberships(request)?;
+    if let Some(caller) = caller
+        && memberships.is_empty()
+    {
+        request.user_ids = None;
+        request.users = Some(vec![ProjectMemberRequest {
+            user_id: caller.user_id,
+            role: UserRole::Owner,
+        }]);
+    }
+    Ok(())
+}
+
+/// Rejects projects in a rating-required language (see `RATING_REQUIRED_LANGUAGES`) without a rating
+fn check_rating_requirement(state: &AppState, language: &str, rating: Option<f64>) -> Result<()> {
+    if rating.is_none() && state.config.requires_rating(language) {
+        return Err(AppError::ValidationError(format!(
+            "A rating is required for {} projects",
+            language
+        )));
+    }
+    Ok(())
+}
+
+/// Fetches a project's technologies with their association timestamps, ordered by name
+async fn fetch_project_technologies(
+    conn: &mut SqliteConnection,
+    id: Uuid,
+) -> Result<Vec<ProjectTechnology>> {
+    let technologies = sqlx::query_as::<_, ProjectTechnology>(
+        "SELECT t.*, pt.created_at as associated_at FROM technologies t
+         JOIN project_technologies pt ON t.id = pt.technology_id
+         WHERE pt.project_id = ?
+         ORDER BY t.name ASC"
+    )
+    .bind(id.to_string())
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(technologies)
+}
+
+/// Fetches a project's members with their roles, ordered by name
+async fn fetch_project_users(conn: &mut SqliteConnection, id: Uuid) -> Result<Vec<UserWithRole>> {
+    let users_raw: Vec<(User, String, DateTime<Utc>)> = sqlx::query(
+        "SELECT u.id, u.name, u.email, u.avatar_url, u.bio, u.created_at, u.updated_at, pu.role,
+                pu.created_at as associated_at
+         FROM users u
+         JOIN project_users pu ON u.id = pu.user_id
+         WHERE pu.project_id = ?
+         ORDER BY u.name ASC"
+    )
+    .bind(id.to_string())
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .map(|row| {
+        let id_str: String = row.try_get("id").unwrap();
+        let user = User {
+            id: Uuid::parse_str(&id_str).unwrap(),
+            name: row.try_get("name").unwrap(),
+            email: row.try_get("email").unwrap(),
+            avatar_url: row.try_get("avatar_url").unwrap(),
+            bio: row.try_get("bio").unwrap(),
+            created_at: row.try_get("created_at").unwrap(),
+            updated_at: row.try_get("updated_at").unwrap(),
+        };
+        let role: String = row.try_get("role").unwrap();
+        let associated_at: DateTime<Utc> = row.try_get("associated_at").unwrap();
+        (user, role, associated_at)
+    })
+    .collect();
+
+    let users: Vec<UserWithRole> = users_raw
+        .into_iter()
+        .filter_map(|(user, role_str, associated_at)| {
+            UserRole::from_str(&role_str)
+                .ok()
+                .map(|role| UserWithRole { user, role, associated_at })
+        })
+        .collect();
+
+    Ok(users)
+}
+
+/// Builds an FTS5 query matching any of the search words
+///
+/// Every word is quoted so user input cannot inject FTS operators; projects
+/// matching more of the words score better under `bm25()`.
+fn fts_query(search: &str) -> Option<String> {
+    let terms: Vec<String> = search
+        .split_whitespace()
+        .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" OR "))
+}
+
+/// Whether the `projects_fts` full-text index exists in this database
+async fn fts_available(db: &SqlitePool) -> Result<bool> {
+    let found = sqlx::query("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'projects_fts'")
+        .fetch_optional(db)
+        .await?;
+    Ok(found.is_some())
 }
 
 /// List all projects with advanced filtering and pagination
@@ -51,176 +511,383 @@ struct ProjectWithRelationsRow {
 ///
 /// # Query Parameters
 /// - `search` - Search text in name and description
-/// - `tech` / `technology` - Filter by technology name
+/// - `technology` - Filter by technology name (substring); a comma-separated list matches exact names
+/// - `tech_mode` - With a technology list, `any` (default) or `all` of them must be used
+/// - `match` - `substring` (default) or `exact` matching for `language` and a single `technology`
+/// - `tech` - Deprecated alias of `technology` (adds `Deprecation`/`Sunset` headers and a warning)
 /// - `user_id` - Filter by user ID
 /// - `min_rating` - Minimum rating filter
 /// - `max_rating` - Maximum rating filter
-/// - `language` - Filter by language
-/// - `sort` - Sort field (name, created_at, updated_at, rating)
-/// - `order` - Sort order (asc, desc)
+/// - `created_after` / `created_before` - Inclusive `created_at` range (RFC 3339, e.g. `2025-01-01T00:00:00Z`)
+/// - `updated_after` / `updated_before` - Inclusive `updated_at` range (RFC 3339)
+/// - `include_deleted` - Also list soft-deleted projects (default: false)
+/// - `language` - Filter by language (case-insensitive, surrounding whitespace ignored)
+/// - `languages` - Comma-separated languages, matches projects in any of them (e.g. `rust,go`)
+/// - `tag` - Only projects carrying this tag (case-insensitive, surrounding whitespace ignored)
+/// - `sort` - Sort field(s), comma-separated (name, created_at, updated_at, rating, relevance);
+///   `relevance` switches `search` to the full-text index and ranks matches with `bm25()`
+/// - `order` - Sort order(s) matching `sort` position by position (asc, desc; default desc)
 /// - `page` - Page number (default: 1)
-/// - `page_size` - Items per page (default: 10, max: 100)
+/// - `page_size` - Items per page (default: 10 or the caller role's configured default; default max 100, configurable via `MAX_PAGE_SIZE`)
+/// - `fields` - Comma-separated project fields to return (e.g. `id,name,rating`); applies to CSV columns too
+/// - `include` - Comma-separated relations to embed in each project (`technologies`, `users`), loaded
+///   with one query per relation for the whole page
+/// - `with_total` - Count matches for `total_items` / `total_pages` (default: true)
+///
+/// # Headers
+/// - `Accept: text/csv` - Return the requested page as CSV instead of the JSON envelope
+/// - `Accept: application/vnd.api+json` - Return a JSON:API document, technologies and users
+///   as `relationships` and pagination under `meta`
 ///
 /// # Returns
-/// - `200 OK` - Paginated list of projects
+/// - `200 OK` - Paginated list of projects, with a `Link` header pointing at the
+///   `first`, `prev`, `next` and `last` pages
+/// - `400 Bad Request` - Unknown `sort`, `order`, `tech_mode`, `match`, `fields` or `include` value, more orders
+///   than sort fields, or a date range whose `_after` bound is later than its `_before` bound
 #[utoipa::path(
     get,
     path = "/projects",
     tag = "projects",
     params(
         ("search" = Option<String>, Query, description = "Search text in name and description"),
-        ("tech" = Option<String>, Query, description = "Filter by technology name"),
-        ("technology" = Option<String>, Query, description = "Filter by technology name (alias)"),
+        ("technology" = Option<String>, Query, description = "Filter by technology name; comma-separated names match exactly (e.g. rust,go)"),
+        ("tech_mode" = Option<String>, Query, description = "How a technology list combines: any (default) or all"),
+        ("tech" = Option<String>, Query, deprecated, description = "Deprecated alias of `technology`; responses carry `Deprecation` and `Sunset` headers"),
         ("user_id" = Option<String>, Query, description = "Filter by user ID"),
         ("min_rating" = Option<f64>, Query, description = "Minimum rating"),
         ("max_rating" = Option<f64>, Query, description = "Maximum rating"),
-        ("language" = Option<String>, Query, description = "Filter by language"),
-        ("sort" = Option<String>, Query, description = "Sort field (name, created_at, updated_at, rating)"),
-        ("order" = Option<String>, Query, description = "Sort order (asc, desc)"),
+        ("created_after" = Option<DateTime<Utc>>, Query, description = "Only projects created at or after this RFC 3339 instant"),
+        ("created_before" = Option<DateTime<Utc>>, Query, description = "Only projects created at or before this RFC 3339 instant"),
+        ("updated_after" = Option<DateTime<Utc>>, Query, description = "Only projects updated at or after this RFC 3339 instant"),
+        ("updated_before" = Option<DateTime<Utc>>, Query, description = "Only projects updated at or before this RFC 3339 instant"),
+        ("language" = Option<String>, Query, description = "Filter by language (case-insensitive)"),
+        ("languages" = Option<String>, Query, description = "Comma-separated languages to match any of (e.g. rust,go)"),
+        ("tag" = Option<String>, Query, description = "Only projects carrying this tag (case-insensitive)"),
+        ("match" = Option<String>, Query, description = "How language / technology match: substring (default) or exact"),
+        ("sort" = Option<String>, Query, description = "Comma-separated sort fields (name, created_at, updated_at, rating, relevance); `relevance` ranks full-text `search` matches"),
+        ("order" = Option<String>, Query, description = "Comma-separated sort orders matching `sort` (asc, desc)"),
         ("page" = Option<u32>, Query, description = "Page number"),
-        ("page_size" = Option<u32>, Query, description = "Items per page (max 100)"),
+        ("page_size" = Option<u32>, Query, description = "Items per page (default max 100, configurable via MAX_PAGE_SIZE)"),
+        ("include_deleted" = Option<bool>, Query, description = "Also list soft-deleted projects"),
+        ("fields" = Option<String>, Query, description = "Comma-separated fields to return (id, name, description, repository_url, language, rating, tags, created_at, updated_at, deleted_at)"),
+        ("include" = Option<String>, Query, description = "Comma-separated relations to embed in each project (technologies, users); projects are then returned as ProjectWithRelations"),
+        ("with_total" = Option<bool>, Query, description = "Count matches for total_items / total_pages (default true); false skips the count and returns them as null"),
     ),
     responses(
-        (status = 200, description = "Paginated list of projects", body = PaginatedResponse<Project>),
+        (status = 200, description = "Paginated list of projects", content(
+            (PaginatedResponse<Project> = "application/json"),
+            (String = "text/csv"),
+            (Document<Vec<Resource>> = "application/vnd.api+json")
+        ), headers(
+            ("link" = String, description = "RFC 8288 links to the first, prev, next and last pages")
+        )),
+        (status = 400, description = "Unknown sort field, order, tech_mode, match, field or include, more orders than fields, or an inverted date range", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
-#[tracing::instrument(skip(state))]
+#[tracing::instrument(skip(state, uri, request_headers))]
 pub async fn list_projects(
     State(state): State<AppState>,
-    Query(params): Query<ListQueryParams>,
-) -> Result<Json<PaginatedResponse<Project>>> {
-    use sqlx::QueryBuilder;
+    caller_role: Option<Extension<CallerRole>>,
+    uri: Uri,
+    request_headers: HeaderMap,
+    Query(mut params): Query<ListQueryParams>,
+) -> Result<(HeaderMap, ProjectListResponse)> {
+    params.validate_sort()?;
+    params.validate_date_ranges()?;
+    params.validate_tech_mode()?;
+    params.validate_match_mode()?;
+    params.validate_fields()?;
+    params.validate_includes()?;
+
+    params
+        .page_size
+        .get_or_insert(state.config.default_page_size(caller_role.as_deref()));
 
     // Pre-compute filter patterns to avoid lifetime issues
-    let search_pattern = params.search.as_ref().map(|s| format!("%{}%", s));
-    let tech_pattern = params.technology.as_ref().map(|t| format!("%{}%", t));
-    let lang_pattern = params.language.as_ref().map(|l| format!("%{}%", l));
+    // LIKE patterns escape `%` / `_` so user input only ever matches literally
+    let search_pattern = params.search.as_deref().map(|s| contains_pattern(&search_key(s)));
+    // `match=exact` compares language / technology with `=` instead of a substring LIKE
+    let exact_match = params.exact_match();
+    let (match_operator, match_escape) = if exact_match { (" = ", "") } else { (" LIKE ", LIKE_ESCAPE) };
+    let match_pattern = |value: &str| {
+        if exact_match {
+            value.to_string()
+        } else {
+            contains_pattern(value)
+        }
+    };
+
+    // One technology follows `match`; a list always matches exact names
+    let technologies = params.technologies();
+    let tech_pattern = match technologies.as_slice() {
+        [technology] => Some(match_pattern(technology)),
+        _ => None,
+    };
+    let require_all_technologies = params.requires_all_technologies();
+    let lang_pattern = params.language().map(|l| match_pattern(&l));
     let user_uuid_str = params.user_id.as_ref()
         .and_then(|id| Uuid::parse_str(id).ok())
         .map(|uuid| uuid.to_string());
 
-    // Build COUNT query using QueryBuilder for type safety
-    let mut count_builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-        "SELECT COUNT(*) as count FROM projects p WHERE 1=1"
-    );
+    let languages = params.languages();
+    let tag = params.tag();
 
-    // Build main query using QueryBuilder for type safety
-    let mut query_builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
-        "SELECT p.* FROM projects p WHERE 1=1"
-    );
+    let include_deleted = params.include_deleted();
 
-    // Apply filters to both queries
-    // Search filter
-    if let Some(ref pattern) = search_pattern {
-        count_builder.push(" AND (p.name LIKE ");
-        count_builder.push_bind(pattern);
-        count_builder.push(" OR p.description LIKE ");
-        count_builder.push_bind(pattern);
-        count_builder.push(")");
+    // `sort=relevance` searches through the FTS5 index; without a search term or
+    // the index it falls back to the LIKE filter and relevance is left out of ORDER BY
+    let fts_match = match params.search.as_deref().and_then(fts_query) {
+        Some(query) if params.sorts_by_relevance() && fts_available(&state.db).await? => Some(query),
+        _ => None,
+    };
 
-        query_builder.push(" AND (p.name LIKE ");
-        query_builder.push_bind(pattern);
-        query_builder.push(" OR p.description LIKE ");
-        query_builder.push_bind(pattern);
-        query_builder.push(")");
+    // Filters are applied identically to the count and main queries
+    let mut list_query = ListQuery::new("projects p");
+
+    // With `sort=relevance` the FTS matches are joined, which filters and ranks at once
+    if let Some(ref query) = fts_match {
+        // Name hits weigh twice as much as description hits (project_id is unindexed)
+        list_query.join(move |b| {
+            b.push(
+                " JOIN (SELECT project_id, bm25(projects_fts, 0.0, 2.0, 1.0) AS rank
+                FROM projects_fts WHERE projects_fts MATCH ",
+            );
+            b.push_bind(query.clone());
+            b.push(") fts ON fts.project_id = p.id");
+        });
     }
 
-    // Technology filter
-    if let Some(ref pattern) = tech_pattern {
-        let exists_clause = " AND EXISTS (
-            SELECT 1 FROM project_technologies pt
-            JOIN technologies t ON pt.technology_id = t.id
-            WHERE pt.project_id = p.id AND t.name LIKE ";
+    // Soft-deleted projects are hidden unless asked for
+    if !include_deleted {
+        list_query.filter(|b| {
+            b.push("p.deleted_at IS NULL");
+        });
+    }
 
-        count_builder.push(exists_clause);
-        count_builder.push_bind(pattern);
-        count_builder.push(")");
+    // Search filter
+    if let (None, Some(pattern)) = (&fts_match, &search_pattern) {
+        list_query.filter(move |b| {
+            b.push(format!("{} LIKE ", PROJECT_SEARCH_BLOB));
+            b.push_bind(pattern.clone());
+            b.push(LIKE_ESCAPE);
+        });
+    }
 
-        query_builder.push(exists_clause);
-        query_builder.push_bind(pattern);
-        query_builder.push(")");
+    // Technology filter
+    if let Some(ref pattern) = tech_pattern {
+        list_query.filter(move |b| {
+            b.push(
+                "EXISTS (
+                SELECT 1 FROM project_technologies pt
+                JOIN technologies t ON pt.technology_id = t.id
+                WHERE pt.project_id = p.id AND t.name_key",
+            );
+            b.push(match_operator);
+            b.push_bind(pattern.clone());
+            b.push(match_escape);
+            b.push(")");
+        });
+    } else if technologies.len() > 1 {
+        let technologies = &technologies;
+        list_query.filter(move |b| {
+            if require_all_technologies {
+                // Every listed technology must be attached
+                b.push(
+                    "(SELECT COUNT(DISTINCT t.name_key) FROM project_technologies pt
+                    JOIN technologies t ON pt.technology_id = t.id
+                    WHERE pt.project_id = p.id AND t.name_key IN (",
+                );
+            } else {
+                b.push(
+                    "EXISTS (
+                    SELECT 1 FROM project_technologies pt
+                    JOIN technologies t ON pt.technology_id = t.id
+                    WHERE pt.project_id = p.id AND t.name_key IN (",
+                );
+            }
+            let mut separated = b.separated(", ");
+            for technology in technologies {
+                separated.push_bind(technology.clone());
+            }
+            separated.push_unseparated("))");
+            if require_all_technologies {
+                b.push(" = ");
+                b.push_bind(technologies.len() as i64);
+            }
+        });
     }
 
     // User filter
     if let Some(ref uuid_str) = user_uuid_str {
-        let exists_clause = " AND EXISTS (
-            SELECT 1 FROM project_users pu
-            WHERE pu.project_id = p.id AND pu.user_id = ";
-
-        count_builder.push(exists_clause);
-        count_builder.push_bind(uuid_str);
-        count_builder.push(")");
-
-        query_builder.push(exists_clause);
-        query_builder.push_bind(uuid_str);
-        query_builder.push(")");
+        list_query.filter(move |b| {
+            b.push(
+                "EXISTS (
+                SELECT 1 FROM project_users pu
+                WHERE pu.project_id = p.id AND pu.user_id = ",
+            );
+            b.push_bind(uuid_str.clone());
+            b.push(")");
+        });
     }
 
     // Rating filters
     if let Some(min_rating) = params.min_rating {
-        count_builder.push(" AND p.rating >= ");
-        count_builder.push_bind(min_rating);
-
-        query_builder.push(" AND p.rating >= ");
-        query_builder.push_bind(min_rating);
+        list_query.filter(move |b| {
+            b.push("p.rating >= ");
+            b.push_bind(min_rating);
+        });
     }
 
     if let Some(max_rating) = params.max_rating {
-        count_builder.push(" AND p.rating <= ");
-        count_builder.push_bind(max_rating);
+        list_query.filter(move |b| {
+            b.push("p.rating <= ");
+            b.push_bind(max_rating);
+        });
+    }
 
-        query_builder.push(" AND p.rating <= ");
-        query_builder.push_bind(max_rating);
+    // Date range filters (inclusive)
+    for (column, operator, bound) in [
+        ("created_at", ">=", params.created_after),
+        ("created_at", "<=", params.created_before),
+        ("updated_at", ">=", params.updated_after),
+        ("updated_at", "<=", params.updated_before),
+    ] {
+        if let Some(bound) = bound {
+            list_query.filter(move |b| {
+                b.push(format!("p.{} {} ", column, operator));
+                b.push_bind(bound);
+            });
+        }
     }
 
-    // Language filter
+    // Language filter (case-insensitive, ignoring surrounding whitespace)
     if let Some(ref pattern) = lang_pattern {
-        count_builder.push(" AND p.language LIKE ");
-        count_builder.push_bind(pattern);
+        list_query.filter(move |b| {
+            b.push("lower(trim(p.language))");
+            b.push(match_operator);
+            b.push_bind(pattern.clone());
+            b.push(match_escape);
+        });
+    }
 
-        query_builder.push(" AND p.language LIKE ");
-        query_builder.push_bind(pattern);
+    // Multi-language filter (exact, case-insensitive match on any of them)
+    if !languages.is_empty() {
+        let languages = &languages;
+        list_query.filter(move |b| {
+            b.push("lower(trim(p.language)) IN (");
+            let mut separated = b.separated(", ");
+            for language in languages {
+                separated.push_bind(language.clone());
+            }
+            separated.push_unseparated(")");
+        });
     }
 
-    // Execute count query
-    let total_items: i64 = count_builder
-        .build()
-        .fetch_one(&state.db)
-        .await?
-        .try_get("count")?;
+    // Tag filter (tags are stored normalized in a JSON array)
+    if let Some(ref tag) = tag {
+        list_query.filter(move |b| {
+            b.push("EXISTS (SELECT 1 FROM json_each(p.tags) WHERE json_each.value = ");
+            b.push_bind(tag.clone());
+            b.push(")");
+        });
+    }
 
-    // Add sorting and pagination to main query
-    let sort_field = params.sort_field();
-    let sort_order = params.sort_order();
-    let limit = params.page_size();
-    let offset = params.offset();
+    // Execute count query, unless the client opted out of totals
+    let total_items: Option<i64> = if params.with_total() {
+        let total_items = list_query.count_query().build().fetch_one(&state.db).await?.try_get("count")?;
+        params.resolve_page(total_items, state.config.max_page_size, state.config.page_overflow)?;
+        Some(total_items)
+    } else {
+        None
+    };
 
-    query_builder.push(format!(" ORDER BY p.{} {}", sort_field, sort_order));
-    query_builder.push(" LIMIT ");
-    query_builder.push_bind(limit);
-    query_builder.push(" OFFSET ");
-    query_builder.push_bind(offset);
+    // Add sorting and pagination to main query
+    let mut order_by = params
+        .sort_clauses()
+        .into_iter()
+        .filter_map(|(field, order)| match field {
+            // bm25() scores are lower for better matches, so "most relevant first" is ASC
+            "relevance" => fts_match.as_ref().map(|_| {
+                format!("fts.rank {}", if order == "DESC" { "ASC" } else { "DESC" })
+            }),
+            _ => Some(format!("p.{} {}", field, order)),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    if order_by.is_empty() {
+        order_by = "p.created_at DESC".to_string();
+    }
+    let limit = params.page_size(state.config.max_page_size);
+    let offset = params.offset(state.config.max_page_size);
 
     // Execute main query
-    let projects = query_builder
+    let projects = list_query
+        .page_query("p.*", &order_by, limit, offset)
         .build_query_as::<Project>()
         .fetch_all(&state.db)
         .await?;
 
     tracing::info!(
-        "Listed {} projects (page {}, total {}) [QueryBuilder]",
+        "Listed {} projects (page {}, total {:?}) [QueryBuilder]",
         projects.len(),
         params.page(),
         total_items
     );
 
-    Ok(Json(PaginatedResponse::new(
-        projects,
-        params.page(),
-        params.page_size(),
-        total_items,
-    )))
+    let mut response = match total_items {
+        Some(total_items) => PaginatedResponse::new(projects, params.page(), limit, total_items),
+        None => PaginatedResponse::without_total(projects, params.page(), limit),
+    };
+
+    // Page links let generic HTTP clients paginate without reading the body; the
+    // body format depends on `Accept`, so caches must key on it
+    let mut headers = HeaderMap::new();
+    headers.insert(header::VARY, HeaderValue::from_static("accept"));
+    if let Ok(link) = HeaderValue::from_str(&response.link_header(&uri)) {
+        headers.insert(header::LINK, link);
+    }
+
+    // The `tech` alias still works, but tells clients to migrate to `technology`
+    if params.tech.is_some() {
+        headers.insert("deprecation", HeaderValue::from_static("true"));
+        headers.insert("sunset", HeaderValue::from_static(TECH_ALIAS_SUNSET));
+        response.warnings.push(format!(
+            "The 'tech' query parameter is deprecated and will be removed after {}; use 'technology' instead",
+            TECH_ALIAS_SUNSET
+        ));
+    }
+
+    let fields = params.fields();
+    match response_format(&request_headers) {
+        ResponseFormat::Csv => {
+            return Ok((headers, ProjectListResponse::Csv(projects_to_csv(&response.data, &fields))));
+        }
+        ResponseFormat::JsonApi => {
+            let data = project_resources(&state, &response.data, &fields).await?;
+            return Ok((headers, ProjectListResponse::JsonApi(Document::page(data, &response))));
+        }
+        ResponseFormat::Json => {}
+    }
+
+    // Relations of the whole page are loaded with one query each, whatever the page size
+    let includes = params.includes();
+    if includes.is_empty() && fields.is_empty() {
+        return Ok((headers, ProjectListResponse::Json(response)));
+    }
+    let PaginatedResponse { data, pagination, warnings } = response;
+    let data = with_relations(&state, data, &includes).await?;
+    if fields.is_empty() {
+        return Ok((headers, ProjectListResponse::WithRelations(PaginatedResponse { data, pagination, warnings })));
+    }
+
+    // Included relations are kept alongside the requested fields
+    let keep: Vec<&str> = fields.iter().chain(&includes).copied().collect();
+    let data = data
+        .iter()
+        .map(|project| sparse_project(project, &keep))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((headers, ProjectListResponse::Fields(PaginatedResponse { data, pagination, warnings })))
 }
 
 /// Get a specific project by ID with related data
@@ -231,27 +898,96 @@ pub async fn list_projects(
 /// # Arguments
 /// - `id` - UUID of the project
 ///
+/// # Headers
+/// - `If-None-Match` - ETag from a previous response; a match yields `304 Not Modified`
+/// - `Accept: application/vnd.api+json` - Return a JSON:API compound document, technologies
+///   and users as `relationships` and `included` resources
+///
 /// # Returns
-/// - `200 OK` - Project details with technologies and users
+/// - `200 OK` - Project details with technologies and users, plus an `ETag` header
+/// - `304 Not Modified` - The project is unchanged since the given ETag
 /// - `404 Not Found` - Project not found
 #[utoipa::path(
     get,
     path = "/projects/{id}",
     tag = "projects",
     params(
-        ("id" = Uuid, Path, description = "Project UUID")
+        ("id" = Uuid, Path, description = "Project UUID"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of a cached copy")
     ),
     responses(
-        (status = 200, description = "Project found", body = ProjectWithRelations),
+        (status = 200, description = "Project found", content(
+            (ProjectWithRelations = "application/json"),
+            (Document<Resource> = "application/vnd.api+json")
+        ), headers(
+            ("ETag" = String, description = "Entity tag of the returned payload and media type"),
+            ("Vary" = String, description = "Always `accept`: the body depends on the Accept header")
+        )),
+        (status = 304, description = "Project not modified"),
         (status = 404, description = "Project not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
-#[tracing::instrument(skip(state))]
+#[tracing::instrument(skip(state, headers))]
 pub async fn get_project(
     State(state): State<AppState>,
-    Path(id): Path<Uuid>,
-) -> Result<Json<ProjectWithRelations>> {
+    ValidatedUuid(id): ValidatedUuid,
+    headers: HeaderMap,
+) -> Result<Response> {
+    let project = fetch_project_with_relations(&state, id).await?;
+    // A single project has no CSV form
+    let format = match response_format(&headers) {
+        ResponseFormat::JsonApi => ResponseFormat::JsonApi,
+        ResponseFormat::Json | ResponseFormat::Csv => ResponseFormat::Json,
+    };
+    let etag = project_etag(&project, format)?;
+    let cache_headers = [(header::ETAG, etag.clone()), (header::VARY, HeaderValue::from_static("accept"))];
+
+    if if_none_match(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+
+    if format == ResponseFormat::JsonApi {
+        let document = Document::project(&project)
+            .map_err(|e| AppError::InternalError(format!("Failed to serialize project: {}", e)))?;
+        return Ok((cache_headers, json_api_response(document)).into_response());
+    }
+
+    Ok((cache_headers, Json(project)).into_response())
+}
+
+/// Computes a strong ETag over the serialized project payload and its media type
+///
+/// Hashing the whole payload (rather than just `updated_at`) means attaching or
+/// detaching technologies and users also changes the tag, and the media type keeps
+/// the JSON and JSON:API representations from sharing one. FNV-1a keeps the value
+/// stable across processes and Rust releases, unlike `DefaultHasher`.
+fn project_etag(project: &ProjectWithRelations, format: ResponseFormat) -> Result<HeaderValue> {
+    let bytes = serde_json::to_vec(project)
+        .map_err(|e| AppError::InternalError(format!("Failed to serialize project: {}", e)))?;
+    let payload = format.media_type().as_bytes().iter().chain(&bytes);
+    let hash = payload.fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+
+    HeaderValue::from_str(&format!("\"{:016x}\"", hash))
+        .map_err(|e| AppError::InternalError(format!("Invalid ETag: {}", e)))
+}
+
+/// Whether any entity tag in `If-None-Match` matches `etag` (weak comparison)
+fn if_none_match(headers: &HeaderMap, etag: &HeaderValue) -> bool {
+    let Some(value) = headers.get(header::IF_NONE_MATCH).and_then(|v| v.to_str().ok()) else {
+        return false;
+    };
+    let etag = etag.to_str().unwrap_or_default();
+
+    value.split(',').map(str::trim).any(|candidate| {
+        candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+    })
+}
+
+/// Loads a live project together with its technologies and users
+async fn fetch_project_with_relations(state: &AppState, id: Uuid) -> Result<ProjectWithRelations> {
     use std::collections::HashMap;
 
     // Single optimized query with LEFT JOINs to fetch everything at once
@@ -260,18 +996,20 @@ pub async fn get_project(
     let rows = sqlx::query_as::<_, ProjectWithRelationsRow>(
         "SELECT
             p.id as project_id, p.name as project_name, p.description as project_description,
-            p.repository_url, p.language, p.rating, p.created_at as project_created_at,
+            p.repository_url, p.language, p.rating, p.tags as project_tags, p.created_at as project_created_at,
             p.updated_at as project_updated_at,
             t.id as tech_id, t.name as tech_name, t.description as tech_description,
-            t.created_at as tech_created_at,
+            t.created_at as tech_created_at, t.updated_at as tech_updated_at, pt.created_at as tech_associated_at,
             u.id as user_id, u.name as user_name, u.email as user_email,
-            u.created_at as user_created_at, pu.role
+            u.avatar_url as user_avatar_url, u.bio as user_bio,
+            u.created_at as user_created_at, u.updated_at as user_updated_at, pu.role,
+            pu.created_at as user_associated_at
          FROM projects p
          LEFT JOIN project_technologies pt ON p.id = pt.project_id
          LEFT JOIN technologies t ON pt.technology_id = t.id
          LEFT JOIN project_users pu ON p.id = pu.project_id
          LEFT JOIN users u ON pu.user_id = u.id
-         WHERE p.id = ?
+         WHERE p.id = ? AND p.deleted_at IS NULL
          ORDER BY t.name ASC, u.name ASC"
     )
     .bind(id.to_string())
@@ -295,8 +1033,10 @@ pub async fn get_project(
         repository_url: first_row.repository_url.clone(),
         language: first_row.language.clone(),
         rating: first_row.rating,
+        tags: decode_tags(first_row.project_tags.clone())?,
         created_at: first_row.project_created_at,
         updated_at: first_row.project_updated_at,
+        deleted_at: None,
     };
 
     // Group technologies and users from results (handling duplicates from JOINs)
@@ -307,36 +1047,45 @@ pub async fn get_project(
         // Extract technology if present (LEFT JOIN may return NULL)
         if let Some(tech_id_str) = &row.tech_id
             && let Ok(tech_id) = Uuid::parse_str(tech_id_str)
-                && let (Some(tech_name), Some(tech_created_at)) = (&row.tech_name, &row.tech_created_at) {
-                    technologies_map.entry(tech_id).or_insert_with(|| Technology {
-                        id: tech_id,
-                        name: tech_name.clone(),
-                        description: row.tech_description.clone(),
-                        created_at: *tech_created_at,
+                && let (Some(tech_name), Some(tech_created_at), Some(associated_at)) =
+                    (&row.tech_name, &row.tech_created_at, &row.tech_associated_at) {
+                    technologies_map.entry(tech_id).or_insert_with(|| ProjectTechnology {
+                        technology: Technology {
+                            id: tech_id,
+                            name: tech_name.clone(),
+                            description: row.tech_description.clone(),
+                            created_at: *tech_created_at,
+                            updated_at: row.tech_updated_at.unwrap_or(*tech_created_at),
+                        },
+                        associated_at: *associated_at,
                     });
                 }
 
         // Extract user if present (LEFT JOIN may return NULL)
         if let Some(user_id_str) = &row.user_id
             && let Ok(user_id) = Uuid::parse_str(user_id_str)
-                && let (Some(user_name), Some(user_email), Some(user_created_at), Some(role_str)) =
-                    (&row.user_name, &row.user_email, &row.user_created_at, &row.role)
+                && let (Some(user_name), Some(user_email), Some(user_created_at), Some(role_str), Some(associated_at)) =
+                    (&row.user_name, &row.user_email, &row.user_created_at, &row.role, &row.user_associated_at)
                     && let Ok(role) = UserRole::from_str(role_str) {
                         users_map.entry(user_id).or_insert_with(|| UserWithRole {
                             user: User {
                                 id: user_id,
                                 name: user_name.clone(),
                                 email: user_email.clone(),
+                                avatar_url: row.user_avatar_url.clone(),
+                                bio: row.user_bio.clone(),
                                 created_at: *user_created_at,
+                                updated_at: row.user_updated_at.unwrap_or(*user_created_at),
                             },
                             role,
+                            associated_at: *associated_at,
                         });
                     }
     }
 
     // Convert HashMaps to sorted Vecs
-    let mut technologies: Vec<Technology> = technologies_map.into_values().collect();
-    technologies.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut technologies: Vec<ProjectTechnology> = technologies_map.into_values().collect();
+    technologies.sort_by(|a, b| a.technology.name.cmp(&b.technology.name));
 
     let mut users: Vec<UserWithRole> = users_map.into_values().collect();
     users.sort_by(|a, b| a.user.name.cmp(&b.user.name));
@@ -346,543 +1095,5406 @@ pub async fn get_project(
         id, technologies.len(), users.len()
     );
 
-    Ok(Json(ProjectWithRelations {
+    Ok(ProjectWithRelations {
         project,
         technologies,
         users,
-    }))
+        warnings: Vec::new(),
+    })
 }
 
-/// Create a new project with optional technologies and users
+/// List projects that share technologies with a project
 ///
 /// # Endpoint
-/// POST /projects
+/// GET /projects/{id}/similar?limit=10
 ///
-/// # Request Body
-/// ```json
-/// {
-///   "name": "My Project",
-///   "description": "A sample project",
-///   "repository_url": "https://github.com/user/repo",
-///   "language": "Rust",
-///   "rating": 4.5,
-///   "technology_ids": ["uuid1", "uuid2"],
-///   "user_ids": ["uuid3", "uuid4"]
-/// }
-/// ```
+/// # Arguments
+/// - `id` - UUID of the project to find related projects for
+///
+/// # Query Parameters
+/// - `limit` - Maximum number of projects to return (default: 10; default max 100, configurable via `MAX_PAGE_SIZE`)
+///
+/// Projects are ordered by how many technologies they share with the target,
+/// then by name. Projects sharing none are left out, so a project without
+/// technologies has no similar projects.
 ///
 /// # Returns
-/// - `201 Created` - Created project with relations
-/// - `400 Bad Request` - Validation error
-/// - `404 Not Found` - Technology or user not found
+/// - `200 OK` - Similar projects, most overlap first
+/// - `404 Not Found` - Project not found
 #[utoipa::path(
-    post,
-    path = "/projects",
+    get,
+    path = "/projects/{id}/similar",
     tag = "projects",
-    request_body = CreateProjectRequest,
+    params(
+        ("id" = Uuid, Path, description = "Project UUID"),
+        ("limit" = Option<u32>, Query, description = "Maximum number of projects (default 10; default max 100, configurable via MAX_PAGE_SIZE)")
+    ),
     responses(
-        (status = 201, description = "Project created successfully", body = ProjectWithRelations),
-        (status = 400, description = "Validation error", body = ErrorResponse),
-        (status = 404, description = "Technology or user not found", body = ErrorResponse),
+        (status = 200, description = "Similar projects, most shared technologies first", body = [Project]),
+        (status = 404, description = "Project not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
 #[tracing::instrument(skip(state))]
-pub async fn create_project(
+pub async fn get_similar_projects(
     State(state): State<AppState>,
-    Json(request): Json<CreateProjectRequest>,
-) -> Result<(StatusCode, Json<ProjectWithRelations>)> {
-    // Validate request
-    request.validate()?;
-
-    // Validate technology IDs exist
-    if let Some(ref tech_ids) = request.technology_ids {
-        for tech_id in tech_ids {
-            let exists = sqlx::query("SELECT 1 FROM technologies WHERE id = ?")
-                .bind(tech_id.to_string())
-                .fetch_optional(&state.db)
-                .await?;
-            if exists.is_none() {
-                return Err(AppError::TechnologyNotFound(tech_id.to_string()));
-            }
-        }
+    Path(id): Path<Uuid>,
+    Query(query): Query<SimilarProjectsQuery>,
+) -> Result<Json<Vec<Project>>> {
+    let exists = sqlx::query("SELECT 1 FROM projects WHERE id = ? AND deleted_at IS NULL")
+        .bind(id.to_string())
+        .fetch_optional(&state.db)
+        .await?;
+    if exists.is_none() {
+        return Err(AppError::ProjectNotFound(id.to_string()));
     }
 
-    // Validate user IDs exist
-    if let Some(ref user_ids) = request.user_ids {
-        for user_id in user_ids {
-            let exists = sqlx::query("SELECT 1 FROM users WHERE id = ?")
-                .bind(user_id.to_string())
-                .fetch_optional(&state.db)
-                .await?;
-            if exists.is_none() {
-                return Err(AppError::UserNotFound(user_id.to_string()));
-            }
-        }
-    }
+    let projects = sqlx::query_as::<_, Project>(
+        "SELECT p.* FROM projects p
+         JOIN (
+             SELECT other.project_id, COUNT(*) AS shared
+             FROM project_technologies target
+             JOIN project_technologies other ON other.technology_id = target.technology_id
+             WHERE target.project_id = ? AND other.project_id != target.project_id
+             GROUP BY other.project_id
+         ) overlap ON overlap.project_id = p.id
+         WHERE p.deleted_at IS NULL
+         ORDER BY overlap.shared DESC, p.name ASC
+         LIMIT ?"
+    )
+    .bind(id.to_string())
+    .bind(query.limit(state.config.max_page_size))
+    .fetch_all(&state.db)
+    .await?;
 
-    // Create new project
-    let project = Project::new(request.clone());
+    tracing::info!("Found {} projects similar to {}", projects.len(), id);
+    Ok(Json(projects))
+}
 
-    // Insert into database
-    sqlx::query(
-        "INSERT INTO projects (id, name, description, repository_url, language, rating, created_at, updated_at)
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+/// Projects read per query while exporting
+const EXPORT_BATCH_SIZE: u32 = 100;
+
+/// Export every project with its relations as a downloadable JSON array
+///
+/// # Endpoint
+/// GET /projects/export
+///
+/// Soft-deleted projects are included (with their `deleted_at`). Projects are read
+/// in batches ordered by id and streamed out as they are serialized, so the export
+/// is never held in memory as a whole.
+///
+/// # Returns
+/// - `200 OK` - JSON array of projects with technologies and users, sent as an attachment
+#[utoipa::path(
+    get,
+    path = "/projects/export",
+    tag = "projects",
+    responses(
+        (status = 200, description = "All projects with their relations", body = [ProjectWithRelations],
+            headers(("Content-Disposition" = String, description = "attachment; filename=\"projects-export.json\""))),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
     )
-    .bind(project.id.to_string())
-    .bind(&project.name)
-    .bind(&project.description)
-    .bind(&project.repository_url)
-    .bind(&project.language)
-    .bind(project.rating)
-    .bind(project.created_at)
-    .bind(project.updated_at)
-    .execute(&state.db)
-    .await?;
+)]
+#[tracing::instrument(skip(state))]
+pub async fn export_projects(State(state): State<AppState>) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, "application/json"),
+            (header::CONTENT_DISPOSITION, "attachment; filename=\"projects-export.json\""),
+        ],
+        axum::body::Body::from_stream(export_stream(state, EXPORT_BATCH_SIZE)),
+    )
+        .into_response()
+}
 
-    // Associate technologies
-    let mut technologies = Vec::new();
-    if let Some(tech_ids) = request.technology_ids {
-        let now = Utc::now();
-        for tech_id in &tech_ids {
-            sqlx::query(
-                "INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)"
-            )
-            .bind(project.id.to_string())
-            .bind(tech_id.to_string())
-            .bind(now)
-            .execute(&state.db)
-            .await?;
+/// Chunks of the export's JSON array, one per batch of `batch_size` projects
+///
+/// A failing batch ends the stream with the error, which aborts the response.
+fn export_stream(state: AppState, batch_size: u32) -> impl futures::Stream<Item = Result<String>> {
+    struct Cursor {
+        state: AppState,
+        after: Option<String>,
+        exported: usize,
+        done: bool,
+    }
+
+    let cursor = Cursor {
+        state,
+        after: None,
+        exported: 0,
+        done: false,
+    };
+
+    futures::stream::try_unfold(cursor, move |mut cursor| async move {
+        if cursor.done {
+            return Ok(None);
         }
 
-        // Fetch the technologies
-        for tech_id in tech_ids {
-            if let Some(tech) = sqlx::query_as::<_, Technology>("SELECT * FROM technologies WHERE id = ?")
-                .bind(tech_id.to_string())
-                .fetch_optional(&state.db)
-                .await?
-            {
-                technologies.push(tech);
+        let projects = sqlx::query_as::<_, Project>(
+            "SELECT * FROM projects WHERE id > COALESCE(?, '') ORDER BY id ASC LIMIT ?"
+        )
+        .bind(cursor.after.as_deref())
+        .bind(batch_size)
+        .fetch_all(&cursor.state.db)
+        .await?;
+        cursor.done = projects.len() < batch_size as usize;
+        cursor.after = projects.last().map(|project| project.id.to_string());
+
+        let mut chunk = String::new();
+        if cursor.exported == 0 {
+            chunk.push('[');
+        }
+        for project in with_relations(&cursor.state, projects, &PROJECT_INCLUDES).await? {
+            if cursor.exported > 0 {
+                chunk.push(',');
             }
+            let json = serde_json::to_string(&project)
+                .map_err(|e| AppError::InternalError(format!("Failed to serialize project: {}", e)))?;
+            chunk.push_str(&json);
+            cursor.exported += 1;
+        }
+        if cursor.done {
+            chunk.push(']');
+            tracing::info!("Exported {} projects", cursor.exported);
         }
-    }
 
-    // Associate users (all as contributors by default, first one as owner if any)
-    let mut users = Vec::new();
-    if let Some(user_ids) = request.user_ids {
-        let now = Utc::now();
-        for (idx, user_id) in user_ids.iter().enumerate() {
-            let role = if idx == 0 {
-                UserRole::Owner
-            } else {
-                UserRole::Contributor
-            };
+        Ok(Some((chunk, cursor)))
+    })
+}
 
-            sqlx::query(
-                "INSERT INTO project_users (project_id, user_id, role, created_at) VALUES (?, ?, ?, ?)"
-            )
-            .bind(project.id.to_string())
-            .bind(user_id.to_string())
-            .bind(role.as_str())
-            .bind(now)
-            .execute(&state.db)
-            .await?;
+/// Loads the `relations` (technologies, users) of a batch of projects, one query per relation
+///
+/// Relations are ordered by name, as in `get_project`; those not asked for are left empty.
+async fn with_relations(
+    state: &AppState,
+    projects: Vec<Project>,
+    relations: &[&str],
+) -> Result<Vec<ProjectWithRelations>> {
+    use std::collections::HashMap;
 
-            // Fetch the user
-            if let Some(user) = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = ?")
-                .bind(user_id.to_string())
-                .fetch_optional(&state.db)
-                .await?
-            {
-                users.push(UserWithRole { user, role });
-            }
+    if projects.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let query = |select: &'static str| {
+        let mut builder: sqlx::QueryBuilder<sqlx::Sqlite> = sqlx::QueryBuilder::new(select);
+        let mut separated = builder.separated(", ");
+        for project in &projects {
+            separated.push_bind(project.id.to_string());
+        }
+        separated.push_unseparated(") ORDER BY name ASC");
+        builder
+    };
+
+    let mut technologies: HashMap<String, Vec<ProjectTechnology>> = HashMap::new();
+    if relations.contains(&"technologies") {
+        let rows = query(
+            "SELECT pt.project_id, t.id, t.name, t.description, t.created_at, t.updated_at, pt.created_at AS associated_at
+             FROM project_technologies pt
+             JOIN technologies t ON t.id = pt.technology_id
+             WHERE pt.project_id IN (",
+        )
+        .build()
+        .fetch_all(&state.db)
+        .await?;
+        for row in rows {
+            technologies
+                .entry(row.try_get("project_id")?)
+                .or_default()
+                .push(ProjectTechnology::from_row(&row)?);
         }
     }
 
-    tracing::info!("Created project: {}", project.id);
+    let mut users: HashMap<String, Vec<UserWithRole>> = HashMap::new();
+    if relations.contains(&"users") {
+        let rows = query(
+            "SELECT pu.project_id, u.id, u.name, u.email, u.avatar_url, u.bio, u.created_at, u.updated_at, pu.role,
+                    pu.created_at AS associated_at
+             FROM project_users pu
+             JOIN users u ON u.id = pu.user_id
+             WHERE pu.project_id IN (",
+        )
+        .build()
+        .fetch_all(&state.db)
+        .await?;
+        for row in rows {
+            let role: String = row.try_get("role")?;
+            let role = UserRole::from_str(&role).map_err(|e| AppError::InternalError(format!("Invalid role: {}", e)))?;
+            users.entry(row.try_get("project_id")?).or_default().push(UserWithRole {
+                user: User::from_row(&row)?,
+                role,
+                associated_at: row.try_get("associated_at")?,
+            });
+        }
+    }
 
-    Ok((
-        StatusCode::CREATED,
-        Json(ProjectWithRelations {
-            project,
-            technologies,
-            users,
-        }),
-    ))
+    Ok(projects
+        .into_iter()
+        .map(|project| {
+            let key = project.id.to_string();
+            ProjectWithRelations {
+                technologies: technologies.remove(&key).unwrap_or_default(),
+                users: users.remove(&key).unwrap_or_default(),
+                project,
+                warnings: Vec::new(),
+            }
+        })
+        .collect())
 }
 
-/// Update an existing project
+/// Import projects from an export
 ///
 /// # Endpoint
-/// PUT /projects/{id}
-///
-/// # Arguments
-/// - `id` - UUID of the project to update
+/// POST /projects/import
 ///
 /// # Request Body
-/// All fields are optional. If technology_ids or user_ids are provided, they replace existing associations.
-/// ```json
-/// {
-///   "name": "Updated Name",
-///   "description": "Updated description",
-///   "repository_url": "https://github.com/user/new-repo",
-///   "language": "Python",
-///   "rating": 4.8,
-///   "technology_ids": ["uuid1", "uuid2"],
-///   "user_ids": ["uuid3"]
-/// }
-/// ```
+/// The output of `GET /projects/export`: a JSON array of projects with their
+/// technologies and users, or the same records as newline-delimited JSON.
+///
+/// Each record is upserted by id (including its timestamps and `deleted_at`) and its
+/// technology and user associations are replaced by the ones in the record.
+/// Technologies are matched by id, then by name; users must already exist. All
+/// records are written in one transaction.
+///
+/// # Query Parameters
+/// - `partial` - Skip invalid records and list them in `errors` instead of rejecting the import
+/// - `create_technologies` - Create technologies that match neither an id nor a name
 ///
 /// # Returns
-/// - `200 OK` - Updated project with relations
-/// - `404 Not Found` - Project, technology, or user not found
-/// - `400 Bad Request` - Validation error
+/// - `200 OK` - Counts of inserted, updated and skipped records
+/// - `400 Bad Request` - Malformed or invalid record (without `partial`)
+/// - `403 Forbidden` - Caller is not an admin, or the project quota was reached
+/// - `404 Not Found` - Technology or user not found (without `partial`)
 #[utoipa::path(
-    put,
-    path = "/projects/{id}",
+    post,
+    path = "/projects/import",
     tag = "projects",
     params(
-        ("id" = Uuid, Path, description = "Project UUID")
+        ("partial" = Option<bool>, Query, description = "Skip invalid records and report them instead of failing"),
+        ("create_technologies" = Option<bool>, Query, description = "Create technologies missing by both id and name"),
     ),
-    request_body = UpdateProjectRequest,
+    request_body(content = [ProjectWithRelations], description = "JSON array or NDJSON of exported projects"),
     responses(
-        (status = 200, description = "Project updated successfully", body = ProjectWithRelations),
-        (status = 400, description = "Validation error", body = ErrorResponse),
-        (status = 404, description = "Project, technology, or user not found", body = ErrorResponse),
+        (status = 200, description = "Import summary", body = ImportSummary),
+        (status = 400, description = "Malformed or invalid record", body = ErrorResponse),
+        (status = 403, description = "Caller is not an admin, or project quota reached", body = ErrorResponse),
+        (status = 404, description = "Technology or user not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
-#[tracing::instrument(skip(state))]
-pub async fn update_project(
+#[tracing::instrument(skip(state, body))]
+pub async fn import_projects(
     State(state): State<AppState>,
-    Path(id): Path<Uuid>,
-    Json(update): Json<UpdateProjectRequest>,
-) -> Result<Json<ProjectWithRelations>> {
-    // Validate request
-    update.validate()?;
+    caller: Option<AuthUser>,
+    Query(options): Query<ImportOptions>,
+    body: String,
+) -> Result<Json<ImportSummary>> {
+    // Imports overwrite projects regardless of ownership
+    if let Some(caller) = caller {
+        caller.require_admin()?;
+    }
 
-    // Validate technology IDs exist
-    if let Some(ref tech_ids) = update.technology_ids {
-        for tech_id in tech_ids {
-            let exists = sqlx::query("SELECT 1 FROM technologies WHERE id = ?")
-                .bind(tech_id.to_string())
-                .fetch_optional(&state.db)
-                .await?;
-            if exists.is_none() {
-                return Err(AppError::TechnologyNotFound(tech_id.to_string()));
+    let records = parse_import(&body)?;
+
+    // Find each record's first unknown user before taking the write transaction;
+    // the lookups fan out over the pool, capped at `max_concurrent_db_ops`
+    let user_ids: Vec<Vec<Uuid>> = records
+        .iter()
+        .map(|record| match record {
+            Ok(record) => record.users.iter().map(|member| member.user.id).collect(),
+            Err(_) => Vec::new(),
+        })
+        .collect();
+    let missing_users = state
+        .bounded_db_ops(user_ids, |user_ids| {
+            let db = state.db.clone();
+            async move {
+                let mut conn = db.acquire().await?;
+                first_missing_id(&mut conn, "users", &user_ids).await
+            }
+        })
+        .await?;
+
+    let mut summary = ImportSummary::default();
+    let mut tx = state.db.begin().await?;
+
+    for (index, (record, missing_user)) in records.into_iter().zip(missing_users).enumerate() {
+        let id = record.as_ref().ok().map(|record| record.project.id);
+        let result = match (record, missing_user) {
+            (Ok(_), Some(missing)) => Err(AppError::UserNotFound(missing.to_string())),
+            (Ok(record), None) => {
+                // Each record gets a savepoint so a skipped one leaves nothing behind
+                let mut savepoint = sqlx::Connection::begin(&mut *tx).await?;
+                match import_record(&mut savepoint, &state, &options, &record).await {
+                    Ok(inserted) => {
+                        savepoint.commit().await?;
+                        Ok(inserted)
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            (Err(message), _) => Err(AppError::ValidationError(message)),
+        };
+
+        match result {
+            Ok(true) => summary.inserted += 1,
+            Ok(false) => summary.updated += 1,
+            Err(e) if options.partial && !matches!(e, AppError::DatabaseError { .. }) => {
+                summary.skipped += 1;
+                summary.errors.push(ImportRecordError {
+                    index,
+                    id,
+                    message: e.to_string(),
+                });
+            }
+            Err(e) => {
+                return Err(match e {
+                    AppError::ValidationError(message) => AppError::ValidationError(format!("Record {}: {}", index, message)),
+                    AppError::TechnologyNotFound(id) => AppError::TechnologyNotFound(format!("{} (record {})", id, index)),
+                    AppError::UserNotFound(id) => AppError::UserNotFound(format!("{} (record {})", id, index)),
+                    other => other,
+                });
             }
         }
     }
 
-    // Validate user IDs exist
-    if let Some(ref user_ids) = update.user_ids {
-        for user_id in user_ids {
-            let exists = sqlx::query("SELECT 1 FROM users WHERE id = ?")
-                .bind(user_id.to_string())
-                .fetch_optional(&state.db)
+    tx.commit().await?;
+
+    tracing::info!(
+        "Imported projects: {} inserted, {} updated, {} skipped",
+        summary.inserted,
+        summary.updated,
+        summary.skipped
+    );
+    Ok(Json(summary))
+}
+
+/// Splits an import payload into records, keeping each malformed one as an error message
+///
+/// A payload starting with `[` is a JSON array; anything else is read as NDJSON,
+/// one record per non-blank line.
+fn parse_import(body: &str) -> Result<Vec<std::result::Result<ProjectWithRelations, String>>> {
+    let body = body.trim();
+    if body.starts_with('[') {
+        let values: Vec<Value> = serde_json::from_str(body)
+            .map_err(|e| AppError::ValidationError(format!("Import is not a JSON array: {}", e)))?;
+        return Ok(values
+            .into_iter()
+            .map(|value| serde_json::from_value(value).map_err(|e| format!("Malformed record: {}", e)))
+            .collect());
+    }
+
+    Ok(body
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(|e| format!("Malformed record: {}", e)))
+        .collect())
+}
+
+/// Upserts one exported project and replaces its associations
+///
+/// Returns whether the project was inserted (rather than updated).
+async fn import_record(
+    conn: &mut SqliteConnection,
+    state: &AppState,
+    options: &ImportOptions,
+    record: &ProjectWithRelations,
+) -> Result<bool> {
+    let project = &record.project;
+    CreateProjectRequest {
+        name: project.name.clone(),
+        description: project.description.clone(),
+        repository_url: project.repository_url.clone(),
+        language: project.language.clone(),
+        rating: project.rating,
+        tags: Some(project.tags.clone()),
+        technology_ids: None,
+        user_ids: None,
+        users: None,
+    }
+    .validate()?;
+    check_rating_requirement(state, &project.language, project.rating)?;
+
+    // Technologies by id, then by name, optionally created
+    let mut technology_ids: Vec<(Uuid, DateTime<Utc>)> = Vec::new();
+    for ProjectTechnology { technology, associated_at } in &record.technologies {
+        let existing: Option<String> = sqlx::query_scalar(
+            "SELECT id FROM technologies WHERE id = ? OR name_key = ? ORDER BY id = ? DESC LIMIT 1"
+        )
+        .bind(technology.id.to_string())
+        .bind(Technology::name_key(&technology.name))
+        .bind(technology.id.to_string())
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        let id = match existing {
+            Some(id) => Uuid::parse_str(&id).map_err(|e| AppError::InternalError(format!("Invalid technology UUID: {}", e)))?,
+            None if options.create_technologies => {
+                sqlx::query(
+                    "INSERT INTO technologies (id, name, name_key, description, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)"
+                )
+                .bind(technology.id.to_string())
+                .bind(&technology.name)
+                .bind(Technology::name_key(&technology.name))
+                .bind(&technology.description)
+                .bind(technology.created_at)
+                .bind(technology.updated_at)
+                .execute(&mut *conn)
                 .await?;
-            if exists.is_none() {
-                return Err(AppError::UserNotFound(user_id.to_string()));
+                technology.id
             }
+            None => return Err(AppError::TechnologyNotFound(technology.id.to_string())),
+        };
+        if !technology_ids.iter().any(|(existing, _)| *existing == id) {
+            technology_ids.push((id, *associated_at));
         }
     }
 
-    // Fetch existing project
-    let mut project = sqlx::query_as::<_, Project>("SELECT * FROM projects WHERE id = ?")
-        .bind(id.to_string())
-        .fetch_optional(&state.db)
+    // Also checked before the import starts; repeated here in case a user was deleted meanwhile
+    if let Some(missing) = first_missing_id(&mut *conn, "users", record.users.iter().map(|member| &member.user.id)).await? {
+        return Err(AppError::UserNotFound(missing.to_string()));
+    }
+
+    let exists = sqlx::query("SELECT 1 FROM projects WHERE id = ?")
+        .bind(project.id.to_string())
+        .fetch_optional(&mut *conn)
         .await?
-        .ok_or_else(|| AppError::ProjectNotFound(id.to_string()))?;
+        .is_some();
 
-    // Update project fields
-    project.update(update.clone());
+    // Same quota check as `insert_project`; soft-deleted records do not count
+    let max_projects = state.config.max_projects.map(|max| max as i64);
+    let quota_reached = || {
+        AppError::QuotaExceeded(format!(
+            "Project quota reached: at most {} projects may exist",
+            max_projects.unwrap_or_default()
+        ))
+    };
 
-    // Update in database
-    sqlx::query(
-        "UPDATE projects SET name = ?, description = ?, repository_url = ?, language = ?, rating = ?, updated_at = ?
-         WHERE id = ?"
-    )
-    .bind(&project.name)
-    .bind(&project.description)
-    .bind(&project.repository_url)
-    .bind(&project.language)
-    .bind(project.rating)
-    .bind(project.updated_at)
-    .bind(id.to_string())
-    .execute(&state.db)
-    .await?;
+    if exists {
+        // Records carry the effective rating; one equal to the stored rating (e.g. a
+        // review average) is not a new manual rating, so the manual one is kept.
+        // Reviving a soft-deleted project counts against the quota like an insert.
+        let updated = sqlx::query(
+            "UPDATE projects SET name = ?, description = ?, repository_url = ?, language = ?,
+             rating = COALESCE((SELECT AVG(score) FROM reviews WHERE reviews.project_id = projects.id),
+                               CASE WHEN ? IS rating THEN manual_rating ELSE ? END, ?),
+             manual_rating = CASE WHEN ? IS rating THEN manual_rating ELSE ? END,
+             tags = ?, search_blob = ?, created_at = ?, deleted_at = ?
+             WHERE id = ?
+             AND (? IS NULL OR ? IS NOT NULL OR deleted_at IS NULL
+                  OR (SELECT COUNT(*) FROM projects WHERE deleted_at IS NULL) < ?)"
+        )
+        .bind(&project.name)
+        .bind(&project.description)
+        .bind(&project.repository_url)
+        .bind(&project.language)
+        .bind(project.rating)
+        .bind(project.rating)
+        .bind(state.config.default_rating)
+        .bind(project.rating)
+        .bind(project.rating)
+        .bind(encode_tags(&normalize_tags(project.tags.clone())))
+        .bind(search_blob(&project.name, &project.description))
+        .bind(project.created_at)
+        .bind(project.deleted_at)
+        .bind(project.id.to_string())
+        .bind(max_projects)
+        .bind(project.deleted_at)
+        .bind(max_projects)
+        .execute(&mut *conn)
+        .await?;
 
-    // Update technology associations if provided
-    if let Some(tech_ids) = update.technology_ids {
-        // Delete existing associations
-        sqlx::query("DELETE FROM project_technologies WHERE project_id = ?")
-            .bind(id.to_string())
-            .execute(&state.db)
-            .await?;
+        if updated.rows_affected() == 0 {
+            return Err(quota_reached());
+        }
 
-        // Create new associations
-        let now = Utc::now();
-        for tech_id in tech_ids {
-            sqlx::query(
-                "INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)"
-            )
-            .bind(id.to_string())
-            .bind(tech_id.to_string())
-            .bind(now)
-            .execute(&state.db)
+        // Assigned on its own: trg_projects_updated_at fires on content columns and
+        // would otherwise replace the record's timestamp with the import time
+        sqlx::query("UPDATE projects SET updated_at = ? WHERE id = ?")
+            .bind(project.updated_at)
+            .bind(project.id.to_string())
+            .execute(&mut *conn)
             .await?;
+    } else {
+        let inserted = sqlx::query(
+            "INSERT INTO projects (id, name, description, repository_url, language, rating, manual_rating, tags, search_blob, created_at, updated_at, deleted_at)
+             SELECT ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+             WHERE ? IS NULL OR ? IS NOT NULL OR (SELECT COUNT(*) FROM projects WHERE deleted_at IS NULL) < ?"
+        )
+        .bind(project.id.to_string())
+        .bind(&project.name)
+        .bind(&project.description)
+        .bind(&project.repository_url)
+        .bind(&project.language)
+        .bind(project.rating.or(state.config.default_rating))
+        .bind(project.rating)
+        .bind(encode_tags(&normalize_tags(project.tags.clone())))
+        .bind(search_blob(&project.name, &project.description))
+        .bind(project.created_at)
+        .bind(project.updated_at)
+        .bind(project.deleted_at)
+        .bind(max_projects)
+        .bind(project.deleted_at)
+        .bind(max_projects)
+        .execute(&mut *conn)
+        .await?;
+
+        if inserted.rows_affected() == 0 {
+            return Err(quota_reached());
         }
     }
 
-    // Update user associations if provided
-    if let Some(user_ids) = update.user_ids {
-        // Delete existing associations
-        sqlx::query("DELETE FROM project_users WHERE project_id = ?")
-            .bind(id.to_string())
-            .execute(&state.db)
+    sqlx::query("DELETE FROM project_technologies WHERE project_id = ?")
+        .bind(project.id.to_string())
+        .execute(&mut *conn)
+        .await?;
+    for (technology_id, associated_at) in technology_ids {
+        sqlx::query("INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)")
+            .bind(project.id.to_string())
+            .bind(technology_id.to_string())
+            .bind(associated_at)
+            .execute(&mut *conn)
             .await?;
+    }
 
-        // Create new associations
-        let now = Utc::now();
-        for (idx, user_id) in user_ids.iter().enumerate() {
-            let role = if idx == 0 {
-                UserRole::Owner
-            } else {
-                UserRole::Contributor
-            };
-
-            sqlx::query(
-                "INSERT INTO project_users (project_id, user_id, role, created_at) VALUES (?, ?, ?, ?)"
-            )
-            .bind(id.to_string())
-            .bind(user_id.to_string())
-            .bind(role.as_str())
-            .bind(now)
-            .execute(&state.db)
+    sqlx::query("DELETE FROM project_users WHERE project_id = ?")
+        .bind(project.id.to_string())
+        .execute(&mut *conn)
+        .await?;
+    for member in &record.users {
+        sqlx::query("INSERT OR REPLACE INTO project_users (project_id, user_id, role, created_at) VALUES (?, ?, ?, ?)")
+            .bind(project.id.to_string())
+            .bind(member.user.id.to_string())
+            .bind(member.role.as_str())
+            .bind(member.associated_at)
+            .execute(&mut *conn)
             .await?;
-        }
     }
 
-    // Fetch updated relations
-    let technologies = sqlx::query_as::<_, Technology>(
-        "SELECT t.* FROM technologies t
-         JOIN project_technologies pt ON t.id = pt.technology_id
-         WHERE pt.project_id = ?
-         ORDER BY t.name ASC"
+    Ok(!exists)
+}
+
+/// Aggregate statistics over all live projects
+///
+/// # Endpoint
+/// GET /projects/stats
+///
+/// Soft-deleted projects are not counted. Languages are grouped the way the
+/// `language` filter matches them (case-insensitive, trimmed); technologies no
+/// live project uses are left out.
+///
+/// # Returns
+/// - `200 OK` - Project count, average rating and per-language / per-technology counts
+#[utoipa::path(
+    get,
+    path = "/projects/stats",
+    tag = "projects",
+    responses(
+        (status = 200, description = "Project statistics", body = ProjectStats),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn get_project_stats(State(state): State<AppState>) -> Result<Json<ProjectStats>> {
+    // AVG ignores NULL ratings and is NULL when no project is rated
+    let (total_projects, average_rating): (i64, Option<f64>) =
+        sqlx::query_as("SELECT COUNT(*), AVG(rating) FROM projects WHERE deleted_at IS NULL")
+            .fetch_one(&state.db)
+            .await?;
+
+    let languages = sqlx::query_as::<_, LanguageCount>(
+        "SELECT lower(trim(language)) AS language, COUNT(*) AS count
+         FROM projects
+         WHERE deleted_at IS NULL
+         GROUP BY lower(trim(language))
+         ORDER BY count DESC, language ASC"
     )
-    .bind(id.to_string())
     .fetch_all(&state.db)
     .await?;
 
-    let users_raw: Vec<(User, String)> = sqlx::query(
-        "SELECT u.id, u.name, u.email, u.created_at, pu.role
-         FROM users u
-         JOIN project_users pu ON u.id = pu.user_id
-         WHERE pu.project_id = ?
-         ORDER BY u.name ASC"
+    let technology_rows: Vec<(String, String, i64)> = sqlx::query_as(
+        "SELECT t.id, t.name, COUNT(*) AS count
+         FROM technologies t
+         JOIN project_technologies pt ON pt.technology_id = t.id
+         JOIN projects p ON p.id = pt.project_id AND p.deleted_at IS NULL
+         GROUP BY t.id, t.name
+         ORDER BY count DESC, t.name ASC"
     )
-    .bind(id.to_string())
     .fetch_all(&state.db)
-    .await?
-    .into_iter()
-    .map(|row| {
-        let id_str: String = row.try_get("id").unwrap();
-        let user = User {
-            id: Uuid::parse_str(&id_str).unwrap(),
-            name: row.try_get("name").unwrap(),
-            email: row.try_get("email").unwrap(),
-            created_at: row.try_get("created_at").unwrap(),
-        };
-        let role: String = row.try_get("role").unwrap();
-        (user, role)
-    })
-    .collect();
+    .await?;
 
-    let users: Vec<UserWithRole> = users_raw
+    let technologies = technology_rows
         .into_iter()
-        .filter_map(|(user, role_str)| {
-            UserRole::from_str(&role_str).ok().map(|role| UserWithRole { user, role })
+        .map(|(id, name, count)| {
+            let technology_id = Uuid::parse_str(&id)
+                .map_err(|e| AppError::InternalError(format!("Invalid technology UUID: {}", e)))?;
+            Ok(TechnologyCount { technology_id, name, count })
         })
-        .collect();
-
-    tracing::info!("Updated project: {}", id);
+        .collect::<Result<Vec<_>>>()?;
 
-    Ok(Json(ProjectWithRelations {
-        project,
+    Ok(Json(ProjectStats {
+        total_projects,
+        average_rating,
+        languages,
         technologies,
-        users,
     }))
 }
 
-/// Delete a project
+/// Create a new project with optional technologies and users
 ///
 /// # Endpoint
-/// DELETE /projects/{id}
+/// POST /projects?warn_duplicate_name=true
 ///
-/// # Arguments
-/// - `id` - UUID of the project to delete
+/// # Query Parameters
+/// - `warn_duplicate_name` - Still create the project, but add a warning when the name is taken
+///
+/// # Request Body
+/// ```json
+/// {
+///   "name": "My Project",
+///   "description": "A sample project",
+///   "repository_url": "https://github.com/user/repo",
+///   "language": "Rust",
+///   "rating": 4.5,
+///   "tags": ["cli", "async"],
+///   "technology_ids": ["uuid1", "uuid2"],
+///   "user_ids": ["uuid3", "uuid4"]
+/// }
+/// ```
+///
+/// Members get positional roles from `user_ids` (the first is the owner). To pick
+/// roles explicitly send `users` instead, with exactly one owner:
+/// `"users": [{ "id": "uuid3", "role": "owner" }, { "id": "uuid4", "role": "viewer" }]`.
+/// With authentication enabled, a project created without members gets the caller
+/// as its owner.
 ///
 /// # Returns
-/// - `204 No Content` - Successfully deleted
-/// - `404 Not Found` - Project not found
+/// - `201 Created` - Created project with relations
+/// - `400 Bad Request` - Validation error
+/// - `403 Forbidden` - Project quota (`MAX_PROJECTS`) reached
+/// - `404 Not Found` - Technology or user not found
+/// - `409 Conflict` - `repository_url` already used, with `REJECT_DUPLICATE_REPOSITORY_URLS` set
+///   (otherwise the project is created with a warning)
 #[utoipa::path(
-    delete,
-    path = "/projects/{id}",
+    post,
+    path = "/projects",
     tag = "projects",
     params(
-        ("id" = Uuid, Path, description = "Project UUID")
+        ("warn_duplicate_name" = Option<bool>, Query, description = "Warn (without failing) when a project with the same name exists"),
     ),
+    request_body = CreateProjectRequest,
     responses(
-        (status = 204, description = "Project deleted successfully"),
-        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 201, description = "Project created successfully", body = ProjectWithRelations,
+            headers(("Location" = String, description = "URI of the created project"))),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 403, description = "Project quota reached", body = ErrorResponse),
+        (status = 404, description = "Technology or user not found", body = ErrorResponse),
+        (status = 409, description = "Repository URL already used (strict mode)", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
 #[tracing::instrument(skip(state))]
-pub async fn delete_project(
+pub async fn create_project(
     State(state): State<AppState>,
-    Path(id): Path<Uuid>,
-) -> Result<StatusCode> {
-    let result = sqlx::query("DELETE FROM projects WHERE id = ?")
-        .bind(id.to_string())
-        .execute(&state.db)
-        .await?;
+    caller: Option<AuthUser>,
+    Query(options): Query<CreateProjectOptions>,
+    ValidatedJson(mut request): ValidatedJson<CreateProjectRequest>,
+) -> Result<(StatusCode, HeaderMap, Json<ProjectWithRelations>)> {
+    default_owner(&mut request, caller.as_ref())?;
+    check_rating_requirement(&state, &request.language, request.rating)?;
 
-    if result.rows_affected() == 0 {
-        return Err(AppError::ProjectNotFound(id.to_string()));
+    // Create new project
+    let project = Project::new(request.clone());
+
+    // The project row and its associations are written atomically: if any
+    // insert fails, dropping the transaction rolls everything back
+    let mut tx = state.db.begin().await?;
+
+    check_project_references(&mut tx, &request).await?;
+
+    // Duplicate names are allowed, but callers can ask to be told about them
+    let mut warnings = Vec::new();
+    warnings.extend(check_duplicate_repository_url(&mut tx, &state, project.id, &project.repository_url).await?);
+    if options.warn_duplicate_name {
+        let duplicate = sqlx::query("SELECT 1 FROM projects WHERE name = ? AND deleted_at IS NULL LIMIT 1")
+            .bind(&project.name)
+            .fetch_optional(&mut *tx)
+            .await?;
+        if duplicate.is_some() {
+            warnings.push(format!("A project named {} already exists", project.name));
+        }
     }
 
-    tracing::info!("Deleted project: {}", id);
-    Ok(StatusCode::NO_CONTENT)
-}
+    let mut created = insert_project(&mut tx, &state, project, request).await?;
+    created.warnings = warnings;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::models::{CreateTechnologyRequest, CreateUserRequest};
-    use crate::state::tests::new_test_db;
+    tx.commit().await?;
 
-    #[tokio::test]
-    async fn test_create_project_with_rating() {
-        let state = new_test_db().await;
+    tracing::info!("Created project: {}", created.project.id);
 
-        let request = CreateProjectRequest {
-            name: "Test Project".to_string(),
-            description: "A test project".to_string(),
-            repository_url: "https://github.com/test/repo".to_string(),
-            language: "Rust".to_string(),
-            rating: Some(4.5),
-            technology_ids: None,
-            user_ids: None,
-        };
+    let location = created_location(&format!("/projects/{}", created.project.id))?;
+    Ok((StatusCode::CREATED, location, Json(created)))
+}
 
-        let (status, Json(created)) = create_project(State(state), Json(request))
-            .await
-            .unwrap();
+/// Check a project payload without creating it
+///
+/// # Endpoint
+/// POST /projects/validate
+///
+/// Runs the same checks as `POST /projects` (field validation, member roles,
+/// required ratings and technology / user existence) so forms can show errors
+/// before the final submit. Nothing is written to the database.
+///
+/// # Returns
+/// - `200 OK` - `{ "valid": true }`
+/// - `400 Bad Request` - Validation error, as returned by a create
+/// - `404 Not Found` - Technology or user not found
+#[utoipa::path(
+    post,
+    path = "/projects/validate",
+    tag = "projects",
+    request_body = CreateProjectRequest,
+    responses(
+        (status = 200, description = "Payload would be accepted by POST /projects", body = ProjectValidation),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Technology or user not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn validate_project(
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<CreateProjectRequest>,
+) -> Result<Json<ProjectValidation>> {
+    requested_memberships(&request)?;
+    check_rating_requirement(&state, &request.language, request.rating)?;
 
-        assert_eq!(status, StatusCode::CREATED);
-        assert_eq!(created.project.rating, Some(4.5));
-    }
+    let mut conn = state.db.acquire().await?;
+    check_project_references(&mut conn, &request).await?;
 
-    #[tokio::test]
-    async fn test_list_projects_pagination() {
-        let state = new_test_db().await;
+    Ok(Json(ProjectValidation { valid: true }))
+}
 
-        // Create multiple projects
-        for i in 1..=15 {
-            let request = CreateProjectRequest {
-                name: format!("Project {}", i),
-                description: format!("Description {}", i),
-                repository_url: format!("https://github.com/test/repo{}", i),
-                language: "Rust".to_string(),
-                rating: Some(i as f64 % 5.0),
-                technology_ids: None,
-                user_ids: None,
-            };
+/// Checks whether another live project uses the same (normalized) repository URL
+///
+/// Returns the warning to report, or fails with `DuplicateResource` when
+/// `reject_duplicate_repository_urls` is configured. Candidates are narrowed down
+/// in SQL by the URL's last segment and compared in normalized form here.
+async fn check_duplicate_repository_url(
+    conn: &mut SqliteConnection,
+    state: &AppState,
+    project_id: Uuid,
+    repository_url: &str,
+) -> Result<Option<String>> {
+    let normalized = normalize_repository_url(repository_url);
+    let last_segment = normalized.rsplit('/').next().unwrap_or_default();
 
-            let _ = create_project(State(state.clone()), Json(request))
-                .await
-                .unwrap();
-        }
+    let candidates: Vec<(String, String)> = sqlx::query_as(&format!(
+        "SELECT id, repository_url FROM projects WHERE id != ? AND deleted_at IS NULL AND repository_url LIKE ?{}",
+        LIKE_ESCAPE
+    ))
+    .bind(project_id.to_string())
+    .bind(contains_pattern(last_segment))
+    .fetch_all(&mut *conn)
+    .await?;
 
-        // Test pagination
-        let params = ListQueryParams {
-            search: None,
-            technology: None,
-            user_id: None,
-            min_rating: None,
-            max_rating: None,
-            language: None,
-            sort: None,
-            order: None,
-            page: Some(1),
-            page_size: Some(10),
-        };
+    let Some((existing, _)) = candidates
+        .into_iter()
+        .find(|(_, url)| normalize_repository_url(url) == normalized)
+    else {
+        return Ok(None);
+    };
 
-        let Json(response) = list_projects(State(state), Query(params)).await.unwrap();
-        assert_eq!(response.data.len(), 10);
-        assert_eq!(response.pagination.total_items, 15);
-        assert_eq!(response.pagination.total_pages, 2);
+    if state.config.reject_duplicate_repository_urls {
+        return Err(AppError::DuplicateResource(format!(
+            "Project {} already uses repository_url {}",
+            existing, repository_url
+        )));
     }
+    Ok(Some(format!("duplicate repository_url: already used by project {}", existing)))
+}
 
-    #[tokio::test]
-    async fn test_get_project_with_relations() {
-        let state = new_test_db().await;
+/// First of `ids`, in the given order, that has no row in `table`
+///
+/// Looks all of them up with a single `SELECT id ... WHERE id IN (...)` instead of
+/// one query per id; an empty list never touches the database.
+async fn first_missing_id<'a>(
+    conn: &mut SqliteConnection,
+    table: &'static str,
+    ids: impl IntoIterator<Item = &'a Uuid>,
+) -> Result<Option<Uuid>> {
+    let ids: Vec<&Uuid> = ids.into_iter().collect();
+    if ids.is_empty() {
+        return Ok(None);
+    }
 
-        // Create a technology
-        let tech = crate::models::Technology::new(CreateTechnologyRequest {
-            name: "Rust".to_string(),
-            description: Some("A systems language".to_string()),
-        });
-        sqlx::query(
-            "INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)"
-        )
-        .bind(tech.id.to_string())
-        .bind(&tech.name)
-        .bind(&tech.description)
-        .bind(tech.created_at)
-        .execute(&state.db)
-        .await
-        .unwrap();
+    let mut builder: sqlx::QueryBuilder<sqlx::Sqlite> = sqlx::QueryBuilder::new("SELECT id FROM ");
+    builder.push(table);
+    builder.push(" WHERE id IN (");
+    let mut separated = builder.separated(", ");
+    for id in &ids {
+        separated.push_bind(id.to_string());
+    }
+    separated.push_unseparated(")");
 
-        // Create a user
-        let user = crate::models::User::new(CreateUserRequest {
-            name: "John Doe".to_string(),
-            email: "john@example.com".to_string(),
-        });
-        sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, ?)")
-            .bind(user.id.to_string())
-            .bind(&user.name)
-            .bind(&user.email)
-            .bind(user.created_at)
-            .execute(&state.db)
-            .await
-            .unwrap();
+    let found: std::collections::HashSet<String> = builder
+        .build_query_scalar::<String>()
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .collect();
 
-        // Create project with relations
-        let request = CreateProjectRequest {
-            name: "Test Project".to_string(),
-            description: "A test".to_string(),
-            repository_url: "https://github.com/test/repo".to_string(),
-            language: "Rust".to_string(),
-            rating: Some(4.5),
-            technology_ids: Some(vec![tech.id]),
-            user_ids: Some(vec![user.id]),
-        };
+    Ok(ids.into_iter().find(|id| !found.contains(&id.to_string())).copied())
+}
 
-        let (_, Json(created)) = create_project(State(state.clone()), Json(request))
-            .await
-            .unwrap();
+/// Fails with `TechnologyNotFound` / `UserNotFound` if a referenced id does not exist
+async fn check_project_references(conn: &mut SqliteConnection, request: &CreateProjectRequest) -> Result<()> {
+    // Validate technology IDs exist
+    if let Some(missing) = first_missing_id(&mut *conn, "technologies", request.technology_ids.iter().flatten()).await? {
+        return Err(AppError::TechnologyNotFound(missing.to_string()));
+    }
 
-        // Get project and verify relations
-        let Json(retrieved) = get_project(State(state), Path(created.project.id))
-            .await
-            .unwrap();
+    // Validate user IDs exist, from either membership form
+    let member_ids = request.users.iter().flatten().map(|member| &member.user_id);
+    let user_ids = request.user_ids.iter().flatten().chain(member_ids);
+    if let Some(missing) = first_missing_id(&mut *conn, "users", user_ids).await? {
+        return Err(AppError::UserNotFound(missing.to_string()));
+    }
 
-        assert_eq!(retrieved.technologies.len(), 1);
-        assert_eq!(retrieved.technologies[0].name, "Rust");
-        assert_eq!(retrieved.users.len(), 1);
-        assert_eq!(retrieved.users[0].user.name, "John Doe");
-        assert_eq!(retrieved.users[0].role, UserRole::Owner);
+    Ok(())
+}
+
+/// Inserts `project` and the associations from `request` on the caller's transaction
+async fn insert_project(
+    conn: &mut SqliteConnection,
+    state: &AppState,
+    mut project: Project,
+    request: CreateProjectRequest,
+) -> Result<ProjectWithRelations> {
+    // Insert into database. The quota check is part of the INSERT itself, so
+    // concurrent creates cannot both slip in under the cap
+    let max_projects = state.config.max_projects.map(|max| max as i64);
+    let inserted = sqlx::query(
+        "INSERT INTO projects (id, name, description, repository_url, language, rating, manual_rating, tags, search_blob, created_at, updated_at)
+         SELECT ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+         WHERE ? IS NULL OR (SELECT COUNT(*) FROM projects WHERE deleted_at IS NULL) < ?"
+    )
+    .bind(project.id.to_string())
+    .bind(&project.name)
+    .bind(&project.description)
+    .bind(&project.repository_url)
+    .bind(&project.language)
+    .bind(project.rating.or(state.config.default_rating))
+    .bind(project.rating)
+    .bind(encode_tags(&project.tags))
+    .bind(search_blob(&project.name, &project.description))
+    .bind(project.created_at)
+    .bind(project.updated_at)
+    .bind(max_projects)
+    .bind(max_projects)
+    .execute(&mut *conn)
+    .await?;
+
+    if inserted.rows_affected() == 0 {
+        return Err(AppError::QuotaExceeded(format!(
+            "Project quota reached: at most {} projects may exist",
+            max_projects.unwrap_or_default()
+        )));
+    }
+    // A new project has no reviews, so only the default can stand in for a missing rating
+    project.rating = project.rating.or(state.config.default_rating);
+
+    // Associate technologies
+    let mut technologies = Vec::new();
+    if let Some(ref tech_ids) = request.technology_ids {
+        let tech_ids = ordered_technology_ids(tech_ids);
+        let now = Utc::now();
+        for tech_id in &tech_ids {
+            sqlx::query(
+                "INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)"
+            )
+            .bind(project.id.to_string())
+            .bind(tech_id.to_string())
+            .bind(now)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        // Fetch the technologies
+        for tech_id in tech_ids {
+            if let Some(tech) = sqlx::query_as::<_, Technology>("SELECT * FROM technologies WHERE id = ?")
+                .bind(tech_id.to_string())
+                .fetch_optional(&mut *conn)
+                .await?
+            {
+                technologies.push(ProjectTechnology {
+                    technology: tech,
+                    associated_at: now,
+                });
+            }
+        }
+    }
+
+    // Associate users with their explicit roles, or positional ones for `user_ids`
+    let mut users = Vec::new();
+    let now = Utc::now();
+    for (user_id, role) in requested_memberships(&request)? {
+        sqlx::query(
+            "INSERT INTO project_users (project_id, user_id, role, created_at) VALUES (?, ?, ?, ?)"
+        )
+        .bind(project.id.to_string())
+        .bind(user_id.to_string())
+        .bind(role.as_str())
+        .bind(now)
+        .execute(&mut *conn)
+        .await?;
+
+        // Fetch the user
+        if let Some(user) = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = ?")
+            .bind(user_id.to_string())
+            .fetch_optional(&mut *conn)
+            .await?
+        {
+            users.push(UserWithRole {
+                user,
+                role,
+                associated_at: now,
+            });
+        }
+    }
+
+    Ok(ProjectWithRelations {
+        project,
+        technologies,
+        users,
+        warnings: Vec::new(),
+    })
+}
+
+/// Create many projects at once
+///
+/// # Endpoint
+/// POST /projects/bulk
+///
+/// # Request Body
+/// A JSON array of project objects, each shaped like the `POST /projects` body
+/// (at most `MAX_BULK_PROJECTS` items).
+///
+/// Every item is validated before anything is written, and all validation errors
+/// are reported together (field errors are keyed by item, e.g. `[1].name`). The projects are then inserted in a single transaction:
+/// if any item references a missing technology or user, the whole batch is rolled
+/// back and the error names the failing item's index. As with `POST /projects`, an
+/// authenticated caller becomes the owner of items that list no members.
+///
+/// # Returns
+/// - `201 Created` - Created projects with relations, in request order
+/// - `400 Bad Request` - Validation error or batch too large
+/// - `403 Forbidden` - Project quota (`MAX_PROJECTS`) reached
+/// - `404 Not Found` - Technology or user not found
+#[utoipa::path(
+    post,
+    path = "/projects/bulk",
+    tag = "projects",
+    request_body = Vec<CreateProjectRequest>,
+    responses(
+        (status = 201, description = "Projects created successfully", body = Vec<ProjectWithRelations>),
+        (status = 400, description = "Validation error or batch too large", body = ErrorResponse),
+        (status = 403, description = "Project quota reached", body = ErrorResponse),
+        (status = 404, description = "Technology or user not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state, requests))]
+pub async fn create_projects_bulk(
+    State(state): State<AppState>,
+    caller: Option<AuthUser>,
+    ValidatedJson(mut requests): ValidatedJson<Vec<CreateProjectRequest>>,
+) -> Result<(StatusCode, Json<Vec<ProjectWithRelations>>)> {
+    if requests.len() > MAX_BULK_PROJECTS {
+        return Err(AppError::ValidationError(format!(
+            "Batch of {} projects exceeds the limit of {}",
+            requests.len(),
+            MAX_BULK_PROJECTS
+        )));
+    }
+
+    // Field errors of every item were already reported by `ValidatedJson`; run the
+    // remaining checks on the whole batch up front, collecting every item's errors
+    let mut errors = Vec::new();
+    for (idx, request) in requests.iter_mut().enumerate() {
+        if let Err(AppError::ValidationError(message)) = default_owner(request, caller.as_ref()) {
+            errors.push(format!("Item {}: {}", idx, message));
+        }
+        if let Err(AppError::ValidationError(message)) =
+            check_rating_requirement(&state, &request.language, request.rating)
+        {
+            errors.push(format!("Item {}: {}", idx, message));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(AppError::ValidationError(errors.join("; ")));
+    }
+
+    let at_item = |idx: usize, e: AppError| match e {
+        AppError::TechnologyNotFound(id) => AppError::TechnologyNotFound(format!("{} (item {})", id, idx)),
+        AppError::UserNotFound(id) => AppError::UserNotFound(format!("{} (item {})", id, idx)),
+        AppError::QuotaExceeded(message) => AppError::QuotaExceeded(format!("{} (item {})", message, idx)),
+        other => other,
+    };
+
+    // Check every item's references before taking the write transaction, so a bad
+    // batch fails without holding SQLite's write lock. The lookups fan out over the
+    // pool, capped at `max_concurrent_db_ops` so a big batch cannot starve it
+    state
+        .bounded_db_ops(requests.iter().cloned().enumerate(), |(idx, request)| {
+            let db = state.db.clone();
+            async move {
+                let mut conn = db.acquire().await?;
+                check_project_references(&mut conn, &request).await.map_err(|e| at_item(idx, e))
+            }
+        })
+        .await?;
+
+    let mut tx = state.db.begin().await?;
+
+    let mut created = Vec::with_capacity(requests.len());
+    for (idx, request) in requests.into_iter().enumerate() {
+        // Checked again on the transaction in case a reference was deleted meanwhile
+        check_project_references(&mut tx, &request).await.map_err(|e| at_item(idx, e))?;
+        let project = Project::new(request.clone());
+        created.push(insert_project(&mut tx, &state, project, request).await.map_err(|e| at_item(idx, e))?);
+    }
+
+    tx.commit().await?;
+
+    tracing::info!("Bulk created {} projects", created.len());
+
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// Update an existing project
+///
+/// # Endpoint
+/// PUT /projects/{id}
+/// PATCH /projects/{id}
+///
+/// # Arguments
+/// - `id` - UUID of the project to update
+///
+/// # Request Body
+/// All fields are optional. If technology_ids or user_ids are provided, they replace existing associations.
+/// An omitted `rating` is left unchanged while `"rating": null` clears it.
+/// ```json
+/// {
+///   "name": "Updated Name",
+///   "description": "Updated description",
+///   "repository_url": "https://github.com/user/new-repo",
+///   "language": "Python",
+///   "rating": 4.8,
+///   "technology_ids": ["uuid1", "uuid2"],
+///   "user_ids": ["uuid3"]
+/// }
+/// ```
+///
+/// With authentication enabled only the project's owners may update it.
+///
+/// # Optimistic Concurrency
+/// Send the `updated_at` you last read as `expected_updated_at`, or an
+/// `If-Unmodified-Since` header, to have the update rejected when someone else
+/// changed the project in the meantime.
+///
+/// # Returns
+/// - `200 OK` - Updated project with relations
+/// - `404 Not Found` - Project, technology, or user not found
+/// - `400 Bad Request` - Validation error
+/// - `403 Forbidden` - Caller is not an owner of the project
+/// - `409 Conflict` - `user_ids: []` would remove the project's owner
+/// - `412 Precondition Failed` - The project changed since `expected_updated_at` / `If-Unmodified-Since`
+#[utoipa::path(
+    method(put, patch),
+    path = "/projects/{id}",
+    tag = "projects",
+    params(
+        ("id" = Uuid, Path, description = "Project UUID"),
+        ("If-Unmodified-Since" = Option<String>, Header, description = "Reject the update if the project changed after this HTTP-date")
+    ),
+    request_body = UpdateProjectRequest,
+    responses(
+        (status = 200, description = "Project updated successfully", body = ProjectWithRelations),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 403, description = "Caller is not an owner of the project", body = ErrorResponse),
+        (status = 404, description = "Project, technology, or user not found", body = ErrorResponse),
+        (status = 409, description = "Would remove the project's owner", body = ErrorResponse),
+        (status = 412, description = "Project was modified since the given version", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state, headers))]
+pub async fn update_project(
+    State(state): State<AppState>,
+    caller: Option<AuthUser>,
+    ValidatedUuid(id): ValidatedUuid,
+    headers: HeaderMap,
+    Json(update): Json<UpdateProjectRequest>,
+) -> Result<Json<ProjectWithRelations>> {
+    authorize_project_write(&state, caller.as_ref(), id, UserRole::Owner).await?;
+
+    // Validate request
+    update.validate()?;
+
+    // Validate technology and user IDs exist
+    let mut conn = state.db.acquire().await?;
+    if let Some(missing) = first_missing_id(&mut conn, "technologies", update.technology_ids.iter().flatten()).await? {
+        return Err(AppError::TechnologyNotFound(missing.to_string()));
+    }
+    if let Some(missing) = first_missing_id(&mut conn, "users", update.user_ids.iter().flatten()).await? {
+        return Err(AppError::UserNotFound(missing.to_string()));
+    }
+    drop(conn);
+
+    // Fetch existing project, keeping the stored updated_at text as its version
+    let row = sqlx::query("SELECT *, updated_at AS version FROM projects WHERE id = ? AND deleted_at IS NULL")
+        .bind(id.to_string())
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or_else(|| AppError::ProjectNotFound(id.to_string()))?;
+    let mut project = Project::from_row(&row)?;
+    let version: String = row.try_get("version")?;
+    let conditional = check_unmodified(&project, update.expected_updated_at, &headers)?;
+
+    // Update project fields
+    project.update(update.clone());
+    check_rating_requirement(&state, &project.language, project.rating)?;
+
+    let mut warnings = Vec::new();
+    if update.repository_url.is_some() {
+        let mut conn = state.db.acquire().await?;
+        warnings.extend(check_duplicate_repository_url(&mut conn, &state, id, &project.repository_url).await?);
+    }
+
+    // Row update and association replacement run in one transaction so that
+    // concurrent updates never observe (or leave behind) a half-replaced set
+    let UpdateProjectRequest { rating: manual_rating, technology_ids, user_ids, .. } = update;
+    let default_rating = state.config.default_rating;
+    state
+        .with_transaction(|tx| {
+            Box::pin(async move {
+                // Update in database; updated_at is bumped by the trg_projects_updated_at trigger.
+                // Conditional updates only apply if nobody changed the row since it was read.
+                // A given rating replaces the manual one (an omitted one keeps it); reviews,
+                // if any, still decide `rating`.
+                let mut sql = String::from(
+                    "UPDATE projects SET name = ?, description = ?, repository_url = ?, language = ?,
+                     rating = COALESCE((SELECT AVG(score) FROM reviews WHERE reviews.project_id = projects.id),
+                                       CASE WHEN ? THEN ? ELSE manual_rating END, ?),
+                     manual_rating = CASE WHEN ? THEN ? ELSE manual_rating END, tags = ?, search_blob = ?
+                     WHERE id = ?",
+                );
+                if conditional {
+                    sql.push_str(" AND updated_at = ?");
+                }
+                let mut query = sqlx::query(&sql)
+                    .bind(&project.name)
+                    .bind(&project.description)
+                    .bind(&project.repository_url)
+                    .bind(&project.language)
+                    .bind(manual_rating.is_some())
+                    .bind(manual_rating.flatten())
+                    .bind(default_rating)
+                    .bind(manual_rating.is_some())
+                    .bind(manual_rating.flatten())
+                    .bind(encode_tags(&project.tags))
+                    .bind(search_blob(&project.name, &project.description))
+                    .bind(id.to_string());
+                if conditional {
+                    query = query.bind(&version);
+                }
+                let result = query.execute(&mut **tx).await?;
+                if result.rows_affected() == 0 {
+                    return Err(AppError::PreconditionFailed(format!(
+                        "Project {} was modified by another request",
+                        id
+                    )));
+                }
+
+                // Update technology associations if provided
+                if let Some(tech_ids) = technology_ids {
+                    // Delete existing associations
+                    sqlx::query("DELETE FROM project_technologies WHERE project_id = ?")
+                        .bind(id.to_string())
+                        .execute(&mut **tx)
+                        .await?;
+
+                    // Create new associations
+                    let now = Utc::now();
+                    for tech_id in ordered_technology_ids(&tech_ids) {
+                        sqlx::query(
+                            "INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)"
+                        )
+                        .bind(id.to_string())
+                        .bind(tech_id.to_string())
+                        .bind(now)
+                        .execute(&mut **tx)
+                        .await?;
+                    }
+                }
+
+                // Update user associations if provided
+                if let Some(user_ids) = user_ids {
+                    if user_ids.is_empty() {
+                        ensure_owner_kept(tx, id).await?;
+                    }
+
+                    // Delete existing associations
+                    sqlx::query("DELETE FROM project_users WHERE project_id = ?")
+                        .bind(id.to_string())
+                        .execute(&mut **tx)
+                        .await?;
+
+                    // Create new associations
+                    let now = Utc::now();
+                    for (user_id, role) in ordered_memberships(&user_ids) {
+                        sqlx::query(
+                            "INSERT INTO project_users (project_id, user_id, role, created_at) VALUES (?, ?, ?, ?)"
+                        )
+                        .bind(id.to_string())
+                        .bind(user_id.to_string())
+                        .bind(role.as_str())
+                        .bind(now)
+                        .execute(&mut **tx)
+                        .await?;
+                    }
+                }
+
+                Ok(())
+            })
+        })
+        .await?;
+
+    // Fetch updated relations
+    let technologies = fetch_project_technologies(&mut *state.db.acquire().await?, id).await?;
+    let users = fetch_project_users(&mut *state.db.acquire().await?, id).await?;
+
+    // Re-read the row so the response carries the trigger-maintained updated_at
+    let project = sqlx::query_as::<_, Project>("SELECT * FROM projects WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_one(&state.db)
+        .await?;
+
+    tracing::info!("Updated project: {}", id);
+
+    Ok(Json(ProjectWithRelations {
+        project,
+        technologies,
+        users,
+        warnings,
+    }))
+}
+
+/// Checks the optimistic concurrency preconditions of an update
+///
+/// `expected_updated_at` must equal the stored `updated_at`; `If-Unmodified-Since`
+/// (an HTTP-date, so second precision) must not be older than it. An unparsable
+/// header is ignored. Returns whether any precondition was given, in which case
+/// the write must also re-check the version to close the race with other writers.
+fn check_unmodified(project: &Project, expected_updated_at: Option<DateTime<Utc>>, headers: &HeaderMap) -> Result<bool> {
+    let unmodified_since = headers
+        .get(header::IF_UNMODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+        .map(|since| since.with_timezone(&Utc));
+
+    if let Some(expected) = expected_updated_at
+        && expected != project.updated_at
+    {
+        return Err(AppError::PreconditionFailed(format!(
+            "Project {} was updated at {}, not {}",
+            project.id,
+            project.updated_at.to_rfc3339(),
+            expected.to_rfc3339()
+        )));
+    }
+    if let Some(since) = unmodified_since
+        && project.updated_at.timestamp() > since.timestamp()
+    {
+        return Err(AppError::PreconditionFailed(format!(
+            "Project {} was modified after {}",
+            project.id,
+            since.to_rfc2822()
+        )));
+    }
+
+    Ok(expected_updated_at.is_some() || unmodified_since.is_some())
+}
+
+/// Copy another project's technologies onto a project
+///
+/// # Endpoint
+/// POST /projects/{id}/technologies/copy-from/{source_id}
+///
+/// # Arguments
+/// - `id` - UUID of the project receiving the technologies
+/// - `source_id` - UUID of the project to copy from
+///
+/// Technologies the target already has are kept as-is, so repeating the copy
+/// is a no-op. Members are never touched.
+///
+/// # Returns
+/// - `200 OK` - The target project's technologies after the copy
+/// - `403 Forbidden` - Caller is not an owner of the target project
+/// - `404 Not Found` - Either project not found
+#[utoipa::path(
+    post,
+    path = "/projects/{id}/technologies/copy-from/{source_id}",
+    tag = "projects",
+    params(
+        ("id" = Uuid, Path, description = "Target project UUID"),
+        ("source_id" = Uuid, Path, description = "Source project UUID")
+    ),
+    responses(
+        (status = 200, description = "Technologies copied", body = [ProjectTechnology]),
+        (status = 403, description = "Caller is not an owner of the project", body = ErrorResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn copy_project_technologies(
+    State(state): State<AppState>,
+    caller: Option<AuthUser>,
+    Path((id, source_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<ProjectTechnology>>> {
+    authorize_project_write(&state, caller.as_ref(), id, UserRole::Owner).await?;
+
+    let mut tx = state.db.begin().await?;
+
+    for project_id in [id, source_id] {
+        let exists = sqlx::query("SELECT 1 FROM projects WHERE id = ? AND deleted_at IS NULL")
+            .bind(project_id.to_string())
+            .fetch_optional(&mut *tx)
+            .await?;
+        if exists.is_none() {
+            return Err(AppError::ProjectNotFound(project_id.to_string()));
+        }
+    }
+
+    // Ordered by technology id like every other association insert
+    let copied = sqlx::query(
+        "INSERT OR IGNORE INTO project_technologies (project_id, technology_id, created_at)
+         SELECT ?, technology_id, ? FROM project_technologies
+         WHERE project_id = ?
+         ORDER BY technology_id"
+    )
+    .bind(id.to_string())
+    .bind(Utc::now())
+    .bind(source_id.to_string())
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    if copied > 0 {
+        // Association changes count as a project update
+        sqlx::query("UPDATE projects SET updated_at = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    let technologies = fetch_project_technologies(&mut tx, id).await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Copied {} technologies from project {} to {}", copied, source_id, id);
+    Ok(Json(technologies))
+}
+
+/// Attach a single technology to a project
+///
+/// # Endpoint
+/// POST /projects/{id}/technologies
+///
+/// # Request Body
+/// ```json
+/// { "technology_id": "uuid1" }
+/// ```
+///
+/// Unlike `PUT /projects/{id}`, the project's other technologies are left alone.
+///
+/// # Returns
+/// - `201 Created` - The new association
+/// - `403 Forbidden` - Caller is not an owner of the project
+/// - `404 Not Found` - Project or technology not found
+/// - `409 Conflict` - The technology is already attached
+#[utoipa::path(
+    post,
+    path = "/projects/{id}/technologies",
+    tag = "projects",
+    params(
+        ("id" = Uuid, Path, description = "Project UUID")
+    ),
+    request_body = AttachTechnologyRequest,
+    responses(
+        (status = 201, description = "Technology attached", body = ProjectTechnology),
+        (status = 403, description = "Caller is not an owner of the project", body = ErrorResponse),
+        (status = 404, description = "Project or technology not found", body = ErrorResponse),
+        (status = 409, description = "Technology already attached", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn add_project_technology(
+    State(state): State<AppState>,
+    caller: Option<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<AttachTechnologyRequest>,
+) -> Result<(StatusCode, Json<ProjectTechnology>)> {
+    authorize_project_write(&state, caller.as_ref(), id, UserRole::Owner).await?;
+
+    let tech_id = request.technology_id;
+    let mut tx = state.db.begin().await?;
+
+    let project_exists = sqlx::query("SELECT 1 FROM projects WHERE id = ? AND deleted_at IS NULL")
+        .bind(id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+    if project_exists.is_none() {
+        return Err(AppError::ProjectNotFound(id.to_string()));
+    }
+
+    let technology = sqlx::query_as::<_, Technology>("SELECT * FROM technologies WHERE id = ?")
+        .bind(tech_id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::TechnologyNotFound(tech_id.to_string()))?;
+
+    let attached = sqlx::query("SELECT 1 FROM project_technologies WHERE project_id = ? AND technology_id = ?")
+        .bind(id.to_string())
+        .bind(tech_id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+    if attached.is_some() {
+        return Err(AppError::Conflict(format!(
+            "Technology {} is already attached to project {}",
+            tech_id, id
+        )));
+    }
+
+    let now = Utc::now();
+    sqlx::query("INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)")
+        .bind(id.to_string())
+        .bind(tech_id.to_string())
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+
+    // Association changes count as a project update
+    sqlx::query("UPDATE projects SET updated_at = ? WHERE id = ?")
+        .bind(Utc::now())
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Attached technology {} to project {}", tech_id, id);
+    Ok((
+        StatusCode::CREATED,
+        Json(ProjectTechnology {
+            technology,
+            associated_at: now,
+        }),
+    ))
+}
+
+/// Detach a single technology from a project
+///
+/// # Endpoint
+/// DELETE /projects/{id}/technologies/{tech_id}
+///
+/// # Returns
+/// - `204 No Content` - Technology detached
+/// - `403 Forbidden` - Caller is not an owner of the project
+/// - `404 Not Found` - Project not found, or the technology is not attached to it
+#[utoipa::path(
+    delete,
+    path = "/projects/{id}/technologies/{tech_id}",
+    tag = "projects",
+    params(
+        ("id" = Uuid, Path, description = "Project UUID"),
+        ("tech_id" = Uuid, Path, description = "Technology UUID")
+    ),
+    responses(
+        (status = 204, description = "Technology detached"),
+        (status = 403, description = "Caller is not an owner of the project", body = ErrorResponse),
+        (status = 404, description = "Project not found or technology not attached", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn remove_project_technology(
+    State(state): State<AppState>,
+    caller: Option<AuthUser>,
+    Path((id, tech_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode> {
+    authorize_project_write(&state, caller.as_ref(), id, UserRole::Owner).await?;
+
+    let mut tx = state.db.begin().await?;
+
+    let project_exists = sqlx::query("SELECT 1 FROM projects WHERE id = ? AND deleted_at IS NULL")
+        .bind(id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+    if project_exists.is_none() {
+        return Err(AppError::ProjectNotFound(id.to_string()));
+    }
+
+    let removed = sqlx::query("DELETE FROM project_technologies WHERE project_id = ? AND technology_id = ?")
+        .bind(id.to_string())
+        .bind(tech_id.to_string())
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    if removed == 0 {
+        return Err(AppError::TechnologyNotFound(format!(
+            "{} (not attached to project {})",
+            tech_id, id
+        )));
+    }
+
+    sqlx::query("UPDATE projects SET updated_at = ? WHERE id = ?")
+        .bind(Utc::now())
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Detached technology {} from project {}", tech_id, id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Replace a project's members
+///
+/// # Endpoint
+/// PUT /projects/{id}/users
+///
+/// # Request Body
+/// The full desired membership list; users not listed are removed.
+/// ```json
+/// [
+///   { "user_id": "550e8400-e29b-41d4-a716-446655440000", "role": "owner" },
+///   { "user_id": "6ba7b810-9dad-11d1-80b4-00c04fd430c8", "role": "viewer" }
+/// ]
+/// ```
+///
+/// # Returns
+/// - `200 OK` - The project's members after the replacement
+/// - `400 Bad Request` - Duplicate users, or a non-empty list without exactly one owner
+/// - `403 Forbidden` - Caller is not an owner of the project
+/// - `404 Not Found` - Project or user not found
+/// - `409 Conflict` - An empty list would remove the project's owner
+#[utoipa::path(
+    put,
+    path = "/projects/{id}/users",
+    tag = "projects",
+    params(
+        ("id" = Uuid, Path, description = "Project UUID")
+    ),
+    request_body = Vec<ProjectMemberRequest>,
+    responses(
+        (status = 200, description = "Members replaced", body = [UserWithRole]),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 403, description = "Caller is not an owner of the project", body = ErrorResponse),
+        (status = 404, description = "Project or user not found", body = ErrorResponse),
+        (status = 409, description = "Would remove the project's owner", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn replace_project_users(
+    State(state): State<AppState>,
+    caller: Option<AuthUser>,
+    Path(id): Path<Uuid>,
+    ValidatedJson(members): ValidatedJson<Vec<ProjectMemberRequest>>,
+) -> Result<Json<Vec<UserWithRole>>> {
+    authorize_project_write(&state, caller.as_ref(), id, UserRole::Owner).await?;
+    validate_members(&members)?;
+    let mut user_ids: Vec<Uuid> = members.iter().map(|member| member.user_id).collect();
+    user_ids.sort();
+
+    let mut tx = state.db.begin().await?;
+
+    let project_exists = sqlx::query("SELECT 1 FROM projects WHERE id = ? AND deleted_at IS NULL")
+        .bind(id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+    if project_exists.is_none() {
+        return Err(AppError::ProjectNotFound(id.to_string()));
+    }
+
+    if let Some(missing) = first_missing_id(&mut tx, "users", &user_ids).await? {
+        return Err(AppError::UserNotFound(missing.to_string()));
+    }
+    if members.is_empty() {
+        ensure_owner_kept(&mut tx, id).await?;
+    }
+
+    sqlx::query("DELETE FROM project_users WHERE project_id = ?")
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    let now = Utc::now();
+    for member in &members {
+        sqlx::query(
+            "INSERT INTO project_users (project_id, user_id, role, created_at) VALUES (?, ?, ?, ?)"
+        )
+        .bind(id.to_string())
+        .bind(member.user_id.to_string())
+        .bind(member.role.as_str())
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    // Membership changes count as a project update
+    sqlx::query("UPDATE projects SET updated_at = ? WHERE id = ?")
+        .bind(Utc::now())
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    let users = fetch_project_users(&mut tx, id).await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Replaced {} members of project {}", users.len(), id);
+    Ok(Json(users))
+}
+
+/// Change one member's role on a project
+///
+/// # Endpoint
+/// PUT /projects/{id}/users/{user_id}/role
+///
+/// # Request Body
+/// ```json
+/// { "role": "viewer" }
+/// ```
+///
+/// A project always keeps at least one owner, so its last owner cannot be demoted.
+///
+/// # Returns
+/// - `200 OK` - The member with their new role
+/// - `400 Bad Request` - Unknown role
+/// - `403 Forbidden` - Caller is not an owner of the project
+/// - `404 Not Found` - Project not found, or the user is not a member
+/// - `409 Conflict` - The change would leave the project without an owner
+#[utoipa::path(
+    put,
+    path = "/projects/{id}/users/{user_id}/role",
+    tag = "projects",
+    params(
+        ("id" = Uuid, Path, description = "Project UUID"),
+        ("user_id" = Uuid, Path, description = "User UUID")
+    ),
+    request_body = RoleUpdateRequest,
+    responses(
+        (status = 200, description = "Role updated", body = UserWithRole),
+        (status = 400, description = "Unknown role", body = ErrorResponse),
+        (status = 403, description = "Caller is not an owner of the project", body = ErrorResponse),
+        (status = 404, description = "Project not found or user not a member", body = ErrorResponse),
+        (status = 409, description = "Would demote the last owner", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn update_project_user_role(
+    State(state): State<AppState>,
+    caller: Option<AuthUser>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+    ValidatedJson(request): ValidatedJson<RoleUpdateRequest>,
+) -> Result<Json<UserWithRole>> {
+    authorize_project_write(&state, caller.as_ref(), id, UserRole::Owner).await?;
+
+    let mut tx = state.db.begin().await?;
+
+    let project_exists = sqlx::query("SELECT 1 FROM projects WHERE id = ? AND deleted_at IS NULL")
+        .bind(id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+    if project_exists.is_none() {
+        return Err(AppError::ProjectNotFound(id.to_string()));
+    }
+
+    let current: String = sqlx::query_scalar("SELECT role FROM project_users WHERE project_id = ? AND user_id = ?")
+        .bind(id.to_string())
+        .bind(user_id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::UserNotFound(format!("{} (not a member of project {})", user_id, id)))?;
+
+    if current == UserRole::Owner.as_str() && request.role != UserRole::Owner {
+        let owners: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM project_users WHERE project_id = ? AND role = ?")
+            .bind(id.to_string())
+            .bind(UserRole::Owner.as_str())
+            .fetch_one(&mut *tx)
+            .await?;
+        if owners <= 1 {
+            return Err(AppError::Conflict(format!(
+                "User {} is the last owner of project {}",
+                user_id, id
+            )));
+        }
+    }
+
+    if current != request.role.as_str() {
+        sqlx::query("UPDATE project_users SET role = ? WHERE project_id = ? AND user_id = ?")
+            .bind(request.role.as_str())
+            .bind(id.to_string())
+            .bind(user_id.to_string())
+            .execute(&mut *tx)
+            .await?;
+
+        // Association changes count as a project update
+        sqlx::query("UPDATE projects SET updated_at = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    let member = fetch_project_users(&mut tx, id)
+        .await?
+        .into_iter()
+        .find(|member| member.user.id == user_id)
+        .ok_or_else(|| AppError::InternalError(format!("Membership of {} vanished", user_id)))?;
+
+    tx.commit().await?;
+
+    tracing::info!("Set role of user {} on project {} to {}", user_id, id, request.role.as_str());
+    Ok(Json(member))
+}
+
+/// Update the ratings of many projects at once
+///
+/// # Endpoint
+/// PATCH /projects/ratings
+///
+/// # Request Body
+/// ```json
+/// [
+///   { "id": "uuid1", "rating": 4.5 },
+///   { "id": "uuid2", "rating": 3.0 }
+/// ]
+/// ```
+///
+/// Every rating is validated before anything is written; the updates then run in a
+/// single transaction. Unknown (or deleted) project ids are skipped and reported in
+/// `missing`. These are manual ratings: a project with reviews keeps its average
+/// review score.
+///
+/// # Returns
+/// - `200 OK` - Updated and missing project ids
+/// - `400 Bad Request` - A rating is outside 0.0 - 5.0
+/// - `403 Forbidden` - Caller is not an owner of every existing project in the batch
+#[utoipa::path(
+    patch,
+    path = "/projects/ratings",
+    tag = "projects",
+    request_body = Vec<RatingUpdate>,
+    responses(
+        (status = 200, description = "Ratings updated", body = BulkRatingUpdateResult),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 403, description = "Caller is not an owner of every existing project in the batch", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn update_project_ratings(
+    State(state): State<AppState>,
+    caller: Option<AuthUser>,
+    Json(updates): Json<Vec<RatingUpdate>>,
+) -> Result<Json<BulkRatingUpdateResult>> {
+    // Validate the whole batch up front so nothing is written on bad input
+    for (idx, update) in updates.iter().enumerate() {
+        update
+            .validate()
+            .map_err(|e| AppError::ValidationError(format!("Item {}: {}", idx, e)))?;
+    }
+    // Unknown projects are reported in `missing` rather than failing the batch
+    for update in &updates {
+        match authorize_project_write(&state, caller.as_ref(), update.id, UserRole::Owner).await {
+            Err(AppError::ProjectNotFound(_)) => {}
+            other => other?,
+        }
+    }
+
+    let mut result = BulkRatingUpdateResult {
+        updated: Vec::with_capacity(updates.len()),
+        missing: Vec::new(),
+    };
+
+    let mut tx = state.db.begin().await?;
+
+    // updated_at is bumped per row by the trg_projects_updated_at trigger. The
+    // UPDATE itself tells whether the project exists
+    for update in updates {
+        let affected = sqlx::query(
+            "UPDATE projects
+             SET rating = COALESCE((SELECT AVG(score) FROM reviews WHERE reviews.project_id = projects.id), ?),
+                 manual_rating = ?
+             WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(update.rating)
+        .bind(update.rating)
+        .bind(update.id.to_string())
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if affected == 0 {
+            result.missing.push(update.id);
+        } else {
+            result.updated.push(update.id);
+        }
+    }
+
+    tx.commit().await?;
+
+    tracing::info!(
+        "Bulk updated {} project ratings ({} missing)",
+        result.updated.len(),
+        result.missing.len()
+    );
+
+    Ok(Json(result))
+}
+
+/// Soft-delete a project
+///
+/// # Endpoint
+/// DELETE /projects/{id}
+///
+/// # Arguments
+/// - `id` - UUID of the project to delete
+///
+/// The row and its associations are kept with `deleted_at` set, hiding the project
+/// from reads until it is restored with `POST /projects/{id}/restore`. With
+/// authentication enabled only the project's owners may delete it.
+///
+/// # Returns
+/// - `204 No Content` - Successfully deleted
+/// - `403 Forbidden` - Caller is not an owner of the project
+/// - `404 Not Found` - Project not found (or already deleted)
+#[utoipa::path(
+    delete,
+    path = "/projects/{id}",
+    tag = "projects",
+    params(
+        ("id" = Uuid, Path, description = "Project UUID")
+    ),
+    responses(
+        (status = 204, description = "Project deleted successfully"),
+        (status = 403, description = "Caller is not an owner of the project", body = ErrorResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn delete_project(
+    State(state): State<AppState>,
+    caller: Option<AuthUser>,
+    ValidatedUuid(id): ValidatedUuid,
+) -> Result<StatusCode> {
+    authorize_project_write(&state, caller.as_ref(), id, UserRole::Owner).await?;
+
+    let result = sqlx::query("UPDATE projects SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL")
+        .bind(Utc::now())
+        .bind(id.to_string())
+        .execute(&state.db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::ProjectNotFound(id.to_string()));
+    }
+
+    tracing::info!("Deleted project: {}", id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Restore a soft-deleted project
+///
+/// # Endpoint
+/// POST /projects/{id}/restore
+///
+/// # Arguments
+/// - `id` - UUID of the project to restore
+///
+/// Restoring a project that is not deleted is a no-op.
+///
+/// # Returns
+/// - `200 OK` - The restored project
+/// - `403 Forbidden` - Caller is not an owner of the project, or the project quota is reached
+/// - `404 Not Found` - Project not found
+#[utoipa::path(
+    post,
+    path = "/projects/{id}/restore",
+    tag = "projects",
+    params(
+        ("id" = Uuid, Path, description = "Project UUID")
+    ),
+    responses(
+        (status = 200, description = "Project restored", body = Project),
+        (status = 403, description = "Caller is not an owner of the project, or the project quota is reached", body = ErrorResponse),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn restore_project(
+    State(state): State<AppState>,
+    caller: Option<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Project>> {
+    authorize_project_write(&state, caller.as_ref(), id, UserRole::Owner).await?;
+
+    // Same quota check as `insert_project`: a restored project counts as a live one again
+    let max_projects = state.config.max_projects.map(|max| max as i64);
+    sqlx::query(
+        "UPDATE projects SET deleted_at = NULL
+         WHERE id = ? AND deleted_at IS NOT NULL
+         AND (? IS NULL OR (SELECT COUNT(*) FROM projects WHERE deleted_at IS NULL) < ?)"
+    )
+    .bind(id.to_string())
+    .bind(max_projects)
+    .bind(max_projects)
+    .execute(&state.db)
+    .await?;
+
+    let project = sqlx::query_as::<_, Project>("SELECT * FROM projects WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or_else(|| AppError::ProjectNotFound(id.to_string()))?;
+
+    // Still deleted after the update: only the quota keeps it from being restored
+    if project.deleted_at.is_some() {
+        return Err(AppError::QuotaExceeded(format!(
+            "Project quota reached: at most {} projects may exist",
+            max_projects.unwrap_or_default()
+        )));
+    }
+
+    tracing::info!("Restored project: {}", id);
+    Ok(Json(project))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateTechnologyRequest, CreateUserRequest};
+    use crate::state::tests::new_test_db;
+    use chrono::TimeZone;
+
+    /// Calls `list_projects` without an `Accept` header and unwraps the JSON page
+    async fn list_json(
+        state: State<AppState>,
+        caller_role: Option<Extension<CallerRole>>,
+        params: Query<ListQueryParams>,
+    ) -> Result<PaginatedResponse<Project>> {
+        match list_projects(state, caller_role, Uri::from_static("/projects"), HeaderMap::new(), params).await? {
+            (_, ProjectListResponse::Json(page)) => Ok(page),
+            (_, _) => panic!("expected a JSON response"),
+        }
+    }
+
+    /// Counts the SQL statements sqlx runs while a future is polled
+    ///
+    /// sqlx runs SQLite statements on a worker thread that enters the caller's span,
+    /// so statements are attributed through a keyed span on a process-wide subscriber
+    /// rather than a thread-local one.
+    mod query_counter {
+        use std::{
+            collections::HashMap,
+            future::Future,
+            sync::{Mutex, Once},
+        };
+        use tracing::{Event, Instrument, Subscriber, field::Field, span};
+        use tracing_subscriber::{Registry, layer::Context, layer::SubscriberExt, registry::LookupSpan, Layer};
+
+        static COUNTS: Mutex<Option<HashMap<String, usize>>> = Mutex::new(None);
+
+        struct Key(String);
+
+        /// Reads the string field `name` of a span or event
+        struct FieldVisitor {
+            name: &'static str,
+            value: Option<String>,
+        }
+
+        impl tracing::field::Visit for FieldVisitor {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                if field.name() == self.name {
+                    self.value = Some(value.to_string());
+                }
+            }
+
+            fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+        }
+
+        struct QueryCounter;
+
+        impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for QueryCounter {
+            fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+                let mut visitor = FieldVisitor { name: "key", value: None };
+                attrs.record(&mut visitor);
+                if let (Some(key), Some(span)) = (visitor.value, ctx.span(id)) {
+                    span.extensions_mut().insert(Key(key));
+                }
+            }
+
+            fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+                if event.metadata().target() != "sqlx::query" {
+                    return;
+                }
+                // The PRAGMAs run when the pool opens a connection are not the caller's queries
+                let mut visitor = FieldVisitor { name: "db.statement", value: None };
+                event.record(&mut visitor);
+                if visitor.value.is_some_and(|statement| statement.trim_start().starts_with("PRAGMA")) {
+                    return;
+                }
+                let Some(scope) = ctx.event_scope(event) else {
+                    return;
+                };
+                for span in scope {
+                    if let Some(Key(key)) = span.extensions().get::<Key>() {
+                        *COUNTS.lock().unwrap().get_or_insert_default().entry(key.clone()).or_default() += 1;
+                        return;
+                    }
+                }
+            }
+        }
+
+        /// Output of `future` and the number of statements it ran
+        pub async fn count_queries<F: Future>(future: F) -> (F::Output, usize) {
+            static INIT: Once = Once::new();
+            INIT.call_once(|| {
+                let _ = tracing::subscriber::set_global_default(Registry::default().with(QueryCounter));
+            });
+
+            let key = uuid::Uuid::new_v4().to_string();
+            let output = future.instrument(tracing::info_span!("count_queries", key = key.as_str())).await;
+            let count = COUNTS
+                .lock()
+                .unwrap()
+                .as_mut()
+                .and_then(|counts| counts.remove(&key))
+                .unwrap_or_default();
+            (output, count)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_project_with_rating() {
+        let state = new_test_db().await;
+
+        let request = CreateProjectRequest {
+            name: "Test Project".to_string(),
+            description: "A test project".to_string(),
+            repository_url: "https://github.com/test/repo".to_string(),
+            language: "Rust".to_string(),
+            rating: Some(4.5),
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            users: None,
+        };
+
+        let (status, headers, Json(created)) = create_project(State(state), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created.project.rating, Some(4.5));
+        assert_eq!(
+            headers.get(axum::http::header::LOCATION).unwrap(),
+            &format!("/projects/{}", created.project.id)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_project_rolls_back_on_association_failure() {
+        let state = new_test_db().await;
+
+        let tech = Technology::new(CreateTechnologyRequest {
+            name: "Rust".to_string(),
+            description: None,
+        });
+        sqlx::query(
+            "INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)"
+        )
+        .bind(tech.id.to_string())
+        .bind(&tech.name)
+        .bind(&tech.description)
+        .bind(tech.created_at)
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+        // Make every association insert fail after the project row is written
+        sqlx::query(
+            "CREATE TRIGGER fail_project_technologies BEFORE INSERT ON project_technologies
+             BEGIN SELECT RAISE(ABORT, 'simulated association failure'); END"
+        )
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+        let request = CreateProjectRequest {
+            name: "Doomed".to_string(),
+            description: "Never fully created".to_string(),
+            repository_url: "https://github.com/test/doomed".to_string(),
+            language: "Rust".to_string(),
+            rating: None,
+            tags: None,
+            technology_ids: Some(vec![tech.id]),
+            user_ids: None,
+            users: None,
+        };
+
+        let result = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request)).await;
+        assert!(matches!(result, Err(AppError::DatabaseError { .. })));
+
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM projects")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_project_reports_first_missing_reference() {
+        let state = new_test_db().await;
+        let user_ids = insert_test_users(&state, &["Alice"]).await;
+
+        let tech = Technology::new(CreateTechnologyRequest {
+            name: "Rust".to_string(),
+            description: None,
+        });
+        sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+            .bind(tech.id.to_string())
+            .bind(&tech.name)
+            .bind(&tech.description)
+            .bind(tech.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        let (missing_tech, other_tech) = (Uuid::new_v4(), Uuid::new_v4());
+        let (missing_user, other_user) = (Uuid::new_v4(), Uuid::new_v4());
+        let request = |technology_ids: Vec<Uuid>, user_ids: Vec<Uuid>| CreateProjectRequest {
+            name: "Mixed".to_string(),
+            description: "Valid and invalid references".to_string(),
+            repository_url: "https://github.com/test/mixed".to_string(),
+            language: "Rust".to_string(),
+            rating: None,
+            tags: None,
+            technology_ids: Some(technology_ids),
+            user_ids: Some(user_ids),
+            users: None,
+        };
+
+        let result = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request(vec![tech.id, missing_tech, other_tech], user_ids.clone())),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::TechnologyNotFound(id)) if id == missing_tech.to_string()));
+
+        let result = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request(vec![tech.id], vec![user_ids[0], missing_user, other_user])),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::UserNotFound(id)) if id == missing_user.to_string()));
+
+        // Nothing was written, and empty reference lists still succeed
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM projects")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+        let (_, _, Json(_)) = create_project(State(state), None, Query(CreateProjectOptions::default()), ValidatedJson(request(vec![], vec![])))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_project_warns_on_duplicate_name() {
+        let state = new_test_db().await;
+
+        let request = |name: &str| CreateProjectRequest {
+            name: name.to_string(),
+            description: "Duplicate name test".to_string(),
+            repository_url: format!("https://github.com/test/{}", Uuid::new_v4()),
+            language: "Rust".to_string(),
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            users: None,
+        };
+        let warn = || Query(CreateProjectOptions { warn_duplicate_name: true });
+
+        // First project with the name: no warning
+        let (_, _, Json(first)) = create_project(State(state.clone()), None, warn(), ValidatedJson(request("Atlas")))
+            .await
+            .unwrap();
+        assert!(first.warnings.is_empty());
+        assert!(serde_json::to_value(&first).unwrap().get("warnings").is_none());
+
+        // Same name: still created, but with a warning
+        let (status, _, Json(second)) = create_project(State(state.clone()), None, warn(), ValidatedJson(request("Atlas")))
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_ne!(second.project.id, first.project.id);
+        assert_eq!(second.warnings, vec!["A project named Atlas already exists".to_string()]);
+
+        // Without the option no warning is produced
+        let (_, _, Json(third)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request("Atlas")))
+            .await
+            .unwrap();
+        assert!(third.warnings.is_empty());
+
+        // A different name does not warn
+        let (_, _, Json(other)) = create_project(State(state), None, warn(), ValidatedJson(request("Borealis")))
+            .await
+            .unwrap();
+        assert!(other.warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rating_required_for_configured_languages() {
+        use crate::state::{AppConfig, parse_language_set};
+
+        let state = new_test_db().await.with_app_config(AppConfig {
+            rating_required_languages: parse_language_set("Rust, Go"),
+            ..Default::default()
+        });
+
+        let request = |language: &str, rating: Option<f64>| CreateProjectRequest {
+            name: format!("{} project", language),
+            description: "Rating rule test".to_string(),
+            repository_url: "https://github.com/test/rating-rule".to_string(),
+            language: language.to_string(),
+            rating,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            users: None,
+        };
+
+        // Required but missing (matched case-insensitively)
+        let result = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request("rust", None))).await;
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+
+        // Exempt language needs no rating
+        let (status, _, _) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request("Zig", None)))
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+
+        // Clearing the rating of a required-language project is rejected too
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request("Go", Some(4.0))))
+            .await
+            .unwrap();
+        let update = UpdateProjectRequest {
+            name: None,
+            description: None,
+            repository_url: None,
+            language: None,
+            rating: Some(None),
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            expected_updated_at: None,
+        };
+        let result = update_project(State(state), None, ValidatedUuid(created.project.id), HeaderMap::new(), Json(update)).await;
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_project_enforces_quota() {
+        use crate::state::AppConfig;
+
+        let state = new_test_db().await.with_app_config(AppConfig {
+            max_projects: Some(2),
+            ..Default::default()
+        });
+
+        let request = |i: u32| CreateProjectRequest {
+            name: format!("Quota {}", i),
+            description: "Quota test".to_string(),
+            repository_url: "https://github.com/test/quota".to_string(),
+            language: "Rust".to_string(),
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            users: None,
+        };
+
+        let mut ids = Vec::new();
+        for i in 0..2 {
+            let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request(i)))
+                .await
+                .unwrap();
+            ids.push(created.project.id);
+        }
+
+        // At the cap: blocked with 403
+        let result = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request(2))).await;
+        match result {
+            Err(error @ AppError::QuotaExceeded(_)) => {
+                assert_eq!(axum::response::IntoResponse::into_response(error).status(), StatusCode::FORBIDDEN);
+            }
+            other => panic!("expected quota error, got {:?}", other.map(|(status, _, _)| status)),
+        }
+
+        // Freeing a slot allows creation again
+        delete_project(State(state.clone()), None, ValidatedUuid(ids[0])).await.unwrap();
+        let (status, _, _) = create_project(State(state), None, Query(CreateProjectOptions::default()), ValidatedJson(request(3)))
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_and_restore_project() {
+        let state = new_test_db().await;
+
+        let (_, _, Json(created)) = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(bulk_request("Archived")),
+        )
+        .await
+        .unwrap();
+        let id = created.project.id;
+
+        delete_project(State(state.clone()), None, ValidatedUuid(id)).await.unwrap();
+
+        // Hidden from list and get, and a second delete is a 404
+        let response = list_json(State(state.clone()), None, Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        assert!(response.data.is_empty());
+        assert_eq!(response.pagination.total_items, Some(0));
+        assert!(matches!(
+            fetch_project_with_relations(&state, id).await,
+            Err(AppError::ProjectNotFound(_))
+        ));
+        assert!(matches!(
+            delete_project(State(state.clone()), None, ValidatedUuid(id)).await,
+            Err(AppError::ProjectNotFound(_))
+        ));
+
+        // Still listed on request, carrying deleted_at
+        let params = ListQueryParams {
+            include_deleted: Some(true),
+            ..Default::default()
+        };
+        let response = list_json(State(state.clone()), None, Query(params)).await.unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert!(response.data[0].deleted_at.is_some());
+
+        let Json(restored) = restore_project(State(state.clone()), None, Path(id)).await.unwrap();
+        assert!(restored.deleted_at.is_none());
+
+        let response = list_json(State(state.clone()), None, Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].id, id);
+        assert!(fetch_project_with_relations(&state, id).await.is_ok());
+
+        assert!(matches!(
+            restore_project(State(state), None, Path(Uuid::new_v4())).await,
+            Err(AppError::ProjectNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_restore_respects_project_quota() {
+        use crate::state::AppConfig;
+
+        let state = new_test_db().await.with_app_config(AppConfig {
+            max_projects: Some(1),
+            ..Default::default()
+        });
+        let (_, _, Json(deleted)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request("Deleted")))
+            .await
+            .unwrap();
+        delete_project(State(state.clone()), None, ValidatedUuid(deleted.project.id)).await.unwrap();
+        let (_, _, Json(live)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request("Live")))
+            .await
+            .unwrap();
+
+        // Restoring would bring a second live project back
+        let error = restore_project(State(state.clone()), None, Path(deleted.project.id))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, AppError::QuotaExceeded(_)));
+        assert_eq!(error.into_response().status(), StatusCode::FORBIDDEN);
+        let still_deleted: Option<String> = sqlx::query_scalar("SELECT deleted_at FROM projects WHERE id = ?")
+            .bind(deleted.project.id.to_string())
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert!(still_deleted.is_some());
+
+        // Once the live one is deleted there is room again
+        delete_project(State(state.clone()), None, ValidatedUuid(live.project.id)).await.unwrap();
+        let Json(restored) = restore_project(State(state), None, Path(deleted.project.id)).await.unwrap();
+        assert!(restored.deleted_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_deleting_project_row_cascades_to_associations() {
+        let state = new_test_db().await;
+        let tech = Technology::new(CreateTechnologyRequest {
+            name: "Rust".to_string(),
+            description: None,
+        });
+        sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+            .bind(tech.id.to_string())
+            .bind(&tech.name)
+            .bind(&tech.description)
+            .bind(tech.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        let user_ids = insert_test_users(&state, &["Owner"]).await;
+
+        let request = CreateProjectRequest {
+            technology_ids: Some(vec![tech.id]),
+            user_ids: Some(user_ids),
+            ..bulk_request("Linked")
+        };
+        let (_, _, Json(created)) = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request),
+        )
+        .await
+        .unwrap();
+        let id = created.project.id;
+
+        let count = |table: &'static str| {
+            let db = state.db.clone();
+            async move {
+                sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM {} WHERE project_id = ?", table))
+                    .bind(id.to_string())
+                    .fetch_one(&db)
+                    .await
+                    .unwrap()
+            }
+        };
+
+        // A soft delete keeps the associations so a restore brings them back
+        delete_project(State(state.clone()), None, ValidatedUuid(id)).await.unwrap();
+        assert_eq!(count("project_technologies").await, 1);
+        assert_eq!(count("project_users").await, 1);
+
+        // Removing the row itself takes them along through ON DELETE CASCADE
+        sqlx::query("DELETE FROM projects WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(count("project_technologies").await, 0);
+        assert_eq!(count("project_users").await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_validate_project_writes_nothing() {
+        let state = new_test_db().await;
+        let user_ids = insert_test_users(&state, &["Owner"]).await;
+
+        let request = CreateProjectRequest {
+            user_ids: Some(user_ids),
+            ..bulk_request("Draft")
+        };
+        let Json(result) = validate_project(State(state.clone()), ValidatedJson(request)).await.unwrap();
+        assert!(result.valid);
+
+        let projects: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM projects")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(projects, 0);
+
+        // Missing references fail like they would on create
+        let missing = Uuid::new_v4();
+        let request = CreateProjectRequest {
+            technology_ids: Some(vec![missing]),
+            ..bulk_request("Draft")
+        };
+        let result = validate_project(State(state), ValidatedJson(request)).await;
+        assert!(matches!(result, Err(AppError::TechnologyNotFound(id)) if id == missing.to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_validate_project_endpoint_reports_field_errors() {
+        let app = crate::routes::create_router(new_test_db().await);
+        let post = |body: Value| {
+            axum::http::Request::builder()
+                .method("POST")
+                .uri("/projects/validate")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(axum::body::Body::from(body.to_string()))
+                .unwrap()
+        };
+        let body = |response: Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice::<Value>(&bytes).unwrap()
+        };
+
+        let valid = serde_json::json!({
+            "name": "Draft",
+            "description": "Not saved yet",
+            "repository_url": "https://github.com/test/draft",
+            "language": "Rust"
+        });
+        let response = tower::ServiceExt::oneshot(app.clone(), post(valid.clone())).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body(response).await, serde_json::json!({ "valid": true }));
+
+        let mut invalid = valid;
+        invalid["repository_url"] = "ftp://host/draft".into();
+        let response = tower::ServiceExt::oneshot(app, post(invalid)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let error = body(response).await;
+        assert_eq!(error["code"], "VALIDATION_ERROR");
+        assert!(error["fields"]["repository_url"].is_array(), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_repository_url_warns() {
+        let state = new_test_db().await;
+        let request = |url: &str| CreateProjectRequest {
+            repository_url: url.to_string(),
+            ..bulk_request("Mirror")
+        };
+
+        let (_, _, Json(first)) = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request("https://github.com/test/mirror")),
+        )
+        .await
+        .unwrap();
+        assert!(first.warnings.is_empty());
+
+        // Same repository spelled differently: created, with a warning
+        let (status, _, Json(second)) = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request("https://GitHub.com/test/mirror.git/")),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(
+            second.warnings,
+            vec![format!("duplicate repository_url: already used by project {}", first.project.id)]
+        );
+
+        // Updates moving onto a used repository warn too; other changes do not
+        let (_, _, Json(other)) = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request("https://github.com/test/other")),
+        )
+        .await
+        .unwrap();
+        let update = |repository_url: Option<&str>| UpdateProjectRequest {
+            name: Some("Renamed".to_string()),
+            description: None,
+            repository_url: repository_url.map(str::to_string),
+            language: None,
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            expected_updated_at: None,
+        };
+        let Json(updated) = update_project(State(state.clone()), None, ValidatedUuid(other.project.id), HeaderMap::new(), Json(update(None)))
+            .await
+            .unwrap();
+        assert!(updated.warnings.is_empty());
+        let Json(updated) = update_project(
+            State(state.clone()),
+            None,
+            ValidatedUuid(other.project.id),
+            HeaderMap::new(),
+            Json(update(Some("https://github.com/test/mirror/"))),
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.warnings.len(), 1);
+
+        // Soft-deleted projects do not count
+        delete_project(State(state.clone()), None, ValidatedUuid(first.project.id)).await.unwrap();
+        delete_project(State(state.clone()), None, ValidatedUuid(second.project.id)).await.unwrap();
+        delete_project(State(state.clone()), None, ValidatedUuid(other.project.id)).await.unwrap();
+        let (_, _, Json(fresh)) = create_project(
+            State(state),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request("https://github.com/test/mirror")),
+        )
+        .await
+        .unwrap();
+        assert!(fresh.warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_repository_url_rejected_when_strict() {
+        use crate::state::AppConfig;
+
+        let state = new_test_db().await.with_app_config(AppConfig {
+            reject_duplicate_repository_urls: true,
+            ..Default::default()
+        });
+        let request = |url: &str| CreateProjectRequest {
+            repository_url: url.to_string(),
+            ..bulk_request("Strict")
+        };
+
+        let _ = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request("https://github.com/test/strict")),
+        )
+        .await
+        .unwrap();
+        let result = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request("https://github.com/test/strict.git")),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::DuplicateResource(_))));
+
+        let projects: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM projects")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(projects, 1);
+
+        // A different path is fine
+        assert!(create_project(
+            State(state),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request("https://github.com/test/strict-two")),
+        )
+        .await
+        .is_ok());
+    }
+
+    fn bulk_request(name: &str) -> CreateProjectRequest {
+        CreateProjectRequest {
+            name: name.to_string(),
+            description: format!("Project {}", name),
+            repository_url: format!("https://github.com/test/{}", name),
+            language: "Rust".to_string(),
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            users: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_projects_bulk() {
+        let state = new_test_db().await;
+        let user_ids = insert_test_users(&state, &["Alice"]).await;
+
+        let mut second = bulk_request("Second");
+        second.user_ids = Some(user_ids.clone());
+
+        let (status, Json(created)) =
+            create_projects_bulk(State(state.clone()), None, ValidatedJson(vec![bulk_request("First"), second]))
+                .await
+                .unwrap();
+
+        assert_eq!(status, StatusCode::CREATED);
+        let names: Vec<&str> = created.iter().map(|p| p.project.name.as_str()).collect();
+        assert_eq!(names, vec!["First", "Second"]);
+        assert_eq!(created[1].users.len(), 1);
+        assert_eq!(created[1].users[0].role, UserRole::Owner);
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM projects")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_projects_bulk_reports_all_validation_errors() {
+        let state = new_test_db().await;
+
+        // Field errors are rejected by the extractor, keyed by item
+        let item = |name: &str, rating: f64| {
+            serde_json::json!({
+                "name": name,
+                "description": "Bulk",
+                "repository_url": "https://github.com/test/bulk",
+                "language": "Rust",
+                "rating": rating
+            })
+        };
+        let batch = serde_json::json!([item("Fine", 4.0), item("", 4.0), item("Rated", 9.0)]);
+        let response = tower::ServiceExt::oneshot(
+            crate::routes::create_router(state.clone()),
+            axum::http::Request::builder()
+                .method("POST")
+                .uri("/projects/bulk")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(axum::body::Body::from(batch.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let error: Value = serde_json::from_slice(&bytes).unwrap();
+        let mut fields: Vec<&String> = error["fields"].as_object().unwrap().keys().collect();
+        fields.sort();
+        assert_eq!(fields, vec!["[1].name", "[2].rating"]);
+
+        // Checks beyond the fields are collected across items by the handler
+        let rust_rated = state.clone().with_app_config(crate::state::AppConfig {
+            rating_required_languages: ["rust".to_string()].into_iter().collect(),
+            ..Default::default()
+        });
+        let mut rated = bulk_request("Fine");
+        rated.rating = Some(4.0);
+
+        let error = create_projects_bulk(
+            State(rust_rated),
+            None,
+            ValidatedJson(vec![rated, bulk_request("Unrated"), bulk_request("Unrated too")]),
+        )
+        .await
+        .unwrap_err();
+
+        match error {
+            AppError::ValidationError(message) => {
+                assert!(message.contains("Item 1"));
+                assert!(message.contains("Item 2"));
+                assert!(!message.contains("Item 0"));
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+
+        // A missing reference rolls back the items inserted before it
+        let mut missing_tech = bulk_request("Broken");
+        missing_tech.technology_ids = Some(vec![Uuid::new_v4()]);
+        let error = create_projects_bulk(State(state.clone()), None, ValidatedJson(vec![bulk_request("Fine"), missing_tech]))
+            .await
+            .unwrap_err();
+        match error {
+            AppError::TechnologyNotFound(id) => assert!(id.ends_with("(item 1)")),
+            other => panic!("expected technology not found, got {:?}", other),
+        }
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM projects")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_projects_bulk_size_cap() {
+        let state = new_test_db().await;
+
+        let requests = (0..=MAX_BULK_PROJECTS)
+            .map(|i| bulk_request(&format!("Project {}", i)))
+            .collect();
+        let error = create_projects_bulk(State(state), None, ValidatedJson(requests)).await.unwrap_err();
+        assert!(matches!(error, AppError::ValidationError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_handlers_cap_concurrent_db_ops() {
+        use crate::state::AppConfig;
+
+        // Holds a write lock on `users` so every reference lookup blocks, then
+        // counts the pool connections checked out while the handler waits on them
+        async fn peak_lookups<F: std::future::Future + Send + 'static>(state: &AppState, handler: F) -> u32
+        where
+            F::Output: Send,
+        {
+            let mut lock = state.db.acquire().await.unwrap();
+            sqlx::query("BEGIN").execute(&mut *lock).await.unwrap();
+            sqlx::query("UPDATE users SET name = name").execute(&mut *lock).await.unwrap();
+
+            let handler = tokio::spawn(handler);
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            let checked_out = state.db.size() - state.db.num_idle() as u32 - 1;
+
+            sqlx::query("COMMIT").execute(&mut *lock).await.unwrap();
+            drop(lock);
+            handler.await.unwrap();
+            checked_out
+        }
+
+        for cap in [2, 4] {
+            let state = new_test_db().await.with_app_config(AppConfig {
+                max_concurrent_db_ops: cap,
+                ..Default::default()
+            });
+            let user_ids = insert_test_users(&state, &["Alice"]).await;
+
+            let requests: Vec<CreateProjectRequest> = (0..20)
+                .map(|i| CreateProjectRequest {
+                    user_ids: Some(user_ids.clone()),
+                    ..bulk_request(&format!("Project {}", i))
+                })
+                .collect();
+            let bulk = create_projects_bulk(State(state.clone()), None, ValidatedJson(requests));
+            let bulk = async move {
+                let (_, Json(created)) = bulk.await.unwrap();
+                assert_eq!(created.len(), 20);
+            };
+            assert_eq!(peak_lookups(&state, bulk).await as usize, cap);
+
+            let records: Vec<Value> = (0..20)
+                .map(|i| import_record_for(&format!("Imported {}", i), &[], &[(user_ids[0], UserRole::Owner)]))
+                .collect();
+            let body = serde_json::to_string(&records).unwrap();
+            let import = import_projects(State(state.clone()), None, Query(ImportOptions::default()), body);
+            let import = async move {
+                let Json(summary) = import.await.unwrap();
+                assert_eq!(summary.inserted, 20);
+            };
+            assert_eq!(peak_lookups(&state, import).await as usize, cap);
+
+            let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM projects").fetch_one(&state.db).await.unwrap();
+            assert_eq!(count, 40);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_pagination() {
+        let state = new_test_db().await;
+
+        // Create multiple projects
+        for i in 1..=15 {
+            let request = CreateProjectRequest {
+                name: format!("Project {}", i),
+                description: format!("Description {}", i),
+                repository_url: format!("https://github.com/test/repo{}", i),
+                language: "Rust".to_string(),
+                rating: Some(i as f64 % 5.0),
+                tags: None,
+                technology_ids: None,
+                user_ids: None,
+                users: None,
+            };
+
+            let _ = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        // Test pagination
+        let params = ListQueryParams {
+            search: None,
+            technology: None,
+            tech: None,
+            tech_mode: None,
+            user_id: None,
+            min_rating: None,
+            max_rating: None,
+            language: None,
+            languages: None,
+            tag: None,
+            match_mode: None,
+            sort: None,
+            order: None,
+            page: Some(1),
+            page_size: Some(10),
+            include_deleted: None,
+            fields: None,
+            include: None,
+            with_total: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+            updated_before: None,
+        };
+
+        let response = list_json(State(state), None, Query(params)).await.unwrap();
+        assert_eq!(response.data.len(), 10);
+        assert_eq!(response.pagination.total_items, Some(15));
+        assert_eq!(response.pagination.total_pages, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_default_page_size_by_role() {
+        use crate::state::AppConfig;
+
+        let state = new_test_db().await.with_app_config(AppConfig {
+            role_page_sizes: [("admin".to_string(), 50)].into_iter().collect(),
+            ..Default::default()
+        });
+
+        for i in 1..=15 {
+            let request = CreateProjectRequest {
+                name: format!("Project {}", i),
+                description: format!("Description {}", i),
+                repository_url: format!("https://github.com/test/repo{}", i),
+                language: "Rust".to_string(),
+                rating: None,
+                tags: None,
+                technology_ids: None,
+                user_ids: None,
+                users: None,
+            };
+            let _ = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        // Public caller gets the global default
+        let public = list_json(State(state.clone()), None, Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        assert_eq!(public.pagination.page_size, 10);
+        assert_eq!(public.data.len(), 10);
+
+        // Admin gets the configured default on the same endpoint
+        let admin = Some(Extension(CallerRole("admin".to_string())));
+        let response = list_json(State(state.clone()), admin.clone(), Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        assert_eq!(response.pagination.page_size, 50);
+        assert_eq!(response.data.len(), 15);
+
+        // An explicit page_size still wins
+        let params = ListQueryParams {
+            page_size: Some(5),
+            ..Default::default()
+        };
+        let response = list_json(State(state.clone()), admin, Query(params))
+            .await
+            .unwrap();
+        assert_eq!(response.pagination.page_size, 5);
+
+        // Unconfigured roles fall back to the global default
+        let viewer = Some(Extension(CallerRole("viewer".to_string())));
+        let response = list_json(State(state), viewer, Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        assert_eq!(response.pagination.page_size, 10);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_multiple_languages() {
+        let state = new_test_db().await;
+
+        for (name, language) in [("Ferris", "Rust"), ("Gopher", "Go"), ("Snake", "Python")] {
+            let request = CreateProjectRequest {
+                name: name.to_string(),
+                description: format!("A {} project", language),
+                repository_url: format!("https://github.com/test/{}", name),
+                language: language.to_string(),
+                rating: None,
+                tags: None,
+                technology_ids: None,
+                user_ids: None,
+                users: None,
+            };
+            let _ = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        let params = ListQueryParams {
+            languages: Some("rust, GO".to_string()),
+            sort: Some("name".to_string()),
+            order: Some("asc".to_string()),
+            ..Default::default()
+        };
+        let response = list_json(State(state.clone()), None, Query(params)).await.unwrap();
+
+        let names: Vec<&str> = response.data.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Ferris", "Gopher"]);
+        assert_eq!(response.pagination.total_items, Some(2));
+
+        // The single-language form keeps working
+        let params = ListQueryParams {
+            language: Some("Python".to_string()),
+            ..Default::default()
+        };
+        let response = list_json(State(state), None, Query(params)).await.unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].name, "Snake");
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_language_filter_is_normalized() {
+        let state = new_test_db().await;
+
+        for (name, language) in [("Ferris", "Rust"), ("Crab", " rust "), ("Gopher", "Go")] {
+            let request = CreateProjectRequest {
+                name: name.to_string(),
+                description: format!("A {} project", language.trim()),
+                repository_url: format!("https://github.com/test/{}", name),
+                language: language.to_string(),
+                rating: None,
+                tags: None,
+                technology_ids: None,
+                user_ids: None,
+                users: None,
+            };
+            let _ = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        for filter in ["rust", "Rust", " Rust "] {
+            let params = ListQueryParams {
+                language: Some(filter.to_string()),
+                sort: Some("name".to_string()),
+                order: Some("asc".to_string()),
+                ..Default::default()
+            };
+            let response = list_json(State(state.clone()), None, Query(params)).await.unwrap();
+
+            let names: Vec<&str> = response.data.iter().map(|p| p.name.as_str()).collect();
+            assert_eq!(names, vec!["Crab", "Ferris"], "filter {:?}", filter);
+            assert_eq!(response.pagination.total_items, Some(2));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_ignores_case_and_accents() {
+        let state = new_test_db().await;
+
+        for (name, description) in [("Côté Café", "Maps for the coast"), ("Resume Parser", "Reads a plain resume")] {
+            let request = CreateProjectRequest {
+                name: name.to_string(),
+                description: description.to_string(),
+                repository_url: format!("https://github.com/test/{}", name.len()),
+                language: "Rust".to_string(),
+                rating: None,
+                tags: None,
+                technology_ids: None,
+                user_ids: None,
+                users: None,
+            };
+            let _ = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        let search = |search: &str| ListQueryParams {
+            search: Some(search.to_string()),
+            ..Default::default()
+        };
+        let names = |response: PaginatedResponse<Project>| -> Vec<String> {
+            response.data.into_iter().map(|p| p.name).collect()
+        };
+
+        // An unaccented query matches an accented project...
+        let response = list_json(State(state.clone()), None, Query(search("COTE cafe"))).await.unwrap();
+        assert_eq!(names(response), vec!["Côté Café"]);
+
+        // ...and an accented query matches an unaccented one, in name or description
+        let response = list_json(State(state.clone()), None, Query(search("Résumé"))).await.unwrap();
+        assert_eq!(names(response), vec!["Resume Parser"]);
+
+        // Renames keep the folded copy in sync
+        let update = UpdateProjectRequest {
+            name: Some("Ñandú".to_string()),
+            description: None,
+            repository_url: None,
+            language: None,
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            expected_updated_at: None,
+        };
+        let project_id = list_json(State(state.clone()), None, Query(search("parser"))).await.unwrap().data[0].id;
+        let _ = update_project(State(state.clone()), None, ValidatedUuid(project_id), HeaderMap::new(), Json(update))
+            .await
+            .unwrap();
+        let response = list_json(State(state.clone()), None, Query(search("nandu"))).await.unwrap();
+        assert_eq!(names(response), vec!["Ñandú"]);
+        assert!(list_json(State(state), None, Query(search("parser"))).await.unwrap().data.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_blob_backfill_keeps_updated_at() {
+        let state = new_test_db().await;
+        let id = Uuid::new_v4();
+        let updated_at = "2024-01-01T00:00:00.000+00:00";
+        sqlx::query(
+            "INSERT INTO projects (id, name, description, repository_url, language, created_at, updated_at)
+             VALUES (?, 'Crème Brûlée', 'Dessert recipes', 'https://github.com/test/creme', 'Rust', ?, ?)",
+        )
+        .bind(id.to_string())
+        .bind(updated_at)
+        .bind(updated_at)
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+        // Without a search_blob the row only matches through the ASCII fallback
+        let search = || ListQueryParams {
+            search: Some("creme brulee".to_string()),
+            ..Default::default()
+        };
+        assert!(list_json(State(state.clone()), None, Query(search())).await.unwrap().data.is_empty());
+
+        assert_eq!(state.backfill_search_blobs().await.unwrap(), 1);
+        let response = list_json(State(state.clone()), None, Query(search())).await.unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].id, id);
+
+        let stored: String = sqlx::query_scalar("SELECT updated_at FROM projects WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(stored, updated_at);
+
+        // Content changes still bump it
+        sqlx::query("UPDATE projects SET name = 'Renamed' WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&state.db)
+            .await
+            .unwrap();
+        let stored: String = sqlx::query_scalar("SELECT updated_at FROM projects WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_ne!(stored, updated_at);
+        assert_eq!(state.backfill_search_blobs().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_tag_filter() {
+        let state = new_test_db().await;
+
+        for (name, tags) in [("Ferris", vec![" CLI ", "async"]), ("Crab", vec!["cli"]), ("Gopher", vec!["web"])] {
+            let request = CreateProjectRequest {
+                name: name.to_string(),
+                description: format!("The {} project", name),
+                repository_url: format!("https://github.com/test/{}", name),
+                language: "Rust".to_string(),
+                rating: None,
+                tags: Some(tags.into_iter().map(str::to_string).collect()),
+                technology_ids: None,
+                user_ids: None,
+                users: None,
+            };
+            let _ = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        let params = ListQueryParams {
+            tag: Some(" Cli".to_string()),
+            sort: Some("name".to_string()),
+            order: Some("asc".to_string()),
+            ..Default::default()
+        };
+        let response = list_json(State(state.clone()), None, Query(params)).await.unwrap();
+        let names: Vec<&str> = response.data.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Crab", "Ferris"]);
+        assert_eq!(response.pagination.total_items, Some(2));
+        // Stored trimmed and lowercased, in the order given
+        assert_eq!(response.data[1].tags, vec!["cli", "async"]);
+
+        // Replacing the tags on update moves the project out of the filter
+        let update = UpdateProjectRequest {
+            name: None,
+            description: None,
+            repository_url: None,
+            language: None,
+            rating: None,
+            tags: Some(vec!["tui".to_string()]),
+            technology_ids: None,
+            user_ids: None,
+            expected_updated_at: None,
+        };
+        let _ = update_project(State(state.clone()), None, ValidatedUuid(response.data[0].id), HeaderMap::new(), Json(update))
+            .await
+            .unwrap();
+        let params = ListQueryParams {
+            tag: Some("cli".to_string()),
+            ..Default::default()
+        };
+        let response = list_json(State(state), None, Query(params)).await.unwrap();
+        let names: Vec<&str> = response.data.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Ferris"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_multi_field_sort() {
+        let state = new_test_db().await;
+
+        for (name, rating) in [("Alpha", Some(4.0)), ("Bravo", Some(5.0)), ("Charlie", Some(4.0)), ("Delta", None)] {
+            let request = CreateProjectRequest {
+                name: name.to_string(),
+                description: format!("Project {}", name),
+                repository_url: format!("https://github.com/test/{}", name),
+                language: "Rust".to_string(),
+                rating,
+                tags: None,
+                technology_ids: None,
+                user_ids: None,
+                users: None,
+            };
+            let _ = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        // Equal ratings are broken by name ascending
+        let params = ListQueryParams {
+            sort: Some("rating,name".to_string()),
+            order: Some("desc,asc".to_string()),
+            ..Default::default()
+        };
+        let response = list_json(State(state.clone()), None, Query(params)).await.unwrap();
+        let names: Vec<&str> = response.data.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Bravo", "Alpha", "Charlie", "Delta"]);
+
+        let params = ListQueryParams {
+            sort: Some("rating".to_string()),
+            order: Some("desc,asc".to_string()),
+            ..Default::default()
+        };
+        let error = list_json(State(state), None, Query(params)).await.unwrap_err();
+        assert!(matches!(error, AppError::ValidationError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_relevance_search() {
+        let state = new_test_db().await;
+
+        for (name, description) in [
+            ("Logger", "Structured logging with an async runtime adapter"),
+            ("Tokio Runtime", "Async runtime for writing reliable async network applications"),
+            ("Calculator", "Command line calculator"),
+            ("Scheduler", "Cron-like job runner"),
+        ] {
+            let mut request = bulk_request(name);
+            request.description = description.to_string();
+            let (_, _, Json(_)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        let search = |search: &str| ListQueryParams {
+            search: Some(search.to_string()),
+            sort: Some("relevance".to_string()),
+            ..Default::default()
+        };
+
+        let response = list_json(State(state.clone()), None, Query(search("async runtime")))
+            .await
+            .unwrap();
+        let names: Vec<&str> = response.data.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Tokio Runtime", "Logger"]);
+        assert_eq!(response.pagination.total_items, Some(2));
+
+        // The index follows renames, and FTS operators in the input are treated as words
+        sqlx::query("UPDATE projects SET name = 'Async Job Scheduler' WHERE name = 'Scheduler'")
+            .execute(&state.db)
+            .await
+            .unwrap();
+        let response = list_json(State(state.clone()), None, Query(search("scheduler")))
+            .await
+            .unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].name, "Async Job Scheduler");
+        assert!(list_json(State(state.clone()), None, Query(search("\"calc OR -"))).await.is_ok());
+
+        // Without the index the same request falls back to the LIKE filter
+        sqlx::query("DROP TABLE projects_fts").execute(&state.db).await.unwrap();
+        let response = list_json(State(state), None, Query(search("async runtime")))
+            .await
+            .unwrap();
+        assert_eq!(response.data.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_date_ranges() {
+        let state = new_test_db().await;
+
+        let at = |day: u32| Utc.with_ymd_and_hms(2025, 1, day, 12, 0, 0).unwrap();
+        for (name, created, updated) in [("Early", 1, 20), ("Middle", 10, 10), ("Late", 20, 25)] {
+            let (_, _, Json(project)) =
+                create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request(name)))
+                    .await
+                    .unwrap();
+            sqlx::query("UPDATE projects SET created_at = ?, updated_at = ? WHERE id = ?")
+                .bind(at(created))
+                .bind(at(updated))
+                .bind(project.project.id.to_string())
+                .execute(&state.db)
+                .await
+                .unwrap();
+        }
+
+        let names = |response: PaginatedResponse<Project>| {
+            let mut names: Vec<String> = response.data.into_iter().map(|p| p.name).collect();
+            names.sort();
+            names
+        };
+
+        // Bounds are inclusive
+        let params = ListQueryParams {
+            created_after: Some(at(10)),
+            created_before: Some(at(20)),
+            ..Default::default()
+        };
+        let response = list_json(State(state.clone()), None, Query(params)).await.unwrap();
+        assert_eq!(response.pagination.total_items, Some(2));
+        assert_eq!(names(response), vec!["Late", "Middle"]);
+
+        let params = ListQueryParams {
+            updated_after: Some(at(15)),
+            ..Default::default()
+        };
+        let response = list_json(State(state.clone()), None, Query(params)).await.unwrap();
+        assert_eq!(names(response), vec!["Early", "Late"]);
+
+        let params = ListQueryParams {
+            created_before: Some(at(5)),
+            updated_before: Some(at(22)),
+            ..Default::default()
+        };
+        let response = list_json(State(state.clone()), None, Query(params)).await.unwrap();
+        assert_eq!(names(response), vec!["Early"]);
+
+        let params = ListQueryParams {
+            created_after: Some(at(20)),
+            created_before: Some(at(10)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            list_json(State(state), None, Query(params)).await,
+            Err(AppError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_technology_list_modes() {
+        let state = new_test_db().await;
+
+        let mut tech_ids = Vec::new();
+        for name in ["Rust", "Go", "Rustls"] {
+            let tech = Technology::new(CreateTechnologyRequest {
+                name: name.to_string(),
+                description: None,
+            });
+            sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+                .bind(tech.id.to_string())
+                .bind(&tech.name)
+                .bind(&tech.description)
+                .bind(tech.created_at)
+                .execute(&state.db)
+                .await
+                .unwrap();
+            tech_ids.push(tech.id);
+        }
+
+        for (name, technologies) in [
+            ("Both", vec![tech_ids[0], tech_ids[1]]),
+            ("Rust Only", vec![tech_ids[0]]),
+            ("Go Only", vec![tech_ids[1]]),
+            ("Rustls Only", vec![tech_ids[2]]),
+        ] {
+            let mut request = bulk_request(name);
+            request.technology_ids = Some(technologies);
+            let (_, _, Json(_)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        let list = |technology: &str, tech_mode: Option<&str>| {
+            let params = ListQueryParams {
+                technology: Some(technology.to_string()),
+                tech_mode: tech_mode.map(str::to_string),
+                sort: Some("name".to_string()),
+                order: Some("asc".to_string()),
+                ..Default::default()
+            };
+            list_json(State(state.clone()), None, Query(params))
+        };
+        let names = |response: PaginatedResponse<Project>| -> Vec<String> {
+            response.data.into_iter().map(|p| p.name).collect()
+        };
+
+        // Any of the (exact, case-insensitive, de-duplicated) names
+        let response = list("rust, GO,rust", None).await.unwrap();
+        assert_eq!(response.pagination.total_items, Some(3));
+        assert_eq!(names(response), vec!["Both", "Go Only", "Rust Only"]);
+
+        // Every one of them
+        let response = list("rust,go,rust", Some("all")).await.unwrap();
+        assert_eq!(response.pagination.total_items, Some(1));
+        assert_eq!(names(response), vec!["Both"]);
+
+        // A single value keeps the substring match
+        let response = list("rust", None).await.unwrap();
+        assert_eq!(names(response), vec!["Both", "Rust Only", "Rustls Only"]);
+
+        assert!(matches!(list("rust,go", Some("some")).await, Err(AppError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_exact_match() {
+        let state = new_test_db().await;
+
+        let tech = Technology::new(CreateTechnologyRequest {
+            name: "MongoDB".to_string(),
+            description: None,
+        });
+        sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+            .bind(tech.id.to_string())
+            .bind(&tech.name)
+            .bind(&tech.description)
+            .bind(tech.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        for (name, language) in [("Gopher", "Go"), ("Golang Tool", "Golang")] {
+            let mut request = bulk_request(name);
+            request.language = language.to_string();
+            request.technology_ids = Some(vec![tech.id]);
+            let (_, _, Json(_)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        let list = |language: Option<&str>, technology: Option<&str>, match_mode: Option<&str>| {
+            let params = ListQueryParams {
+                language: language.map(str::to_string),
+                technology: technology.map(str::to_string),
+                match_mode: match_mode.map(str::to_string),
+                ..Default::default()
+            };
+            list_json(State(state.clone()), None, Query(params))
+        };
+
+        // Substring matching (the default) also finds "Golang"
+        assert_eq!(list(Some("Go"), None, None).await.unwrap().data.len(), 2);
+
+        let response = list(Some("Go"), None, Some("exact")).await.unwrap();
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].name, "Gopher");
+
+        // Technologies compare against the full (case-insensitive) name
+        assert_eq!(list(None, Some("mongo"), None).await.unwrap().data.len(), 2);
+        assert!(list(None, Some("mongo"), Some("exact")).await.unwrap().data.is_empty());
+        assert_eq!(list(None, Some("mongodb"), Some("exact")).await.unwrap().data.len(), 2);
+
+        assert!(matches!(
+            list(Some("Go"), None, Some("fuzzy")).await,
+            Err(AppError::ValidationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_escapes_like_wildcards() {
+        let state = new_test_db().await;
+
+        for (name, description, language) in [
+            ("Discount", "Take 50% off", "Rust"),
+            ("Fifty", "Version 50 released", "Rust"),
+            ("Snake", "snake_case helpers", "C_Sharp"),
+            ("Camel", "snakeXcase helpers", "CXSharp"),
+        ] {
+            let mut request = bulk_request(name);
+            request.description = description.to_string();
+            request.language = language.to_string();
+            let (_, _, Json(_)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        let list = |search: Option<&str>, language: Option<&str>| {
+            let params = ListQueryParams {
+                search: search.map(str::to_string),
+                language: language.map(str::to_string),
+                ..Default::default()
+            };
+            list_json(State(state.clone()), None, Query(params))
+        };
+        let names = |response: PaginatedResponse<Project>| -> Vec<String> {
+            response.data.into_iter().map(|p| p.name).collect()
+        };
+
+        assert_eq!(names(list(Some("50%"), None).await.unwrap()), vec!["Discount"]);
+        assert_eq!(names(list(Some("snake_case"), None).await.unwrap()), vec!["Snake"]);
+        assert_eq!(names(list(Some("%"), None).await.unwrap()), vec!["Discount"]);
+        assert_eq!(names(list(None, Some("c_sharp")).await.unwrap()), vec!["Snake"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_csv_negotiation() {
+        let state = new_test_db().await;
+
+        for (name, description) in [("Plain", "Simple text"), ("Quoted", "Fast, \"safe\" and small")] {
+            let mut request = bulk_request(name);
+            request.description = description.to_string();
+            let (_, _, Json(_)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/csv"));
+        let (_, body) = list_projects(State(state.clone()), None, Uri::from_static("/projects"), headers, Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        let ProjectListResponse::Csv(csv) = body else {
+            panic!("expected a CSV response");
+        };
+
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "id,name,description,repository_url,language,rating,tags,created_at,updated_at,deleted_at"
+        );
+        assert_eq!(lines.len(), 3);
+        assert!(csv.contains(",Quoted,\"Fast, \"\"safe\"\" and small\","));
+        assert!(csv.contains(",Plain,Simple text,"));
+
+        // No Accept header (or a JSON preference) keeps the JSON envelope
+        let response = list_json(State(state.clone()), None, Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        assert_eq!(response.data.len(), 2);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/json, text/csv"));
+        let (_, body) = list_projects(State(state), None, Uri::from_static("/projects"), headers, Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        assert!(matches!(body, ProjectListResponse::Json(_)));
+    }
+
+    #[tokio::test]
+    async fn test_json_api_documents() {
+        let state = new_test_db().await;
+        let user_ids = insert_test_users(&state, &["Alice"]).await;
+        let tech = Technology::new(CreateTechnologyRequest {
+            name: "Rust".to_string(),
+            description: None,
+        });
+        sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+            .bind(tech.id.to_string())
+            .bind(&tech.name)
+            .bind(&tech.description)
+            .bind(tech.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        let mut request = bulk_request("Linked");
+        request.technology_ids = Some(vec![tech.id]);
+        request.user_ids = Some(user_ids.clone());
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+        let (_, _, Json(_)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request("Alone")))
+            .await
+            .unwrap();
+        let role = created.users[0].role.as_str();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/vnd.api+json"));
+
+        // List: one resource per project, linkage as relationships, pagination under meta
+        let params = ListQueryParams {
+            sort: Some("name".to_string()),
+            order: Some("desc".to_string()),
+            ..Default::default()
+        };
+        let (list_headers, body) = list_projects(State(state.clone()), None, Uri::from_static("/projects"), headers.clone(), Query(params))
+            .await
+            .unwrap();
+        assert_eq!(list_headers[header::VARY], "accept");
+        let response = body.into_response();
+        assert_eq!(response.headers()[header::CONTENT_TYPE], JSON_API_MEDIA_TYPE);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let document: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(document["meta"]["pagination"]["total_items"], 2);
+        let data = document["data"].as_array().unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0]["type"], "projects");
+        assert_eq!(data[0]["id"], created.project.id.to_string());
+        assert_eq!(data[0]["attributes"]["name"], "Linked");
+        assert!(data[0]["attributes"].get("id").is_none());
+        assert_eq!(
+            data[0]["relationships"]["technologies"]["data"],
+            serde_json::json!([{ "type": "technologies", "id": tech.id }])
+        );
+        assert_eq!(
+            data[0]["relationships"]["users"]["data"],
+            serde_json::json!([{ "type": "users", "id": user_ids[0], "meta": { "role": role } }])
+        );
+        assert_eq!(data[1]["relationships"]["technologies"]["data"], serde_json::json!([]));
+
+        // Single project: compound document with the related resources included
+        let response = get_project(State(state), ValidatedUuid(created.project.id), headers).await.unwrap();
+        assert_eq!(response.headers()[header::CONTENT_TYPE], JSON_API_MEDIA_TYPE);
+        assert!(response.headers().contains_key(header::ETAG));
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let document: Document<Resource> = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(document.data.kind, "projects");
+        assert_eq!(document.data.id, created.project.id);
+        assert_eq!(document.data.attributes["language"], "Rust");
+        assert_eq!(document.data.relationships["technologies"].data, vec![ResourceIdentifier::technology(tech.id)]);
+        assert_eq!(document.data.relationships["users"].data, vec![ResourceIdentifier::member(user_ids[0], role)]);
+        let included: Vec<(&str, Uuid)> = document.included.iter().map(|resource| (resource.kind.as_str(), resource.id)).collect();
+        assert_eq!(included, vec![("technologies", tech.id), ("users", user_ids[0])]);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_include_relations() {
+        let state = new_test_db().await;
+        let user_ids = insert_test_users(&state, &["Alice", "Bob"]).await;
+        let tech = Technology::new(CreateTechnologyRequest {
+            name: "Rust".to_string(),
+            description: None,
+        });
+        sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+            .bind(tech.id.to_string())
+            .bind(&tech.name)
+            .bind(&tech.description)
+            .bind(tech.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        for index in 0..6 {
+            let mut request = bulk_request(&format!("Included{}", index));
+            request.technology_ids = Some(vec![tech.id]);
+            request.user_ids = Some(vec![user_ids[index % 2]]);
+            let _ = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+        }
+
+        let list = |page_size: u32, include: Option<&str>| {
+            let params = ListQueryParams {
+                include: include.map(str::to_string),
+                page_size: Some(page_size),
+                sort: Some("name".to_string()),
+                order: Some("asc".to_string()),
+                ..Default::default()
+            };
+            list_projects(State(state.clone()), None, Uri::from_static("/projects"), HeaderMap::new(), Query(params))
+        };
+
+        let (response, queries) = query_counter::count_queries(list(6, Some("technologies,users"))).await;
+        let (_, ProjectListResponse::WithRelations(page)) = response.unwrap() else {
+            panic!("expected projects with relations");
+        };
+        assert_eq!(page.data.len(), 6);
+        for (index, project) in page.data.iter().enumerate() {
+            assert_eq!(project.technologies.len(), 1);
+            assert_eq!(project.technologies[0].technology.id, tech.id);
+            assert_eq!(project.users.len(), 1);
+            assert_eq!(project.users[0].user.id, user_ids[index % 2]);
+        }
+
+        // Relations cost one query each, whatever the page size
+        let (_, bare) = query_counter::count_queries(list(6, None)).await;
+        let (_, small_page) = query_counter::count_queries(list(2, Some("users,technologies"))).await;
+        assert_eq!(bare, 2, "count and page queries");
+        assert_eq!(queries, bare + 2);
+        assert_eq!(small_page, queries);
+
+        // Only the requested relation is loaded
+        let (response, technologies_only) = query_counter::count_queries(list(6, Some("technologies"))).await;
+        assert_eq!(technologies_only, bare + 1);
+        let (_, ProjectListResponse::WithRelations(page)) = response.unwrap() else {
+            panic!("expected projects with relations");
+        };
+        assert!(page.data.iter().all(|p| p.technologies.len() == 1 && p.users.is_empty()));
+
+        // Without `include` the page keeps its plain shape; unknown relations are rejected
+        assert!(matches!(list(6, None).await.unwrap().1, ProjectListResponse::Json(_)));
+        assert!(matches!(list(6, Some("reviews")).await, Err(AppError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_export_projects_round_trip() {
+        use futures::TryStreamExt;
+
+        let state = new_test_db().await;
+        let user_ids = insert_test_users(&state, &["Alice", "Bob"]).await;
+        let mut technology_ids = Vec::new();
+        for name in ["Rust", "Axum"] {
+            let tech = Technology::new(CreateTechnologyRequest {
+                name: name.to_string(),
+                description: Some(format!("About {}", name)),
+            });
+            sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+                .bind(tech.id.to_string())
+                .bind(&tech.name)
+                .bind(&tech.description)
+                .bind(tech.created_at)
+                .execute(&state.db)
+                .await
+                .unwrap();
+            technology_ids.push(tech.id);
+        }
+
+        let mut ids = Vec::new();
+        for (index, name) in ["One", "Two", "Three"].into_iter().enumerate() {
+            let mut request = bulk_request(name);
+            request.rating = Some(index as f64);
+            request.technology_ids = Some(technology_ids[..index.min(2)].to_vec());
+            request.user_ids = Some(user_ids[..index.min(2)].to_vec());
+            let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+            ids.push(created.project.id);
+        }
+
+        // Small batches so the export spans several queries
+        let chunks: Vec<String> = export_stream(state.clone(), 2).try_collect().await.unwrap();
+        assert_eq!(chunks.len(), 2);
+        let exported: Vec<ProjectWithRelations> = serde_json::from_str(&chunks.concat()).unwrap();
+        assert_eq!(exported.len(), 3);
+
+        for project in &exported {
+            let stored = fetch_project_with_relations(&state, project.project.id).await.unwrap();
+            assert_eq!(serde_json::to_value(project).unwrap(), serde_json::to_value(&stored).unwrap());
+        }
+        let mut exported_ids: Vec<Uuid> = exported.iter().map(|project| project.project.id).collect();
+        exported_ids.sort();
+        ids.sort();
+        assert_eq!(exported_ids, ids);
+        let linked = exported.iter().find(|project| project.project.name == "Three").unwrap();
+        assert_eq!(linked.technologies.len(), 2);
+        assert_eq!(linked.users.len(), 2);
+
+        // The endpoint sends the same array as an attachment
+        let response = export_projects(State(state.clone())).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_DISPOSITION],
+            "attachment; filename=\"projects-export.json\""
+        );
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Vec<ProjectWithRelations> = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body.len(), 3);
+
+        // Importing the export back into the same database keeps every updated_at
+        let updated_at = || async {
+            sqlx::query_scalar::<_, String>("SELECT updated_at FROM projects ORDER BY id")
+                .fetch_all(&state.db)
+                .await
+                .unwrap()
+        };
+        let before = updated_at().await;
+        let summary = import(&state, ImportOptions::default(), String::from_utf8(bytes.to_vec()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(summary.updated, 3);
+        assert_eq!(updated_at().await, before);
+    }
+
+    #[tokio::test]
+    async fn test_export_projects_empty() {
+        use futures::TryStreamExt;
+
+        let state = new_test_db().await;
+        let chunks: Vec<String> = export_stream(state, 2).try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), "[]");
+    }
+
+    /// Export-format record for a new project linked to `technologies` and `users`
+    fn import_record_for(name: &str, technologies: &[Technology], users: &[(Uuid, UserRole)]) -> Value {
+        let now = Utc::now();
+        serde_json::json!({
+            "id": Uuid::new_v4(),
+            "name": name,
+            "description": format!("Imported {}", name),
+            "repository_url": format!("https://github.com/test/{}", name),
+            "language": "Rust",
+            "rating": 3.5,
+            "created_at": now,
+            "updated_at": now,
+            "technologies": technologies
+                .iter()
+                .map(|technology| serde_json::json!({
+                    "id": technology.id,
+                    "name": technology.name,
+                    "description": technology.description,
+                    "created_at": technology.created_at,
+                    "updated_at": technology.updated_at,
+                    "associated_at": now,
+                }))
+                .collect::<Vec<_>>(),
+            "users": users
+                .iter()
+                .map(|(id, role)| serde_json::json!({
+                    "id": id,
+                    "name": "Ignored",
+                    "email": "ignored@example.com",
+                    "created_at": now,
+                    "updated_at": now,
+                    "role": role.as_str(),
+                    "associated_at": now,
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    async fn import(state: &AppState, options: ImportOptions, body: String) -> Result<ImportSummary> {
+        import_projects(State(state.clone()), None, Query(options), body).await.map(|Json(summary)| summary)
+    }
+
+    #[tokio::test]
+    async fn test_import_projects_clean() {
+        let state = new_test_db().await;
+        let user_ids = insert_test_users(&state, &["Alice", "Bob"]).await;
+        let known = Technology::new(CreateTechnologyRequest {
+            name: "Rust".to_string(),
+            description: None,
+        });
+        sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+            .bind(known.id.to_string())
+            .bind(&known.name)
+            .bind(&known.description)
+            .bind(known.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        let unknown = Technology::new(CreateTechnologyRequest {
+            name: "Zig".to_string(),
+            description: Some("Imported along".to_string()),
+        });
+
+        let records = vec![
+            import_record_for("Atlas", std::slice::from_ref(&known), &[(user_ids[0], UserRole::Owner), (user_ids[1], UserRole::Viewer)]),
+            import_record_for("Beacon", &[known.clone(), unknown.clone()], &[]),
+        ];
+
+        // Unknown technologies are only created when asked to
+        let body = serde_json::to_string(&records).unwrap();
+        let result = import(&state, ImportOptions::default(), body.clone()).await;
+        assert!(matches!(result, Err(AppError::TechnologyNotFound(_))));
+
+        let summary = import(&state, ImportOptions { create_technologies: true, ..Default::default() }, body).await.unwrap();
+        assert_eq!((summary.inserted, summary.updated, summary.skipped), (2, 0, 0));
+        assert!(summary.errors.is_empty());
+
+        for record in &records {
+            let id = Uuid::parse_str(record["id"].as_str().unwrap()).unwrap();
+            let stored = fetch_project_with_relations(&state, id).await.unwrap();
+            assert_eq!(stored.project.name, record["name"]);
+            assert_eq!(stored.technologies.len(), record["technologies"].as_array().unwrap().len());
+            assert_eq!(stored.users.len(), record["users"].as_array().unwrap().len());
+        }
+        let created: String = sqlx::query_scalar("SELECT name FROM technologies WHERE id = ?")
+            .bind(unknown.id.to_string())
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(created, "Zig");
+
+        // NDJSON works too, and an export re-imports onto itself as updates
+        use futures::TryStreamExt;
+        let chunks: Vec<String> = export_stream(state.clone(), 10).try_collect().await.unwrap();
+        let exported: Vec<Value> = serde_json::from_str(&chunks.concat()).unwrap();
+        let ndjson = exported.iter().map(Value::to_string).collect::<Vec<_>>().join("\n");
+        let summary = import(&state, ImportOptions::default(), ndjson).await.unwrap();
+        assert_eq!((summary.inserted, summary.updated, summary.skipped), (0, 2, 0));
+    }
+
+    #[tokio::test]
+    async fn test_import_projects_unknown_user() {
+        let state = new_test_db().await;
+        let user_ids = insert_test_users(&state, &["Alice"]).await;
+        let missing = Uuid::new_v4();
+
+        let records = serde_json::to_string(&vec![
+            import_record_for("Valid", &[], &[(user_ids[0], UserRole::Owner)]),
+            import_record_for("Orphaned", &[], &[(missing, UserRole::Owner)]),
+            serde_json::json!({ "name": "No id" }),
+        ])
+        .unwrap();
+
+        // Without `partial` the whole import is rejected
+        let result = import(&state, ImportOptions::default(), records.clone()).await;
+        assert!(matches!(result, Err(AppError::UserNotFound(id)) if id.starts_with(&missing.to_string())));
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM projects").fetch_one(&state.db).await.unwrap();
+        assert_eq!(count, 0);
+
+        // With it, bad records are reported and the rest is imported
+        let summary = import(&state, ImportOptions { partial: true, ..Default::default() }, records).await.unwrap();
+        assert_eq!((summary.inserted, summary.updated, summary.skipped), (1, 0, 2));
+        assert_eq!(summary.errors[0].index, 1);
+        assert!(summary.errors[0].message.contains(&missing.to_string()));
+        assert_eq!(summary.errors[1].index, 2);
+        assert_eq!(summary.errors[1].id, None);
+        let names: Vec<String> = sqlx::query_scalar("SELECT name FROM projects").fetch_all(&state.db).await.unwrap();
+        assert_eq!(names, vec!["Valid"]);
+        let members: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM project_users").fetch_one(&state.db).await.unwrap();
+        assert_eq!(members, 1);
+    }
+
+    #[tokio::test]
+    async fn test_import_projects_updates_existing() {
+        let state = new_test_db().await;
+        let user_ids = insert_test_users(&state, &["Alice", "Bob"]).await;
+        let mut request = bulk_request("Original");
+        request.user_ids = Some(vec![user_ids[0]]);
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+
+        let mut record = import_record_for("Renamed", &[], &[(user_ids[1], UserRole::Owner)]);
+        record["id"] = serde_json::json!(created.project.id);
+        let summary = import(&state, ImportOptions::default(), serde_json::to_string(&vec![record]).unwrap()).await.unwrap();
+        assert_eq!((summary.inserted, summary.updated, summary.skipped), (0, 1, 0));
+
+        let stored = fetch_project_with_relations(&state, created.project.id).await.unwrap();
+        assert_eq!(stored.project.name, "Renamed");
+        assert_eq!(stored.project.rating, Some(3.5));
+        let members: Vec<Uuid> = stored.users.iter().map(|member| member.user.id).collect();
+        assert_eq!(members, vec![user_ids[1]]);
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM projects").fetch_one(&state.db).await.unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_import_revival_respects_project_quota() {
+        use crate::state::AppConfig;
+
+        let state = new_test_db().await.with_app_config(AppConfig {
+            max_projects: Some(1),
+            ..Default::default()
+        });
+        let user_ids = insert_test_users(&state, &["Alice"]).await;
+        let (_, _, Json(deleted)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request("Deleted")))
+            .await
+            .unwrap();
+        delete_project(State(state.clone()), None, ValidatedUuid(deleted.project.id)).await.unwrap();
+        let (_, _, Json(_)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request("Live")))
+            .await
+            .unwrap();
+
+        // The record has no deleted_at, so importing it would bring a second live project back
+        let mut record = import_record_for("Deleted", &[], &[(user_ids[0], UserRole::Owner)]);
+        record["id"] = serde_json::json!(deleted.project.id);
+        let result = import(&state, ImportOptions::default(), serde_json::to_string(&vec![record]).unwrap()).await;
+        assert!(matches!(result, Err(AppError::QuotaExceeded(_))));
+
+        let live: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM projects WHERE deleted_at IS NULL")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(live, 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_sparse_fields() {
+        let state = new_test_db().await;
+        let mut request = bulk_request("Sparse");
+        request.rating = Some(4.0);
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+
+        let params = ListQueryParams {
+            fields: Some("id,name,rating".to_string()),
+            ..Default::default()
+        };
+        let (_, body) = list_projects(State(state.clone()), None, Uri::from_static("/projects"), HeaderMap::new(), Query(params))
+            .await
+            .unwrap();
+        let ProjectListResponse::Fields(page) = body else {
+            panic!("expected a sparse JSON response");
+        };
+        assert_eq!(page.pagination.total_items, Some(1));
+        let project = &page.data[0];
+        let mut keys: Vec<&str> = project.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, vec!["id", "name", "rating"]);
+        assert_eq!(project["id"], created.project.id.to_string());
+        assert_eq!(project["name"], "Sparse");
+        assert_eq!(project["rating"], 4.0);
+
+        // CSV columns follow the same selection
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/csv"));
+        let params = ListQueryParams {
+            fields: Some("name,id".to_string()),
+            ..Default::default()
+        };
+        let (_, body) = list_projects(State(state.clone()), None, Uri::from_static("/projects"), headers, Query(params))
+            .await
+            .unwrap();
+        let ProjectListResponse::Csv(csv) = body else {
+            panic!("expected a CSV response");
+        };
+        assert_eq!(csv, format!("id,name\n{},Sparse\n", created.project.id));
+
+        let params = ListQueryParams {
+            fields: Some("id,secret".to_string()),
+            ..Default::default()
+        };
+        let result = list_projects(State(state), None, Uri::from_static("/projects"), HeaderMap::new(), Query(params)).await;
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_without_total() {
+        let state = new_test_db().await;
+        for name in ["One", "Two", "Three"] {
+            let (_, _, Json(_)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request(name)))
+                .await
+                .unwrap();
+        }
+
+        let page = |with_total: Option<bool>| {
+            let state = state.clone();
+            async move {
+                let params = ListQueryParams {
+                    page: Some(2),
+                    page_size: Some(2),
+                    sort: Some("name".to_string()),
+                    order: Some("asc".to_string()),
+                    with_total,
+                    ..Default::default()
+                };
+                let (_, body) = list_projects(State(state), None, Uri::from_static("/projects"), HeaderMap::new(), Query(params))
+                    .await
+                    .unwrap();
+                let ProjectListResponse::Json(page) = body else {
+                    panic!("expected a JSON response");
+                };
+                page
+            }
+        };
+
+        // Totals are counted by default
+        let counted = page(None).await;
+        assert_eq!(counted.pagination.total_items, Some(3));
+        assert_eq!(counted.pagination.total_pages, Some(2));
+
+        // Without the count the same page comes back, with null totals
+        let uncounted = page(Some(false)).await;
+        assert_eq!(uncounted.pagination.page, 2);
+        assert_eq!(uncounted.pagination.page_size, 2);
+        assert_eq!(uncounted.pagination.total_items, None);
+        assert_eq!(uncounted.pagination.total_pages, None);
+        let names: Vec<&str> = uncounted.data.iter().map(|project| project.name.as_str()).collect();
+        assert_eq!(names, vec!["Two"]);
+        assert_eq!(names, counted.data.iter().map(|project| project.name.as_str()).collect::<Vec<_>>());
+
+        let json = serde_json::to_value(&uncounted).unwrap();
+        assert!(json["pagination"]["total_items"].is_null());
+        assert!(json["pagination"]["total_pages"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_page_out_of_range() {
+        use crate::{models::pagination::PageOverflow, state::AppConfig};
+
+        let state = new_test_db().await;
+        for name in ["One", "Two", "Three"] {
+            let (_, _, Json(_)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request(name)))
+                .await
+                .unwrap();
+        }
+        let params = |page| ListQueryParams {
+            page: Some(page),
+            page_size: Some(1),
+            sort: Some("name".to_string()),
+            order: Some("asc".to_string()),
+            ..Default::default()
+        };
+
+        // By default a page past the last one serves the last page, even an extreme one
+        for page in [999, u32::MAX] {
+            let body = list_json(State(state.clone()), None, Query(params(page))).await.unwrap();
+            assert_eq!(body.pagination.page, 3);
+            assert!(!body.pagination.has_next);
+            assert_eq!(body.data[0].name, "Two");
+        }
+
+        // Configured to reject, the client is told which page is the last
+        let state = state.with_app_config(AppConfig {
+            page_overflow: PageOverflow::Reject,
+            ..Default::default()
+        });
+        let result = list_json(State(state.clone()), None, Query(params(999))).await;
+        assert!(matches!(result, Err(AppError::ValidationError(message)) if message.contains("last page is 3")));
+        let body = list_json(State(state), None, Query(params(3))).await.unwrap();
+        assert_eq!(body.data[0].name, "Two");
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_link_header() {
+        let state = new_test_db().await;
+        for name in ["One", "Two", "Three"] {
+            let (_, _, Json(_)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request(name)))
+                .await
+                .unwrap();
+        }
+
+        let uri = Uri::from_static("/projects?language=rust&page=2&page_size=1&sort=name");
+        let params = ListQueryParams {
+            language: Some("rust".to_string()),
+            page: Some(2),
+            page_size: Some(1),
+            sort: Some("name".to_string()),
+            ..Default::default()
+        };
+        let (headers, _) = list_projects(State(state), None, uri, HeaderMap::new(), Query(params))
+            .await
+            .unwrap();
+
+        let link = headers[header::LINK].to_str().unwrap();
+        let links: Vec<&str> = link.split(", ").collect();
+        assert_eq!(
+            links,
+            vec![
+                r#"</projects?language=rust&page_size=1&sort=name&page=1>; rel="first""#,
+                r#"</projects?language=rust&page_size=1&sort=name&page=1>; rel="prev""#,
+                r#"</projects?language=rust&page_size=1&sort=name&page=3>; rel="next""#,
+                r#"</projects?language=rust&page_size=1&sort=name&page=3>; rel="last""#,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_project_with_relations() {
+        let state = new_test_db().await;
+
+        // Create a technology
+        let tech = crate::models::Technology::new(CreateTechnologyRequest {
+            name: "Rust".to_string(),
+            description: Some("A systems language".to_string()),
+        });
+        sqlx::query(
+            "INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)"
+        )
+        .bind(tech.id.to_string())
+        .bind(&tech.name)
+        .bind(&tech.description)
+        .bind(tech.created_at)
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+        // Create a user
+        let user = crate::models::User::new(CreateUserRequest {
+            name: "John Doe".to_string(),
+            email: "john@example.com".to_string(),
+            avatar_url: None,
+            bio: None,
+        });
+        sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, ?)")
+            .bind(user.id.to_string())
+            .bind(&user.name)
+            .bind(&user.email)
+            .bind(user.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        // Create project with relations
+        let request = CreateProjectRequest {
+            name: "Test Project".to_string(),
+            description: "A test".to_string(),
+            repository_url: "https://github.com/test/repo".to_string(),
+            language: "Rust".to_string(),
+            rating: Some(4.5),
+            tags: None,
+            technology_ids: Some(vec![tech.id]),
+            user_ids: Some(vec![user.id]),
+            users: None,
+        };
+
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+
+        // Get project and verify relations
+        let retrieved = fetch_project_with_relations(&state, created.project.id)
+            .await
+            .unwrap();
+
+        assert_eq!(retrieved.technologies.len(), 1);
+        assert_eq!(retrieved.technologies[0].technology.name, "Rust");
+        assert_eq!(retrieved.users.len(), 1);
+        assert_eq!(retrieved.users[0].user.name, "John Doe");
+        assert_eq!(retrieved.users[0].role, UserRole::Owner);
+    }
+
+    #[tokio::test]
+    async fn test_get_project_returns_association_timestamps() {
+        let state = new_test_db().await;
+
+        // Entities created well before they are associated with the project
+        let created_at = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let tech_id = Uuid::new_v4();
+        sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, 'Rust', NULL, ?)")
+            .bind(tech_id.to_string())
+            .bind(created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        let user_id = Uuid::new_v4();
+        sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, 'Jane', 'jane@example.com', ?)")
+            .bind(user_id.to_string())
+            .bind(created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        let request = CreateProjectRequest {
+            name: "Timestamps".to_string(),
+            description: "Association timestamps".to_string(),
+            repository_url: "https://github.com/test/timestamps".to_string(),
+            language: "Rust".to_string(),
+            rating: None,
+            tags: None,
+            technology_ids: Some(vec![tech_id]),
+            user_ids: Some(vec![user_id]),
+            users: None,
+        };
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+
+        let project = fetch_project_with_relations(&state, created.project.id).await.unwrap();
+
+        let tech = &project.technologies[0];
+        assert_eq!(tech.technology.created_at, created_at);
+        assert!(tech.associated_at > tech.technology.created_at);
+
+        let member = &project.users[0];
+        assert_eq!(member.user.created_at, created_at);
+        assert!(member.associated_at > member.user.created_at);
+
+        let body = serde_json::to_value(&project).unwrap();
+        assert!(body["technologies"][0]["associated_at"].is_string());
+        assert!(body["users"][0]["associated_at"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_updated_at_trigger_bumps_on_raw_update() {
+        let state = new_test_db().await;
+
+        let request = CreateProjectRequest {
+            name: "Trigger Project".to_string(),
+            description: "Checks the updated_at trigger".to_string(),
+            repository_url: "https://github.com/test/trigger".to_string(),
+            language: "Rust".to_string(),
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            users: None,
+        };
+
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+        let id = created.project.id.to_string();
+
+        // Assigning updated_at on its own does not fire the trigger
+        let old = Utc::now() - chrono::Duration::days(1);
+        sqlx::query("UPDATE projects SET updated_at = ? WHERE id = ?")
+            .bind(old)
+            .bind(&id)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        let project = sqlx::query_as::<_, Project>("SELECT * FROM projects WHERE id = ?")
+            .bind(&id)
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(project.updated_at, old);
+
+        // Any other update lets the trigger advance the timestamp
+        sqlx::query("UPDATE projects SET name = 'Renamed' WHERE id = ?")
+            .bind(&id)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        let project = sqlx::query_as::<_, Project>("SELECT * FROM projects WHERE id = ?")
+            .bind(&id)
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(project.name, "Renamed");
+        assert!(project.updated_at > old);
+
+        // The trigger writes the text sqlx would encode for the same instant (AutoSi:
+        // 0, 3, 6 or 9 fractional digits), so a filter bound on exactly that instant
+        // compares equal to it
+        let stored: String = sqlx::query_scalar("SELECT updated_at FROM projects WHERE id = ?")
+            .bind(&id)
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(stored, project.updated_at.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false));
+        let params = ListQueryParams {
+            updated_after: Some(project.updated_at),
+            updated_before: Some(project.updated_at),
+            ..Default::default()
+        };
+        let page = list_json(State(state.clone()), None, Query(params)).await.unwrap();
+        assert_eq!(page.data.len(), 1);
+
+        // Columns outside the content ones, such as search_blob, leave updated_at alone
+        sqlx::query("UPDATE projects SET search_blob = 'renamed' WHERE id = ?")
+            .bind(&id)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        let unchanged: String = sqlx::query_scalar("SELECT updated_at FROM projects WHERE id = ?")
+            .bind(&id)
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(unchanged, stored);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_overlapping_association_updates() {
+        let state = new_test_db().await;
+
+        let mut tech_ids = Vec::new();
+        for i in 0..6 {
+            let tech = crate::models::Technology::new(CreateTechnologyRequest {
+                name: format!("Tech {}", i),
+                description: None,
+            });
+            sqlx::query(
+                "INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)"
+            )
+            .bind(tech.id.to_string())
+            .bind(&tech.name)
+            .bind(&tech.description)
+            .bind(tech.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+            tech_ids.push(tech.id);
+        }
+
+        let mut project_ids = Vec::new();
+        for i in 0..4 {
+            let request = CreateProjectRequest {
+                name: format!("Concurrent {}", i),
+                description: "Concurrency test".to_string(),
+                repository_url: "https://github.com/test/concurrent".to_string(),
+                language: "Rust".to_string(),
+                rating: None,
+                tags: None,
+                technology_ids: None,
+                user_ids: None,
+                users: None,
+            };
+            let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+            project_ids.push(created.project.id);
+        }
+
+        // Each update targets an overlapping technology set, half of them in reverse order
+        let mut handles = Vec::new();
+        for round in 0..3 {
+            for (i, project_id) in project_ids.iter().enumerate() {
+                let mut ids = tech_ids[i % 3..i % 3 + 4].to_vec();
+                if (i + round) % 2 == 0 {
+                    ids.reverse();
+                }
+                let update = UpdateProjectRequest {
+                    name: None,
+                    description: None,
+                    repository_url: None,
+                    language: None,
+                    rating: None,
+                    tags: None,
+                    technology_ids: Some(ids),
+                    user_ids: None,
+                    expected_updated_at: None,
+                };
+                let state = state.clone();
+                let project_id = *project_id;
+                handles.push(tokio::spawn(async move {
+                    update_project(State(state), None, ValidatedUuid(project_id), HeaderMap::new(), Json(update)).await
+                }));
+            }
+        }
+
+        let results = tokio::time::timeout(std::time::Duration::from_secs(10), async {
+            let mut results = Vec::with_capacity(handles.len());
+            for handle in handles {
+                results.push(handle.await.unwrap());
+            }
+            results
+        })
+        .await
+        .expect("concurrent updates timed out");
+
+        for result in results {
+            let Json(updated) = result.unwrap();
+            assert_eq!(updated.technologies.len(), 4);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_project_rolls_back_on_mid_transaction_failure() {
+        let state = new_test_db().await;
+        let tech = crate::models::Technology::new(CreateTechnologyRequest {
+            name: "Kept Tech".to_string(),
+            description: None,
+        });
+        sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+            .bind(tech.id.to_string())
+            .bind(&tech.name)
+            .bind(&tech.description)
+            .bind(tech.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        let user_ids = insert_test_users(&state, &["Member"]).await;
+
+        let mut request = bulk_request("Atomic");
+        request.technology_ids = Some(vec![tech.id]);
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+        let id = created.project.id;
+
+        // Make the last step of the update (member insertion) fail after the row
+        // update and technology replacement have already run
+        sqlx::query(
+            "CREATE TRIGGER test_reject_members BEFORE INSERT ON project_users
+             BEGIN SELECT RAISE(ABORT, 'members rejected'); END",
+        )
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+        let update = UpdateProjectRequest {
+            name: Some("Renamed".to_string()),
+            description: None,
+            repository_url: None,
+            language: None,
+            rating: None,
+            tags: None,
+            technology_ids: Some(Vec::new()),
+            user_ids: Some(user_ids),
+            expected_updated_at: None,
+        };
+        let result = update_project(State(state.clone()), None, ValidatedUuid(id), HeaderMap::new(), Json(update)).await;
+        assert!(result.is_err());
+
+        let project = fetch_project_with_relations(&state, id).await.unwrap();
+        assert_eq!(project.project.name, "Atomic");
+        assert_eq!(project.technologies.len(), 1);
+        assert_eq!(project.technologies[0].technology.id, tech.id);
+        assert!(project.users.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_project_rejects_stale_versions() {
+        let state = new_test_db().await;
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request("Versioned")))
+            .await
+            .unwrap();
+        let id = created.project.id;
+        let rename = |name: &str, expected_updated_at| UpdateProjectRequest {
+            name: Some(name.to_string()),
+            description: None,
+            repository_url: None,
+            language: None,
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            expected_updated_at,
+        };
+
+        // A fresh version applies and yields a new one
+        let seen = fetch_project_with_relations(&state, id).await.unwrap().project.updated_at;
+        let Json(updated) = update_project(State(state.clone()), None, ValidatedUuid(id), HeaderMap::new(), Json(rename("First", Some(seen))))
+            .await
+            .unwrap();
+        assert_eq!(updated.project.name, "First");
+        assert_ne!(updated.project.updated_at, seen);
+
+        // Replaying the old version is rejected and changes nothing
+        let result = update_project(State(state.clone()), None, ValidatedUuid(id), HeaderMap::new(), Json(rename("Second", Some(seen)))).await;
+        assert!(matches!(result, Err(AppError::PreconditionFailed(_))));
+        assert_eq!(fetch_project_with_relations(&state, id).await.unwrap().project.name, "First");
+
+        // If-Unmodified-Since older than the last change is rejected, a later one applies
+        let mut headers = HeaderMap::new();
+        let stale = (updated.project.updated_at - chrono::Duration::hours(1)).to_rfc2822();
+        headers.insert(header::IF_UNMODIFIED_SINCE, HeaderValue::from_str(&stale).unwrap());
+        let result = update_project(State(state.clone()), None, ValidatedUuid(id), headers, Json(rename("Third", None))).await;
+        assert!(matches!(result, Err(AppError::PreconditionFailed(_))));
+
+        let mut headers = HeaderMap::new();
+        let fresh = (updated.project.updated_at + chrono::Duration::seconds(1)).to_rfc2822();
+        headers.insert(header::IF_UNMODIFIED_SINCE, HeaderValue::from_str(&fresh).unwrap());
+        let Json(updated) = update_project(State(state.clone()), None, ValidatedUuid(id), headers, Json(rename("Fourth", None)))
+            .await
+            .unwrap();
+        assert_eq!(updated.project.name, "Fourth");
+    }
+
+    #[tokio::test]
+    async fn test_update_project_ratings() {
+        let state = new_test_db().await;
+
+        let mut ids = Vec::new();
+        for i in 0..2 {
+            let request = CreateProjectRequest {
+                name: format!("Rated {}", i),
+                description: "Bulk rating test".to_string(),
+                repository_url: "https://github.com/test/rated".to_string(),
+                language: "Rust".to_string(),
+                rating: Some(1.0),
+                tags: None,
+                technology_ids: None,
+                user_ids: None,
+                users: None,
+            };
+            let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+            ids.push(created.project);
+        }
+
+        // An unknown id is skipped and reported; the rest of the batch still applies
+        let missing_id = Uuid::new_v4();
+        let updates = vec![
+            RatingUpdate { id: ids[0].id, rating: 4.5 },
+            RatingUpdate { id: missing_id, rating: 2.0 },
+            RatingUpdate { id: ids[1].id, rating: 3.0 },
+        ];
+
+        let Json(result) = update_project_ratings(State(state.clone()), None, Json(updates))
+            .await
+            .unwrap();
+        assert_eq!(result.updated, vec![ids[0].id, ids[1].id]);
+        assert_eq!(result.missing, vec![missing_id]);
+
+        let first = fetch_project_with_relations(&state, ids[0].id).await.unwrap();
+        assert_eq!(first.project.rating, Some(4.5));
+        assert!(first.project.updated_at > ids[0].updated_at);
+        let second = fetch_project_with_relations(&state, ids[1].id).await.unwrap();
+        assert_eq!(second.project.rating, Some(3.0));
+    }
+
+    #[tokio::test]
+    async fn test_update_project_ratings_rejects_out_of_range() {
+        let state = new_test_db().await;
+
+        let request = CreateProjectRequest {
+            name: "Rated".to_string(),
+            description: "Bulk rating test".to_string(),
+            repository_url: "https://github.com/test/rated".to_string(),
+            language: "Rust".to_string(),
+            rating: Some(1.0),
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            users: None,
+        };
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+
+        let updates = vec![
+            RatingUpdate { id: created.project.id, rating: 4.0 },
+            RatingUpdate { id: created.project.id, rating: 7.5 },
+        ];
+
+        let result = update_project_ratings(State(state.clone()), None, Json(updates)).await;
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+
+        // Nothing from the batch was applied
+        let project = fetch_project_with_relations(&state, created.project.id).await.unwrap();
+        assert_eq!(project.project.rating, Some(1.0));
+    }
+
+    async fn insert_test_users(state: &AppState, names: &[&str]) -> Vec<Uuid> {
+        let mut ids = Vec::new();
+        for name in names {
+            let user = User::new(crate::models::CreateUserRequest {
+                name: name.to_string(),
+                email: format!("{}@example.com", name.to_lowercase()),
+                avatar_url: None,
+                bio: None,
+            });
+            sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, ?)")
+                .bind(user.id.to_string())
+                .bind(&user.name)
+                .bind(&user.email)
+                .bind(user.created_at)
+                .execute(&state.db)
+                .await
+                .unwrap();
+            ids.push(user.id);
+        }
+        ids
+    }
+
+    #[tokio::test]
+    async fn test_attach_and_detach_project_technology() {
+        let state = new_test_db().await;
+
+        let tech = Technology::new(CreateTechnologyRequest {
+            name: "Tokio".to_string(),
+            description: None,
+        });
+        sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+            .bind(tech.id.to_string())
+            .bind(&tech.name)
+            .bind(&tech.description)
+            .bind(tech.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        let (_, _, Json(created)) = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(bulk_request("Attach")),
+        )
+        .await
+        .unwrap();
+        let id = created.project.id;
+        let attach = || Json(AttachTechnologyRequest { technology_id: tech.id });
+
+        let (status, Json(attached)) = add_project_technology(State(state.clone()), None, Path(id), attach())
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(attached.technology.id, tech.id);
+
+        // Attaching twice is a conflict
+        assert!(matches!(
+            add_project_technology(State(state.clone()), None, Path(id), attach()).await,
+            Err(AppError::Conflict(_))
+        ));
+
+        // Unknown technology or project
+        assert!(matches!(
+            add_project_technology(
+                State(state.clone()),
+                None,
+                Path(id),
+                Json(AttachTechnologyRequest { technology_id: Uuid::new_v4() })
+            )
+            .await,
+            Err(AppError::TechnologyNotFound(_))
+        ));
+        assert!(matches!(
+            add_project_technology(State(state.clone()), None, Path(Uuid::new_v4()), attach()).await,
+            Err(AppError::ProjectNotFound(_))
+        ));
+
+        let status = remove_project_technology(State(state.clone()), None, Path((id, tech.id)))
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        // Detaching a technology that is no longer attached
+        assert!(matches!(
+            remove_project_technology(State(state.clone()), None, Path((id, tech.id))).await,
+            Err(AppError::TechnologyNotFound(_))
+        ));
+
+        let project = fetch_project_with_relations(&state, id).await.unwrap();
+        assert!(project.technologies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_project_etag_and_not_modified() {
+        let state = new_test_db().await;
+
+        let tech = Technology::new(CreateTechnologyRequest {
+            name: "Serde".to_string(),
+            description: None,
+        });
+        sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+            .bind(tech.id.to_string())
+            .bind(&tech.name)
+            .bind(&tech.description)
+            .bind(tech.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        let (_, _, Json(created)) = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(bulk_request("Cached")),
+        )
+        .await
+        .unwrap();
+        let id = created.project.id;
+
+        let response = get_project(State(state.clone()), ValidatedUuid(id), HeaderMap::new()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get(header::VARY).unwrap(), "accept");
+        let etag = response.headers().get(header::ETAG).unwrap().clone();
+
+        // The JSON:API representation of the same project has its own tag
+        let mut json_api = HeaderMap::new();
+        json_api.insert(header::ACCEPT, HeaderValue::from_static(JSON_API_MEDIA_TYPE));
+        let response = get_project(State(state.clone()), ValidatedUuid(id), json_api.clone()).await.unwrap();
+        let json_api_etag = response.headers().get(header::ETAG).unwrap().clone();
+        assert_ne!(json_api_etag, etag);
+        json_api.insert(header::IF_NONE_MATCH, etag.clone());
+        let response = get_project(State(state.clone()), ValidatedUuid(id), json_api).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Matching tag: 304 with an empty body
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, etag.clone());
+        let response = get_project(State(state.clone()), ValidatedUuid(id), headers.clone()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers().get(header::ETAG), Some(&etag));
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(body.is_empty());
+
+        // Attaching a technology changes the tag even though it lives in another table
+        let (_, Json(_)) = add_project_technology(
+            State(state.clone()),
+            None,
+            Path(id),
+            Json(AttachTechnologyRequest { technology_id: tech.id }),
+        )
+        .await
+        .unwrap();
+        let response = get_project(State(state), ValidatedUuid(id), headers).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_ne!(response.headers().get(header::ETAG), Some(&etag));
+    }
+
+    #[tokio::test]
+    async fn test_get_similar_projects() {
+        let state = new_test_db().await;
+
+        let mut tech_ids = Vec::new();
+        for name in ["Axum", "Rust", "SQLite", "Tokio"] {
+            let tech = Technology::new(CreateTechnologyRequest {
+                name: name.to_string(),
+                description: None,
+            });
+            sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+                .bind(tech.id.to_string())
+                .bind(&tech.name)
+                .bind(&tech.description)
+                .bind(tech.created_at)
+                .execute(&state.db)
+                .await
+                .unwrap();
+            tech_ids.push(tech.id);
+        }
+
+        let mut ids = Vec::new();
+        for (name, technologies) in [
+            ("Target", vec![tech_ids[0], tech_ids[1], tech_ids[2]]),
+            ("One Shared", vec![tech_ids[2], tech_ids[3]]),
+            ("Two Shared", vec![tech_ids[0], tech_ids[1]]),
+            ("None Shared", vec![tech_ids[3]]),
+            ("Bare", vec![]),
+        ] {
+            let mut request = bulk_request(name);
+            request.technology_ids = Some(technologies);
+            let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+            ids.push(created.project.id);
+        }
+
+        let Json(similar) = get_similar_projects(State(state.clone()), Path(ids[0]), Query(SimilarProjectsQuery::default()))
+            .await
+            .unwrap();
+        let names: Vec<&str> = similar.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Two Shared", "One Shared"]);
+
+        let Json(similar) = get_similar_projects(
+            State(state.clone()),
+            Path(ids[0]),
+            Query(SimilarProjectsQuery { limit: Some(1) }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(similar.len(), 1);
+        assert_eq!(similar[0].name, "Two Shared");
+
+        // No technologies, nothing in common
+        let Json(similar) = get_similar_projects(State(state.clone()), Path(ids[4]), Query(SimilarProjectsQuery::default()))
+            .await
+            .unwrap();
+        assert!(similar.is_empty());
+
+        assert!(matches!(
+            get_similar_projects(State(state), Path(Uuid::new_v4()), Query(SimilarProjectsQuery::default())).await,
+            Err(AppError::ProjectNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_project_stats() {
+        let state = new_test_db().await;
+
+        // Empty database: no rated projects means a null average
+        let Json(stats) = get_project_stats(State(state.clone())).await.unwrap();
+        assert_eq!(stats.total_projects, 0);
+        assert_eq!(stats.average_rating, None);
+        assert!(stats.languages.is_empty());
+        assert!(stats.technologies.is_empty());
+
+        let mut tech_ids = Vec::new();
+        for name in ["Axum", "Django", "Unused"] {
+            let tech = Technology::new(CreateTechnologyRequest {
+                name: name.to_string(),
+                description: None,
+            });
+            sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+                .bind(tech.id.to_string())
+                .bind(&tech.name)
+                .bind(&tech.description)
+                .bind(tech.created_at)
+                .execute(&state.db)
+                .await
+                .unwrap();
+            tech_ids.push(tech.id);
+        }
+
+        let mut ids = Vec::new();
+        for (name, language, rating, technologies) in [
+            ("One", "Rust", Some(4.0), vec![tech_ids[0]]),
+            ("Two", " rust", Some(2.0), vec![tech_ids[0]]),
+            ("Three", "Python", None, vec![tech_ids[1]]),
+            ("Deleted", "Go", Some(1.0), vec![tech_ids[1]]),
+        ] {
+            let mut request = bulk_request(name);
+            request.language = language.to_string();
+            request.rating = rating;
+            request.technology_ids = Some(technologies);
+            let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+                .await
+                .unwrap();
+            ids.push(created.project.id);
+        }
+        delete_project(State(state.clone()), None, ValidatedUuid(ids[3])).await.unwrap();
+
+        let Json(stats) = get_project_stats(State(state)).await.unwrap();
+        assert_eq!(stats.total_projects, 3);
+        assert_eq!(stats.average_rating, Some(3.0));
+
+        let languages: Vec<(&str, i64)> = stats.languages.iter().map(|l| (l.language.as_str(), l.count)).collect();
+        assert_eq!(languages, vec![("rust", 2), ("python", 1)]);
+
+        let technologies: Vec<(Uuid, &str, i64)> = stats
+            .technologies
+            .iter()
+            .map(|t| (t.technology_id, t.name.as_str(), t.count))
+            .collect();
+        assert_eq!(technologies, vec![(tech_ids[0], "Axum", 2), (tech_ids[1], "Django", 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_copy_project_technologies() {
+        let state = new_test_db().await;
+        let user_ids = insert_test_users(&state, &["Alice", "Bob"]).await;
+
+        let mut tech_ids = Vec::new();
+        for name in ["Axum", "Rust", "SQLite"] {
+            let tech = Technology::new(CreateTechnologyRequest {
+                name: name.to_string(),
+                description: None,
+            });
+            sqlx::query("INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)")
+                .bind(tech.id.to_string())
+                .bind(&tech.name)
+                .bind(&tech.description)
+                .bind(tech.created_at)
+                .execute(&state.db)
+                .await
+                .unwrap();
+            tech_ids.push(tech.id);
+        }
+
+        let request = |name: &str, technology_ids: Vec<Uuid>, user_ids: Vec<Uuid>| CreateProjectRequest {
+            name: name.to_string(),
+            description: "Copy test".to_string(),
+            repository_url: "https://github.com/test/copy".to_string(),
+            language: "Rust".to_string(),
+            rating: None,
+            tags: None,
+            technology_ids: Some(technology_ids),
+            user_ids: Some(user_ids),
+            users: None,
+        };
+        let (_, _, Json(source)) = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request("Source", tech_ids.clone(), vec![user_ids[0]])),
+        )
+        .await
+        .unwrap();
+        // Target already shares one technology with the source
+        let (_, _, Json(target)) = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request("Target", vec![tech_ids[1]], vec![user_ids[1]])),
+        )
+        .await
+        .unwrap();
+
+        for _ in 0..2 {
+            let Json(technologies) = copy_project_technologies(
+                State(state.clone()),
+                None,
+                Path((target.project.id, source.project.id)),
+            )
+            .await
+            .unwrap();
+            let names: Vec<&str> = technologies.iter().map(|t| t.technology.name.as_str()).collect();
+            assert_eq!(names, vec!["Axum", "Rust", "SQLite"]);
+        }
+
+        // Members are untouched on both sides
+        let target = fetch_project_with_relations(&state, target.project.id).await.unwrap();
+        assert_eq!(target.users.len(), 1);
+        assert_eq!(target.users[0].user.id, user_ids[1]);
+        let source = fetch_project_with_relations(&state, source.project.id).await.unwrap();
+        assert_eq!(source.technologies.len(), 3);
+        assert_eq!(source.users.len(), 1);
+
+        // Both projects must exist
+        let result = copy_project_technologies(State(state), None, Path((target.project.id, Uuid::new_v4()))).await;
+        assert!(matches!(result, Err(AppError::ProjectNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_replace_project_users() {
+        let state = new_test_db().await;
+        let ids = insert_test_users(&state, &["Alice", "Bob", "Carol"]).await;
+
+        let request = CreateProjectRequest {
+            name: "Members".to_string(),
+            description: "Membership test".to_string(),
+            repository_url: "https://github.com/test/members".to_string(),
+            language: "Rust".to_string(),
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: Some(vec![ids[0], ids[1]]),
+            users: None,
+        };
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+
+        // Replace Alice (owner) + Bob with Bob (owner) + Carol (viewer)
+        let members = vec![
+            ProjectMemberRequest { user_id: ids[1], role: UserRole::Owner },
+            ProjectMemberRequest { user_id: ids[2], role: UserRole::Viewer },
+        ];
+        let Json(users) = replace_project_users(State(state.clone()), None, Path(created.project.id), ValidatedJson(members))
+            .await
+            .unwrap();
+
+        let roles: Vec<(&str, UserRole)> = users.iter().map(|u| (u.user.name.as_str(), u.role)).collect();
+        assert_eq!(roles, vec![("Bob", UserRole::Owner), ("Carol", UserRole::Viewer)]);
+
+        let project = fetch_project_with_relations(&state, created.project.id).await.unwrap();
+        assert_eq!(project.users.len(), 2);
+        assert!(project.users.iter().all(|u| u.user.id != ids[0]));
+
+        // An empty list would leave the project without an owner
+        let result = replace_project_users(State(state), None, Path(created.project.id), ValidatedJson(vec![])).await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_project_with_explicit_roles() {
+        let state = new_test_db().await;
+        let ids = insert_test_users(&state, &["Alice", "Bob", "Carol"]).await;
+        let member = |user_id: Uuid, role: UserRole| ProjectMemberRequest { user_id, role };
+
+        // Explicit form: Bob owns the project although he is not listed first
+        let mut request = bulk_request("Explicit");
+        request.users = Some(vec![
+            member(ids[0], UserRole::Viewer),
+            member(ids[1], UserRole::Owner),
+            member(ids[2], UserRole::Contributor),
+        ]);
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+        let project = fetch_project_with_relations(&state, created.project.id).await.unwrap();
+        let roles: Vec<(&str, UserRole)> = project.users.iter().map(|u| (u.user.name.as_str(), u.role)).collect();
+        assert_eq!(
+            roles,
+            vec![("Alice", UserRole::Viewer), ("Bob", UserRole::Owner), ("Carol", UserRole::Contributor)]
+        );
+
+        // Legacy form: the first user owns the project
+        let mut request = bulk_request("Legacy");
+        request.user_ids = Some(vec![ids[2], ids[0]]);
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+        let project = fetch_project_with_relations(&state, created.project.id).await.unwrap();
+        let roles: Vec<(&str, UserRole)> = project.users.iter().map(|u| (u.user.name.as_str(), u.role)).collect();
+        assert_eq!(roles, vec![("Alice", UserRole::Contributor), ("Carol", UserRole::Owner)]);
+
+        // Zero or several owners, and mixing both forms, are rejected
+        for (users, user_ids) in [
+            (vec![member(ids[0], UserRole::Viewer), member(ids[1], UserRole::Contributor)], None),
+            (vec![member(ids[0], UserRole::Owner), member(ids[1], UserRole::Owner)], None),
+            (vec![member(ids[0], UserRole::Owner)], Some(vec![ids[1]])),
+        ] {
+            let mut request = bulk_request("Invalid");
+            request.users = Some(users);
+            request.user_ids = user_ids;
+            let result = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request)).await;
+            assert!(matches!(result, Err(AppError::ValidationError(_))));
+        }
+
+        // The alias `id` is accepted for the member's user id
+        let request: CreateProjectRequest = serde_json::from_value(serde_json::json!({
+            "name": "Aliased",
+            "description": "Alias test",
+            "repository_url": "https://github.com/test/alias",
+            "language": "Rust",
+            "users": [{ "id": ids[0], "role": "owner" }]
+        }))
+        .unwrap();
+        assert_eq!(request.users.unwrap()[0].user_id, ids[0]);
+    }
+
+    #[tokio::test]
+    async fn test_owner_role_required_for_update_and_delete() {
+        let state = new_test_db().await;
+        let ids = insert_test_users(&state, &["Owner", "Contributor", "Outsider"]).await;
+        let caller = |user_id: Uuid| {
+            Some(AuthUser {
+                user_id,
+                role: "user".to_string(),
+            })
+        };
+
+        let mut request = bulk_request("Guarded");
+        request.users = Some(vec![
+            ProjectMemberRequest { user_id: ids[0], role: UserRole::Owner },
+            ProjectMemberRequest { user_id: ids[1], role: UserRole::Contributor },
+        ]);
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+        let id = created.project.id;
+
+        // A contributor and a non-member are both forbidden
+        for user_id in [ids[1], ids[2]] {
+            let result = delete_project(State(state.clone()), caller(user_id), ValidatedUuid(id)).await;
+            assert!(matches!(result, Err(AppError::Forbidden(_))));
+
+            let update = UpdateProjectRequest {
+                name: Some("Hijacked".to_string()),
+                description: None,
+                repository_url: None,
+                language: None,
+                rating: None,
+                tags: None,
+                technology_ids: None,
+                user_ids: None,
+                expected_updated_at: None,
+            };
+            let result = update_project(State(state.clone()), caller(user_id), ValidatedUuid(id), HeaderMap::new(), Json(update)).await;
+            assert!(matches!(result, Err(AppError::Forbidden(_))));
+        }
+        let project = fetch_project_with_relations(&state, id).await.unwrap();
+        assert_eq!(project.project.name, "Guarded");
+
+        // The owner succeeds
+        let status = delete_project(State(state.clone()), caller(ids[0]), ValidatedUuid(id)).await.unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_owner_role_required_for_membership_ratings_and_restore() {
+        let state = new_test_db().await;
+        let ids = insert_test_users(&state, &["Owner", "Outsider"]).await;
+        let caller = |user_id: Uuid| {
+            Some(AuthUser {
+                user_id,
+                role: "user".to_string(),
+            })
+        };
+
+        let mut request = bulk_request("Guarded");
+        request.users = Some(vec![ProjectMemberRequest { user_id: ids[0], role: UserRole::Owner }]);
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+        let id = created.project.id;
+
+        let takeover = vec![ProjectMemberRequest { user_id: ids[1], role: UserRole::Owner }];
+        let result = replace_project_users(State(state.clone()), caller(ids[1]), Path(id), ValidatedJson(takeover)).await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+
+        let result = update_project_user_role(
+            State(state.clone()),
+            caller(ids[1]),
+            Path((id, ids[0])),
+            ValidatedJson(RoleUpdateRequest { role: UserRole::Viewer }),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+
+        let updates = vec![RatingUpdate { id, rating: 1.0 }];
+        let result = update_project_ratings(State(state.clone()), caller(ids[1]), Json(updates)).await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+
+        delete_project(State(state.clone()), caller(ids[0]), ValidatedUuid(id)).await.unwrap();
+        let result = restore_project(State(state.clone()), caller(ids[1]), Path(id)).await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+        let Json(restored) = restore_project(State(state.clone()), caller(ids[0]), Path(id)).await.unwrap();
+        assert!(restored.deleted_at.is_none());
+
+        // A missing project is a 404 for everyone, not a 403
+        let result = restore_project(State(state.clone()), caller(ids[1]), Path(Uuid::new_v4())).await;
+        assert!(matches!(result, Err(AppError::ProjectNotFound(_))));
+
+        let project = fetch_project_with_relations(&state, id).await.unwrap();
+        assert_eq!(project.users.len(), 1);
+        assert_eq!(project.users[0].user.id, ids[0]);
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_creator_owns_project_without_members() {
+        let state = new_test_db().await;
+        let ids = insert_test_users(&state, &["Creator", "Named"]).await;
+        let caller = || {
+            Some(AuthUser {
+                user_id: ids[0],
+                role: "user".to_string(),
+            })
+        };
+
+        let (_, _, Json(created)) = create_project(State(state.clone()), caller(), Query(CreateProjectOptions::default()), ValidatedJson(bulk_request("Mine")))
+            .await
+            .unwrap();
+        assert_eq!(created.users.len(), 1);
+        assert_eq!(created.users[0].user.id, ids[0]);
+        assert_eq!(created.users[0].role, UserRole::Owner);
+
+        // The creator can write the project afterwards
+        let update = UpdateProjectRequest {
+            name: Some("Still mine".to_string()),
+            description: None,
+            repository_url: None,
+            language: None,
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            expected_updated_at: None,
+        };
+        let Json(updated) = update_project(State(state.clone()), caller(), ValidatedUuid(created.project.id), HeaderMap::new(), Json(update))
+            .await
+            .unwrap();
+        assert_eq!(updated.project.name, "Still mine");
+
+        // Named members are kept as given, in bulk creates too
+        let mut named = bulk_request("Named");
+        named.user_ids = Some(vec![ids[1]]);
+        let (_, Json(bulk)) = create_projects_bulk(State(state.clone()), caller(), ValidatedJson(vec![bulk_request("Bulk"), named]))
+            .await
+            .unwrap();
+        assert_eq!(bulk[0].users[0].user.id, ids[0]);
+        assert_eq!(bulk[0].users[0].role, UserRole::Owner);
+        assert_eq!(bulk[1].users.len(), 1);
+        assert_eq!(bulk[1].users[0].user.id, ids[1]);
+
+        // Without authentication nobody is added
+        let (_, _, Json(anonymous)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(bulk_request("Open")))
+            .await
+            .unwrap();
+        assert!(anonymous.users.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_membership_changes_keep_an_owner() {
+        let state = new_test_db().await;
+        let ids = insert_test_users(&state, &["Owner", "Contributor"]).await;
+        let caller = Some(AuthUser {
+            user_id: ids[0],
+            role: "user".to_string(),
+        });
+
+        let mut request = bulk_request("Owned");
+        request.user_ids = Some(vec![ids[0], ids[1]]);
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+        let id = created.project.id;
+
+        let update = UpdateProjectRequest {
+            name: None,
+            description: None,
+            repository_url: None,
+            language: None,
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: Some(vec![]),
+            expected_updated_at: None,
+        };
+        let result = update_project(State(state.clone()), caller.clone(), ValidatedUuid(id), HeaderMap::new(), Json(update)).await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+
+        let result = replace_project_users(State(state.clone()), caller.clone(), Path(id), ValidatedJson(vec![])).await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+
+        let project = fetch_project_with_relations(&state, id).await.unwrap();
+        assert_eq!(project.users.len(), 2);
+
+        // Handing ownership to someone else is still allowed
+        let handover = vec![ProjectMemberRequest { user_id: ids[1], role: UserRole::Owner }];
+        let Json(members) = replace_project_users(State(state.clone()), caller, Path(id), ValidatedJson(handover)).await.unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].user.id, ids[1]);
+    }
+
+    #[tokio::test]
+    async fn test_update_project_user_role() {
+        let state = new_test_db().await;
+        let ids = insert_test_users(&state, &["Alice", "Bob", "Carol"]).await;
+
+        let mut request = bulk_request("Roles");
+        request.user_ids = Some(vec![ids[0], ids[1]]);
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+        let id = created.project.id;
+        let set_role = |user_id: Uuid, role: UserRole| {
+            update_project_user_role(State(state.clone()), None, Path((id, user_id)), ValidatedJson(RoleUpdateRequest { role }))
+        };
+
+        // Alice is the only owner and cannot be demoted
+        assert!(matches!(set_role(ids[0], UserRole::Viewer).await, Err(AppError::Conflict(_))));
+
+        // Promote Bob, after which Alice can step down
+        let Json(bob) = set_role(ids[1], UserRole::Owner).await.unwrap();
+        assert_eq!(bob.role, UserRole::Owner);
+        let Json(alice) = set_role(ids[0], UserRole::Viewer).await.unwrap();
+        assert_eq!(alice.role, UserRole::Viewer);
+
+        // Bob is now the last owner
+        assert!(matches!(set_role(ids[1], UserRole::Contributor).await, Err(AppError::Conflict(_))));
+
+        // Carol is not a member
+        assert!(matches!(set_role(ids[2], UserRole::Viewer).await, Err(AppError::UserNotFound(_))));
+
+        let project = fetch_project_with_relations(&state, id).await.unwrap();
+        let roles: Vec<(&str, UserRole)> = project.users.iter().map(|u| (u.user.name.as_str(), u.role)).collect();
+        assert_eq!(roles, vec![("Alice", UserRole::Viewer), ("Bob", UserRole::Owner)]);
+    }
+
+    #[tokio::test]
+    async fn test_replace_project_users_requires_single_owner() {
+        let state = new_test_db().await;
+        let ids = insert_test_users(&state, &["Alice", "Bob"]).await;
+
+        let request = CreateProjectRequest {
+            name: "Owned".to_string(),
+            description: "Owner invariant test".to_string(),
+            repository_url: "https://github.com/test/owned".to_string(),
+            language: "Rust".to_string(),
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: Some(vec![ids[0]]),
+            users: None,
+        };
+        let (_, _, Json(created)) = create_project(State(state.clone()), None, Query(CreateProjectOptions::default()), ValidatedJson(request))
+            .await
+            .unwrap();
+
+        for members in [
+            // No owner
+            vec![ProjectMemberRequest { user_id: ids[0], role: UserRole::Contributor }],
+            // Two owners
+            vec![
+                ProjectMemberRequest { user_id: ids[0], role: UserRole::Owner },
+                ProjectMemberRequest { user_id: ids[1], role: UserRole::Owner },
+            ],
+        ] {
+            let result = replace_project_users(State(state.clone()), None, Path(created.project.id), ValidatedJson(members)).await;
+            assert!(matches!(result, Err(AppError::ValidationError(_))));
+        }
+
+        // The original membership is untouched
+        let project = fetch_project_with_relations(&state, created.project.id).await.unwrap();
+        assert_eq!(project.users.len(), 1);
+        assert_eq!(project.users[0].role, UserRole::Owner);
     }
 }
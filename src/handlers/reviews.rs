@@ -0,0 +1,417 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use sqlx::SqliteConnection;
+use uuid::Uuid;
+
+use crate::{
+    error::{AppError, ErrorResponse, Result},
+    extractors::ValidatedJson,
+    models::{CreateReviewRequest, Review},
+    state::AppState,
+};
+
+/// Recomputes a project's effective `rating` from its reviews
+///
+/// The rating is the average review score, or the manually set `manual_rating`
+/// once no reviews are left, or else `default_rating`.
+pub(crate) async fn refresh_project_rating(
+    conn: &mut SqliteConnection,
+    project_id: &str,
+    default_rating: Option<f64>,
+) -> Result<()> {
+    sqlx::query(
+        "UPDATE projects
+         SET rating = COALESCE((SELECT AVG(score) FROM reviews WHERE project_id = ?), manual_rating, ?)
+         WHERE id = ?",
+    )
+    .bind(project_id)
+    .bind(default_rating)
+    .bind(project_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// Fails with `ProjectNotFound` unless a live project with `id` exists
+async fn ensure_live_project(conn: &mut SqliteConnection, id: Uuid) -> Result<()> {
+    let exists = sqlx::query("SELECT 1 FROM projects WHERE id = ? AND deleted_at IS NULL")
+        .bind(id.to_string())
+        .fetch_optional(&mut *conn)
+        .await?;
+    if exists.is_none() {
+        return Err(AppError::ProjectNotFound(id.to_string()));
+    }
+    Ok(())
+}
+
+/// List the reviews of a project
+///
+/// # Endpoint
+/// GET /projects/{id}/reviews
+///
+/// # Returns
+/// - `200 OK` - Reviews, newest first
+/// - `404 Not Found` - Project not found
+#[utoipa::path(
+    get,
+    path = "/projects/{id}/reviews",
+    tag = "projects",
+    params(
+        ("id" = Uuid, Path, description = "Project UUID")
+    ),
+    responses(
+        (status = 200, description = "Reviews of the project", body = [Review]),
+        (status = 404, description = "Project not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn list_project_reviews(State(state): State<AppState>, Path(id): Path<Uuid>) -> Result<Json<Vec<Review>>> {
+    let mut conn = state.db.acquire().await?;
+    ensure_live_project(&mut conn, id).await?;
+
+    let reviews = sqlx::query_as::<_, Review>(
+        "SELECT id, project_id, user_id, score, comment, created_at FROM reviews
+         WHERE project_id = ? ORDER BY created_at DESC, id ASC",
+    )
+    .bind(id.to_string())
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok(Json(reviews))
+}
+
+/// Review a project
+///
+/// # Endpoint
+/// POST /projects/{id}/reviews
+///
+/// # Request Body
+/// ```json
+/// {
+///   "user_id": "uuid",
+///   "score": 4,
+///   "comment": "Solid and well documented"
+/// }
+/// ```
+///
+/// The project's `rating` becomes the average score of its reviews; the manually
+/// set rating only applies while a project has none. Each user may review a
+/// project once.
+///
+/// # Returns
+/// - `201 Created` - The created review
+/// - `400 Bad Request` - Validation error
+/// - `404 Not Found` - Project or user not found
+/// - `409 Conflict` - The user already reviewed this project
+#[utoipa::path(
+    post,
+    path = "/projects/{id}/reviews",
+    tag = "projects",
+    params(
+        ("id" = Uuid, Path, description = "Project UUID")
+    ),
+    request_body = CreateReviewRequest,
+    responses(
+        (status = 201, description = "Review created", body = Review),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Project or user not found", body = ErrorResponse),
+        (status = 409, description = "User already reviewed the project", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state, request))]
+pub async fn create_project_review(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    ValidatedJson(request): ValidatedJson<CreateReviewRequest>,
+) -> Result<(StatusCode, Json<Review>)> {
+    let mut tx = state.db.begin().await?;
+    ensure_live_project(&mut tx, id).await?;
+
+    let user_exists = sqlx::query("SELECT 1 FROM users WHERE id = ?")
+        .bind(request.user_id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+    if user_exists.is_none() {
+        return Err(AppError::UserNotFound(request.user_id.to_string()));
+    }
+
+    let reviewed = sqlx::query("SELECT 1 FROM reviews WHERE project_id = ? AND user_id = ?")
+        .bind(id.to_string())
+        .bind(request.user_id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+    if reviewed.is_some() {
+        return Err(AppError::DuplicateResource(format!(
+            "User {} already reviewed project {}",
+            request.user_id, id
+        )));
+    }
+
+    let review = Review::new(id, request);
+    // The UNIQUE (project_id, user_id) constraint still catches concurrent duplicates
+    sqlx::query(
+        "INSERT INTO reviews (id, project_id, user_id, score, comment, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(review.id.to_string())
+    .bind(review.project_id.to_string())
+    .bind(review.user_id.to_string())
+    .bind(review.score)
+    .bind(&review.comment)
+    .bind(review.created_at)
+    .execute(&mut *tx)
+    .await?;
+
+    refresh_project_rating(&mut tx, &id.to_string(), state.config.default_rating).await?;
+    tx.commit().await?;
+
+    tracing::info!("User {} reviewed project {} with {}", review.user_id, id, review.score);
+    Ok((StatusCode::CREATED, Json(review)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        extractors::ValidatedUuid,
+        handlers::{
+            projects::{create_project, update_project},
+            users::delete_user,
+        },
+        models::{CreateProjectOptions, CreateProjectRequest, UpdateProjectRequest},
+        state::{AppConfig, tests::new_test_db},
+    };
+    use axum::{extract::Query, http::HeaderMap};
+
+    async fn insert_users(state: &AppState, count: usize) -> Vec<Uuid> {
+        let mut ids = Vec::new();
+        for i in 0..count {
+            let id = Uuid::new_v4();
+            sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, CURRENT_TIMESTAMP)")
+                .bind(id.to_string())
+                .bind(format!("Reviewer {}", i))
+                .bind(format!("reviewer{}@example.com", i))
+                .execute(&state.db)
+                .await
+                .unwrap();
+            ids.push(id);
+        }
+        ids
+    }
+
+    async fn create_rated_project(state: &AppState, rating: Option<f64>) -> Uuid {
+        let request = CreateProjectRequest {
+            name: "Reviewed".to_string(),
+            description: "A project with reviews".to_string(),
+            repository_url: "https://github.com/test/reviewed".to_string(),
+            language: "Rust".to_string(),
+            rating,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            users: None,
+        };
+        let (_, _, Json(created)) = create_project(
+            State(state.clone()),
+            None,
+            Query(CreateProjectOptions::default()),
+            ValidatedJson(request),
+        )
+        .await
+        .unwrap();
+        created.project.id
+    }
+
+    async fn review(state: &AppState, project_id: Uuid, user_id: Uuid, score: u8) -> Result<Review> {
+        let request = CreateReviewRequest {
+            user_id,
+            score,
+            comment: Some(format!("Worth {}", score)),
+        };
+        let (status, Json(review)) =
+            create_project_review(State(state.clone()), Path(project_id), ValidatedJson(request)).await?;
+        assert_eq!(status, StatusCode::CREATED);
+        Ok(review)
+    }
+
+    async fn stored_rating(state: &AppState, project_id: Uuid) -> Option<f64> {
+        sqlx::query_scalar("SELECT rating FROM projects WHERE id = ?")
+            .bind(project_id.to_string())
+            .fetch_one(&state.db)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_and_list_reviews() {
+        let state = new_test_db().await;
+        let users = insert_users(&state, 2).await;
+        let project_id = create_rated_project(&state, None).await;
+
+        let first = review(&state, project_id, users[0], 4).await.unwrap();
+        assert_eq!(first.project_id, project_id);
+        assert_eq!(first.comment.as_deref(), Some("Worth 4"));
+        review(&state, project_id, users[1], 5).await.unwrap();
+
+        let Json(reviews) = list_project_reviews(State(state.clone()), Path(project_id)).await.unwrap();
+        assert_eq!(reviews.len(), 2);
+        assert!(reviews.iter().any(|r| r.id == first.id && r.score == 4));
+
+        // Unknown projects and users are 404s
+        let missing = Uuid::new_v4();
+        assert!(matches!(
+            list_project_reviews(State(state.clone()), Path(missing)).await,
+            Err(AppError::ProjectNotFound(_))
+        ));
+        assert!(matches!(
+            review(&state, missing, users[0], 3).await,
+            Err(AppError::ProjectNotFound(_))
+        ));
+        assert!(matches!(
+            review(&state, project_id, missing, 3).await,
+            Err(AppError::UserNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_one_review_per_user_and_project() {
+        let state = new_test_db().await;
+        let users = insert_users(&state, 1).await;
+        let project_id = create_rated_project(&state, None).await;
+
+        review(&state, project_id, users[0], 2).await.unwrap();
+        let result = review(&state, project_id, users[0], 5).await;
+        assert!(matches!(result, Err(AppError::DuplicateResource(_))));
+
+        // The database refuses a second row as well
+        let result = sqlx::query(
+            "INSERT INTO reviews (id, project_id, user_id, score, created_at) VALUES (?, ?, ?, 5, CURRENT_TIMESTAMP)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(project_id.to_string())
+        .bind(users[0].to_string())
+        .execute(&state.db)
+        .await;
+        assert!(matches!(result.map_err(AppError::from), Err(AppError::DuplicateResource(_))));
+        assert_eq!(stored_rating(&state, project_id).await, Some(2.0));
+    }
+
+    #[tokio::test]
+    async fn test_rating_is_average_of_reviews_with_manual_fallback() {
+        let state = new_test_db().await;
+        let users = insert_users(&state, 3).await;
+        let project_id = create_rated_project(&state, Some(1.5)).await;
+        assert_eq!(stored_rating(&state, project_id).await, Some(1.5));
+
+        review(&state, project_id, users[0], 5).await.unwrap();
+        assert_eq!(stored_rating(&state, project_id).await, Some(5.0));
+        review(&state, project_id, users[1], 4).await.unwrap();
+        review(&state, project_id, users[2], 3).await.unwrap();
+        assert_eq!(stored_rating(&state, project_id).await, Some(4.0));
+
+        // A manual rating is kept as the fallback but does not override reviews
+        let update = UpdateProjectRequest {
+            name: None,
+            description: None,
+            repository_url: None,
+            language: None,
+            rating: Some(Some(2.0)),
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            expected_updated_at: None,
+        };
+        let Json(updated) = update_project(State(state.clone()), None, ValidatedUuid(project_id), HeaderMap::new(), Json(update))
+            .await
+            .unwrap();
+        assert_eq!(updated.project.rating, Some(4.0));
+
+        // Deleting a reviewer drops their review; with none left the manual rating applies again
+        delete_user(State(state.clone()), Path(users[0])).await.unwrap();
+        assert_eq!(stored_rating(&state, project_id).await, Some(3.5));
+        for user_id in &users[1..] {
+            delete_user(State(state.clone()), Path(*user_id)).await.unwrap();
+        }
+        assert_eq!(stored_rating(&state, project_id).await, Some(2.0));
+    }
+
+    fn update_request(name: Option<&str>, rating: Option<Option<f64>>) -> UpdateProjectRequest {
+        UpdateProjectRequest {
+            name: name.map(str::to_string),
+            description: None,
+            repository_url: None,
+            language: None,
+            rating,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            expected_updated_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_without_rating_keeps_manual_rating() {
+        let state = new_test_db().await;
+        let users = insert_users(&state, 1).await;
+        let project_id = create_rated_project(&state, Some(2.5)).await;
+        review(&state, project_id, users[0], 5).await.unwrap();
+
+        // Renaming a reviewed project must not store the review average as the manual rating
+        let Json(updated) = update_project(
+            State(state.clone()),
+            None,
+            ValidatedUuid(project_id),
+            HeaderMap::new(),
+            Json(update_request(Some("Renamed"), None)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.project.rating, Some(5.0));
+
+        delete_user(State(state.clone()), Path(users[0])).await.unwrap();
+        assert_eq!(stored_rating(&state, project_id).await, Some(2.5));
+    }
+
+    #[tokio::test]
+    async fn test_default_rating_applies_without_reviews_or_manual_rating() {
+        let state = new_test_db().await.with_app_config(AppConfig {
+            default_rating: Some(3.0),
+            ..Default::default()
+        });
+        let users = insert_users(&state, 1).await;
+        let project_id = create_rated_project(&state, None).await;
+        assert_eq!(stored_rating(&state, project_id).await, Some(3.0));
+
+        review(&state, project_id, users[0], 5).await.unwrap();
+        assert_eq!(stored_rating(&state, project_id).await, Some(5.0));
+        delete_user(State(state.clone()), Path(users[0])).await.unwrap();
+        assert_eq!(stored_rating(&state, project_id).await, Some(3.0));
+
+        // A manual rating wins over the default; clearing it brings the default back
+        for (rating, expected) in [(Some(1.0), 1.0), (None, 3.0)] {
+            let Json(updated) = update_project(
+                State(state.clone()),
+                None,
+                ValidatedUuid(project_id),
+                HeaderMap::new(),
+                Json(update_request(None, Some(rating))),
+            )
+            .await
+            .unwrap();
+            assert_eq!(updated.project.rating, Some(expected));
+        }
+
+        // Changing the default re-rates projects that rely on it
+        let state = state.with_app_config(AppConfig {
+            default_rating: Some(4.0),
+            ..Default::default()
+        });
+        assert_eq!(state.apply_default_rating().await.unwrap(), 1);
+        assert_eq!(stored_rating(&state, project_id).await, Some(4.0));
+        assert_eq!(state.apply_default_rating().await.unwrap(), 0);
+    }
+}
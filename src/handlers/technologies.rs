@@ -1,42 +1,369 @@
 use axum::{
-    Json,
-    extract::State,
-    http::StatusCode,
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
 };
+use chrono::Utc;
+use sqlx::{Row, SqliteConnection};
+use std::collections::HashSet;
+use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
     error::{AppError, ErrorResponse, Result},
-    models::{CreateTechnologyRequest, Technology},
-    state::AppState,
+    extractors::ValidatedJson,
+    handlers::created_location,
+    models::{
+        CreateTechnologyRequest, ListQueryParams, MergeTechnologiesRequest, PaginatedResponse, Project,
+        Technology, TechnologyMergeSummary, UpdateTechnologyRequest,
+        pagination::{LIKE_ESCAPE, ListQuery, PROJECT_SEARCH_BLOB, contains_pattern, search_key},
+        technology::MAX_RESOLVE_TECHNOLOGIES,
+    },
+    state::{AppState, CallerRole},
 };
 
-/// List all technologies
+/// List technologies with pagination
 ///
 /// # Endpoint
-/// GET /technologies
+/// GET /technologies?search=ru&page=1&page_size=10
+///
+/// # Query Parameters
+/// - `search` - Filter by technology name (case-insensitive substring)
+/// - `page` - Page number (default: 1)
+/// - `page_size` - Items per page (default: 10 or the caller role's configured default; default max 100, configurable via `MAX_PAGE_SIZE`)
 ///
 /// # Returns
-/// - `200 OK` - List of all technologies
+/// - `200 OK` - Paginated list of technologies ordered by name
 #[utoipa::path(
     get,
     path = "/technologies",
     tag = "technologies",
+    params(
+        ("search" = Option<String>, Query, description = "Filter by technology name"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("page_size" = Option<u32>, Query, description = "Items per page (default max 100, configurable via MAX_PAGE_SIZE)"),
+    ),
     responses(
-        (status = 200, description = "List of all technologies", body = [Technology]),
+        (status = 200, description = "Paginated list of technologies", body = PaginatedResponse<Technology>),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
 #[tracing::instrument(skip(state))]
-pub async fn list_technologies(State(state): State<AppState>) -> Result<Json<Vec<Technology>>> {
-    let technologies = sqlx::query_as::<_, Technology>(
-        "SELECT * FROM technologies ORDER BY name ASC"
+pub async fn list_technologies(
+    State(state): State<AppState>,
+    caller_role: Option<Extension<CallerRole>>,
+    Query(mut params): Query<ListQueryParams>,
+) -> Result<Json<PaginatedResponse<Technology>>> {
+    params
+        .page_size
+        .get_or_insert(state.config.default_page_size(caller_role.as_deref()));
+
+    let search_pattern = params
+        .search
+        .as_ref()
+        .map(|s| contains_pattern(&Technology::name_key(s)));
+
+    let mut list_query = ListQuery::new("technologies t");
+    if let Some(ref pattern) = search_pattern {
+        list_query.filter(move |b| {
+            b.push("t.name_key LIKE ");
+            b.push_bind(pattern.clone());
+            b.push(LIKE_ESCAPE);
+        });
+    }
+
+    let total_items: i64 = list_query
+        .count_query()
+        .build()
+        .fetch_one(&state.db)
+        .await?
+        .try_get("count")?;
+    params.resolve_page(total_items, state.config.max_page_size, state.config.page_overflow)?;
+
+    let page_size = params.page_size(state.config.max_page_size);
+
+    let technologies = list_query
+        .page_query("t.*", "t.name ASC", page_size, params.offset(state.config.max_page_size))
+        .build_query_as::<Technology>()
+        .fetch_all(&state.db)
+        .await?;
+
+    tracing::info!(
+        "Listed {} technologies (page {}, total {})",
+        technologies.len(),
+        params.page(),
+        total_items
+    );
+
+    Ok(Json(PaginatedResponse::new(
+        technologies,
+        params.page(),
+        page_size,
+        total_items,
+    )))
+}
+
+/// Get a specific technology by ID
+///
+/// # Endpoint
+/// GET /technologies/{id}
+///
+/// # Arguments
+/// - `id` - UUID of the technology
+///
+/// # Returns
+/// - `200 OK` - Technology details
+/// - `404 Not Found` - Technology not found
+#[utoipa::path(
+    get,
+    path = "/technologies/{id}",
+    tag = "technologies",
+    params(
+        ("id" = Uuid, Path, description = "Technology UUID")
+    ),
+    responses(
+        (status = 200, description = "Technology found", body = Technology),
+        (status = 404, description = "Technology not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
     )
-    .fetch_all(&state.db)
-    .await?;
+)]
+#[tracing::instrument(skip(state))]
+pub async fn get_technology(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Technology>> {
+    let technology = sqlx::query_as::<_, Technology>("SELECT * FROM technologies WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or_else(|| AppError::TechnologyNotFound(id.to_string()))?;
 
-    tracing::info!("Listed {} technologies", technologies.len());
-    Ok(Json(technologies))
+    Ok(Json(technology))
+}
+
+/// List the projects using a technology
+///
+/// # Endpoint
+/// GET /technologies/{id}/projects?sort=rating&order=desc&page=1&page_size=10
+///
+/// # Query Parameters
+/// - `search` - Search text in project name and description
+/// - `language` - Filter by language (case-insensitive)
+/// - `min_rating` / `max_rating` - Rating range
+/// - `sort` / `order` - Same fields as `GET /projects` (default `created_at` DESC)
+/// - `page` / `page_size` - Pagination
+///
+/// Soft-deleted projects are left out; a technology no project uses yields an empty page.
+///
+/// # Returns
+/// - `200 OK` - Paginated list of projects
+/// - `400 Bad Request` - Unknown `sort` or `order` value
+/// - `404 Not Found` - Technology not found
+#[utoipa::path(
+    get,
+    path = "/technologies/{id}/projects",
+    tag = "technologies",
+    params(
+        ("id" = Uuid, Path, description = "Technology UUID"),
+        ("search" = Option<String>, Query, description = "Search text in project name and description"),
+        ("language" = Option<String>, Query, description = "Filter by language (case-insensitive)"),
+        ("min_rating" = Option<f64>, Query, description = "Minimum rating"),
+        ("max_rating" = Option<f64>, Query, description = "Maximum rating"),
+        ("sort" = Option<String>, Query, description = "Comma-separated sort fields (name, created_at, updated_at, rating)"),
+        ("order" = Option<String>, Query, description = "Comma-separated sort orders matching `sort` (asc, desc)"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("page_size" = Option<u32>, Query, description = "Items per page (default max 100, configurable via MAX_PAGE_SIZE)"),
+    ),
+    responses(
+        (status = 200, description = "Paginated list of projects using the technology", body = PaginatedResponse<Project>),
+        (status = 400, description = "Unknown sort field or order", body = ErrorResponse),
+        (status = 404, description = "Technology not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn get_technology_projects(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(mut params): Query<ListQueryParams>,
+) -> Result<Json<PaginatedResponse<Project>>> {
+    params.validate_sort()?;
+
+    let exists = sqlx::query("SELECT 1 FROM technologies WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_optional(&state.db)
+        .await?;
+    if exists.is_none() {
+        return Err(AppError::TechnologyNotFound(id.to_string()));
+    }
+
+    let search_pattern = params.search.as_deref().map(|s| contains_pattern(&search_key(s)));
+    let language = params.language();
+
+    let mut list_query = ListQuery::new("project_technologies pt JOIN projects p ON p.id = pt.project_id");
+    list_query
+        .filter(move |b| {
+            b.push("pt.technology_id = ");
+            b.push_bind(id.to_string());
+        })
+        .filter(|b| {
+            b.push("p.deleted_at IS NULL");
+        });
+    if let Some(ref pattern) = search_pattern {
+        list_query.filter(move |b| {
+            b.push(format!("{} LIKE ", PROJECT_SEARCH_BLOB));
+            b.push_bind(pattern.clone());
+            b.push(LIKE_ESCAPE);
+        });
+    }
+    if let Some(ref language) = language {
+        list_query.filter(move |b| {
+            b.push("lower(trim(p.language)) = ");
+            b.push_bind(language.clone());
+        });
+    }
+    if let Some(min_rating) = params.min_rating {
+        list_query.filter(move |b| {
+            b.push("p.rating >= ");
+            b.push_bind(min_rating);
+        });
+    }
+    if let Some(max_rating) = params.max_rating {
+        list_query.filter(move |b| {
+            b.push("p.rating <= ");
+            b.push_bind(max_rating);
+        });
+    }
+
+    let total_items: i64 = list_query
+        .count_query()
+        .build()
+        .fetch_one(&state.db)
+        .await?
+        .try_get("count")?;
+    params.resolve_page(total_items, state.config.max_page_size, state.config.page_overflow)?;
+
+    // Without a search index here, `relevance` has nothing to rank by
+    let mut order_by = params
+        .sort_clauses()
+        .into_iter()
+        .filter(|(field, _)| *field != "relevance")
+        .map(|(field, order)| format!("p.{} {}", field, order))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if order_by.is_empty() {
+        order_by = "p.created_at DESC".to_string();
+    }
+    let page_size = params.page_size(state.config.max_page_size);
+
+    let projects = list_query
+        .page_query("p.*", &order_by, page_size, params.offset(state.config.max_page_size))
+        .build_query_as::<Project>()
+        .fetch_all(&state.db)
+        .await?;
+
+    tracing::info!(
+        "Listed {} projects using technology {} (page {}, total {})",
+        projects.len(),
+        id,
+        params.page(),
+        total_items
+    );
+
+    Ok(Json(PaginatedResponse::new(
+        projects,
+        params.page(),
+        page_size,
+        total_items,
+    )))
+}
+
+/// List technologies that are not used by any project
+///
+/// # Endpoint
+/// GET /technologies/unused?search=script&page=1&page_size=10
+///
+/// # Query Parameters
+/// - `search` - Filter by technology name
+/// - `page` - Page number (default: 1)
+/// - `page_size` - Items per page (default: 10 or the caller role's configured default; default max 100, configurable via `MAX_PAGE_SIZE`)
+///
+/// # Returns
+/// - `200 OK` - Paginated list of technologies without any `project_technologies` rows
+#[utoipa::path(
+    get,
+    path = "/technologies/unused",
+    tag = "technologies",
+    params(
+        ("search" = Option<String>, Query, description = "Filter by technology name"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("page_size" = Option<u32>, Query, description = "Items per page (default max 100, configurable via MAX_PAGE_SIZE)"),
+    ),
+    responses(
+        (status = 200, description = "Paginated list of unused technologies", body = PaginatedResponse<Technology>),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn list_unused_technologies(
+    State(state): State<AppState>,
+    caller_role: Option<Extension<CallerRole>>,
+    Query(mut params): Query<ListQueryParams>,
+) -> Result<Json<PaginatedResponse<Technology>>> {
+    params
+        .page_size
+        .get_or_insert(state.config.default_page_size(caller_role.as_deref()));
+
+    let search_pattern = params
+        .search
+        .as_ref()
+        .map(|s| contains_pattern(&Technology::name_key(s)));
+
+    let mut list_query = ListQuery::new("technologies t");
+    list_query.filter(|b| {
+        b.push(
+            "NOT EXISTS (
+                SELECT 1 FROM project_technologies pt WHERE pt.technology_id = t.id
+            )",
+        );
+    });
+    if let Some(ref pattern) = search_pattern {
+        list_query.filter(move |b| {
+            b.push("t.name_key LIKE ");
+            b.push_bind(pattern.clone());
+            b.push(LIKE_ESCAPE);
+        });
+    }
+
+    let total_items: i64 = list_query
+        .count_query()
+        .build()
+        .fetch_one(&state.db)
+        .await?
+        .try_get("count")?;
+    params.resolve_page(total_items, state.config.max_page_size, state.config.page_overflow)?;
+
+    let page_size = params.page_size(state.config.max_page_size);
+
+    let technologies = list_query
+        .page_query("t.*", "t.name ASC", page_size, params.offset(state.config.max_page_size))
+        .build_query_as::<Technology>()
+        .fetch_all(&state.db)
+        .await?;
+
+    tracing::info!(
+        "Listed {} unused technologies (page {}, total {})",
+        technologies.len(),
+        params.page(),
+        total_items
+    );
+
+    Ok(Json(PaginatedResponse::new(
+        technologies,
+        params.page(),
+        page_size,
+        total_items,
+    )))
 }
 
 /// Create a new technology
@@ -62,7 +389,8 @@ pub async fn list_technologies(State(state): State<AppState>) -> Result<Json<Vec
     tag = "technologies",
     request_body = CreateTechnologyRequest,
     responses(
-        (status = 201, description = "Technology created successfully", body = Technology),
+        (status = 201, description = "Technology created successfully", body = Technology,
+            headers(("Location" = String, description = "URI of the created technology"))),
         (status = 400, description = "Validation error", body = ErrorResponse),
         (status = 409, description = "Technology already exists", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
@@ -71,16 +399,13 @@ pub async fn list_technologies(State(state): State<AppState>) -> Result<Json<Vec
 #[tracing::instrument(skip(state))]
 pub async fn create_technology(
     State(state): State<AppState>,
-    Json(request): Json<CreateTechnologyRequest>,
-) -> Result<(StatusCode, Json<Technology>)> {
-    // Validate request
-    request.validate()?;
-
-    // Check if technology with this name already exists
+    ValidatedJson(request): ValidatedJson<CreateTechnologyRequest>,
+) -> Result<(StatusCode, HeaderMap, Json<Technology>)> {
+    // Check if a technology with this name (in any casing) already exists
     let existing = sqlx::query_as::<_, Technology>(
-        "SELECT * FROM technologies WHERE name = ?"
+        "SELECT * FROM technologies WHERE name_key = ?"
     )
-    .bind(&request.name)
+    .bind(Technology::name_key(&request.name))
     .fetch_optional(&state.db)
     .await?;
 
@@ -96,17 +421,454 @@ pub async fn create_technology(
 
     // Insert into database
     sqlx::query(
-        "INSERT INTO technologies (id, name, description, created_at) VALUES (?, ?, ?, ?)"
+        "INSERT INTO technologies (id, name, name_key, description, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)"
     )
     .bind(technology.id.to_string())
     .bind(&technology.name)
+    .bind(Technology::name_key(&technology.name))
     .bind(&technology.description)
     .bind(technology.created_at)
+    .bind(technology.updated_at)
     .execute(&state.db)
     .await?;
 
     tracing::info!("Created technology: {}", technology.id);
-    Ok((StatusCode::CREATED, Json(technology)))
+    let location = created_location(&format!("/technologies/{}", technology.id))?;
+    Ok((StatusCode::CREATED, location, Json(technology)))
+}
+
+/// Resolve technology names to technologies, creating the missing ones
+///
+/// # Endpoint
+/// POST /technologies/resolve
+///
+/// # Request Body
+/// ```json
+/// ["Rust", "react", "PostgreSQL"]
+/// ```
+///
+/// Names match existing technologies case-insensitively; the rest are created,
+/// all in one transaction. The result has one technology per distinct name, in
+/// the order the names first appear (at most `MAX_RESOLVE_TECHNOLOGIES` names).
+///
+/// # Returns
+/// - `200 OK` - The resolved technologies
+/// - `400 Bad Request` - Too many names, or a name that is empty or too long
+#[utoipa::path(
+    post,
+    path = "/technologies/resolve",
+    tag = "technologies",
+    request_body = Vec<String>,
+    responses(
+        (status = 200, description = "Existing and newly created technologies, one per distinct name", body = [Technology]),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn resolve_technologies(
+    State(state): State<AppState>,
+    Json(names): Json<Vec<String>>,
+) -> Result<Json<Vec<Technology>>> {
+    if names.len() > MAX_RESOLVE_TECHNOLOGIES {
+        return Err(AppError::ValidationError(format!(
+            "At most {} names can be resolved at once",
+            MAX_RESOLVE_TECHNOLOGIES
+        )));
+    }
+
+    // Later spellings of a name already seen resolve to the same technology
+    let mut seen = HashSet::new();
+    let mut requests = Vec::new();
+    for (index, name) in names.into_iter().enumerate() {
+        let request = CreateTechnologyRequest { name, description: None };
+        request
+            .validate()
+            .map_err(|e| AppError::ValidationError(format!("Name {}: {}", index, e)))?;
+        if seen.insert(Technology::name_key(&request.name)) {
+            requests.push(request);
+        }
+    }
+
+    let mut tx = state.db.begin().await?;
+    let mut technologies = Vec::with_capacity(requests.len());
+    let mut created = 0;
+
+    for request in requests {
+        let name_key = Technology::name_key(&request.name);
+        let existing = sqlx::query_as::<_, Technology>("SELECT * FROM technologies WHERE name_key = ?")
+            .bind(&name_key)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+        let technology = match existing {
+            Some(technology) => technology,
+            None => {
+                let technology = Technology::new(request);
+                sqlx::query(
+                    "INSERT INTO technologies (id, name, name_key, description, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)"
+                )
+                .bind(technology.id.to_string())
+                .bind(&technology.name)
+                .bind(&name_key)
+                .bind(&technology.description)
+                .bind(technology.created_at)
+                .bind(technology.updated_at)
+                .execute(&mut *tx)
+                .await?;
+                created += 1;
+                technology
+            }
+        };
+        technologies.push(technology);
+    }
+
+    tx.commit().await?;
+
+    tracing::info!("Resolved {} technologies ({} created)", technologies.len(), created);
+    Ok(Json(technologies))
+}
+
+/// Update a technology
+///
+/// # Endpoint
+/// PUT /technologies/{id}
+///
+/// # Request Body
+/// All fields are optional; omitted fields are left unchanged.
+/// ```json
+/// {
+///   "name": "Rust",
+///   "description": "Updated description"
+/// }
+/// ```
+///
+/// # Returns
+/// - `200 OK` - Updated technology
+/// - `400 Bad Request` - Validation error
+/// - `404 Not Found` - Technology not found
+/// - `409 Conflict` - Another technology already has this name
+#[utoipa::path(
+    put,
+    path = "/technologies/{id}",
+    tag = "technologies",
+    params(
+        ("id" = Uuid, Path, description = "Technology UUID")
+    ),
+    request_body = UpdateTechnologyRequest,
+    responses(
+        (status = 200, description = "Technology updated successfully", body = Technology),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "Technology not found", body = ErrorResponse),
+        (status = 409, description = "Technology name already taken", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn update_technology(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(update): Json<UpdateTechnologyRequest>,
+) -> Result<Json<Technology>> {
+    update.validate()?;
+
+    let mut tx = state.db.begin().await?;
+
+    let mut technology = sqlx::query_as::<_, Technology>("SELECT * FROM technologies WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::TechnologyNotFound(id.to_string()))?;
+
+    if let Some(name) = update.name {
+        // Renaming may only collide with *other* technologies (a casing change is fine)
+        let taken = sqlx::query("SELECT 1 FROM technologies WHERE name_key = ? AND id != ?")
+            .bind(Technology::name_key(&name))
+            .bind(id.to_string())
+            .fetch_optional(&mut *tx)
+            .await?;
+        if taken.is_some() {
+            return Err(AppError::DuplicateResource(format!(
+                "Technology with name '{}' already exists",
+                name
+            )));
+        }
+        technology.name = name;
+    }
+    if let Some(description) = update.description {
+        technology.description = Some(description);
+    }
+    technology.updated_at = Utc::now();
+
+    sqlx::query("UPDATE technologies SET name = ?, name_key = ?, description = ?, updated_at = ? WHERE id = ?")
+        .bind(&technology.name)
+        .bind(Technology::name_key(&technology.name))
+        .bind(&technology.description)
+        .bind(technology.updated_at)
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Updated technology: {}", id);
+    Ok(Json(technology))
+}
+
+/// Delete a technology
+///
+/// # Endpoint
+/// DELETE /technologies/{id}
+///
+/// # Arguments
+/// - `id` - UUID of the technology to delete
+///
+/// # Returns
+/// - `204 No Content` - Successfully deleted
+/// - `404 Not Found` - Technology not found
+/// - `409 Conflict` - Technology is still used by projects (merge or detach it first)
+#[utoipa::path(
+    delete,
+    path = "/technologies/{id}",
+    tag = "technologies",
+    params(
+        ("id" = Uuid, Path, description = "Technology UUID")
+    ),
+    responses(
+        (status = 204, description = "Technology deleted successfully"),
+        (status = 404, description = "Technology not found", body = ErrorResponse),
+        (status = 409, description = "Technology is used by projects", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn delete_technology(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode> {
+    // Usage check and delete share a transaction so no association can sneak in between
+    let mut tx = state.db.begin().await?;
+
+    let exists = sqlx::query("SELECT 1 FROM technologies WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+    if exists.is_none() {
+        return Err(AppError::TechnologyNotFound(id.to_string()));
+    }
+
+    // Refuse to silently cascade into project associations
+    let usage: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM project_technologies WHERE technology_id = ?"
+    )
+    .bind(id.to_string())
+    .fetch_one(&mut *tx)
+    .await?;
+    if usage > 0 {
+        return Err(AppError::Conflict(format!(
+            "Technology {} is used by {} project(s)",
+            id, usage
+        )));
+    }
+
+    sqlx::query("DELETE FROM technologies WHERE id = ?")
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Deleted technology: {}", id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Ensures both sides of a merge exist and are distinct
+async fn check_merge_request(
+    conn: &mut SqliteConnection,
+    request: &MergeTechnologiesRequest,
+) -> Result<()> {
+    if request.from == request.into {
+        return Err(AppError::ValidationError(
+            "Cannot merge a technology into itself".to_string(),
+        ));
+    }
+
+    for id in [request.from, request.into] {
+        let exists = sqlx::query("SELECT 1 FROM technologies WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&mut *conn)
+            .await?;
+        if exists.is_none() {
+            return Err(AppError::TechnologyNotFound(id.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Computes what merging `from` into `into` would do to `project_technologies`
+async fn merge_summary(
+    conn: &mut SqliteConnection,
+    request: &MergeTechnologiesRequest,
+) -> Result<TechnologyMergeSummary> {
+    let affected: Vec<(String,)> = sqlx::query_as(
+        "SELECT project_id FROM project_technologies WHERE technology_id = ? ORDER BY project_id"
+    )
+    .bind(request.from.to_string())
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let (duplicates_dropped,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM project_technologies a
+         WHERE a.technology_id = ?
+           AND EXISTS (
+               SELECT 1 FROM project_technologies b
+               WHERE b.project_id = a.project_id AND b.technology_id = ?
+           )"
+    )
+    .bind(request.from.to_string())
+    .bind(request.into.to_string())
+    .fetch_one(&mut *conn)
+    .await?;
+
+    let affected_project_ids = affected
+        .into_iter()
+        .map(|(id,)| {
+            Uuid::parse_str(&id)
+                .map_err(|e| AppError::InternalError(format!("Invalid project UUID: {}", e)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(TechnologyMergeSummary {
+        moved: affected_project_ids.len() as i64 - duplicates_dropped,
+        duplicates_dropped,
+        affected_project_ids,
+    })
+}
+
+/// Preview the effect of merging one technology into another
+///
+/// # Endpoint
+/// GET /technologies/merge/preview?from={uuid}&into={uuid}
+///
+/// Nothing is written; the counts match what `POST /technologies/merge` would do.
+///
+/// # Returns
+/// - `200 OK` - Merge impact summary
+/// - `400 Bad Request` - `from` and `into` are the same technology
+/// - `404 Not Found` - Either technology does not exist
+#[utoipa::path(
+    get,
+    path = "/technologies/merge/preview",
+    tag = "technologies",
+    params(
+        ("from" = Uuid, Query, description = "Technology to merge away"),
+        ("into" = Uuid, Query, description = "Technology that receives the associations"),
+    ),
+    responses(
+        (status = 200, description = "Merge impact summary", body = TechnologyMergeSummary),
+        (status = 400, description = "Invalid merge request", body = ErrorResponse),
+        (status = 404, description = "Technology not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn preview_merge_technologies(
+    State(state): State<AppState>,
+    Query(request): Query<MergeTechnologiesRequest>,
+) -> Result<Json<TechnologyMergeSummary>> {
+    let mut conn = state.db.acquire().await?;
+
+    check_merge_request(&mut conn, &request).await?;
+    let summary = merge_summary(&mut conn, &request).await?;
+
+    tracing::info!(
+        "Previewed merge of technology {} into {}: {} moved, {} dropped",
+        request.from, request.into, summary.moved, summary.duplicates_dropped
+    );
+    Ok(Json(summary))
+}
+
+/// Merge one technology into another
+///
+/// # Endpoint
+/// POST /technologies/merge
+///
+/// # Request Body
+/// ```json
+/// {
+///   "from": "uuid-of-duplicate",
+///   "into": "uuid-to-keep"
+/// }
+/// ```
+///
+/// Re-points every project using `from` to `into` (dropping associations that would
+/// duplicate an existing one) and deletes `from`, all in a single transaction.
+///
+/// # Returns
+/// - `200 OK` - Summary of what was merged
+/// - `400 Bad Request` - `from` and `into` are the same technology
+/// - `404 Not Found` - Either technology does not exist
+#[utoipa::path(
+    post,
+    path = "/technologies/merge",
+    tag = "technologies",
+    request_body = MergeTechnologiesRequest,
+    responses(
+        (status = 200, description = "Technologies merged", body = TechnologyMergeSummary),
+        (status = 400, description = "Invalid merge request", body = ErrorResponse),
+        (status = 404, description = "Technology not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn merge_technologies(
+    State(state): State<AppState>,
+    Json(request): Json<MergeTechnologiesRequest>,
+) -> Result<Json<TechnologyMergeSummary>> {
+    let mut tx = state.db.begin().await?;
+
+    check_merge_request(&mut tx, &request).await?;
+    let summary = merge_summary(&mut tx, &request).await?;
+
+    let from = request.from.to_string();
+    let into = request.into.to_string();
+
+    // Touch affected projects so their updated_at reflects the relation change
+    sqlx::query(
+        "UPDATE projects SET updated_at = ?
+         WHERE id IN (SELECT project_id FROM project_technologies WHERE technology_id = ?)"
+    )
+    .bind(Utc::now())
+    .bind(&from)
+    .execute(&mut *tx)
+    .await?;
+
+    // Re-point associations; rows that would collide with an existing one are left behind
+    sqlx::query("UPDATE OR IGNORE project_technologies SET technology_id = ? WHERE technology_id = ?")
+        .bind(&into)
+        .bind(&from)
+        .execute(&mut *tx)
+        .await?;
+
+    // Whatever is left are the duplicates
+    sqlx::query("DELETE FROM project_technologies WHERE technology_id = ?")
+        .bind(&from)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query("DELETE FROM technologies WHERE id = ?")
+        .bind(&from)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    tracing::info!(
+        "Merged technology {} into {}: {} moved, {} dropped",
+        request.from, request.into, summary.moved, summary.duplicates_dropped
+    );
+    Ok(Json(summary))
 }
 
 #[cfg(test)]
@@ -124,15 +886,20 @@ mod tests {
         };
 
         // Create technology
-        let (status, Json(created)) = create_technology(State(state.clone()), Json(request))
+        let (status, headers, Json(created)) = create_technology(State(state.clone()), ValidatedJson(request))
             .await
             .unwrap();
 
         assert_eq!(status, StatusCode::CREATED);
         assert_eq!(created.name, "Rust");
+        assert_eq!(
+            headers.get(axum::http::header::LOCATION).unwrap(),
+            &format!("/technologies/{}", created.id)
+        );
 
         // List technologies
-        let Json(technologies) = list_technologies(State(state)).await.unwrap();
+        let Json(page) = list_technologies(State(state), None, Query(ListQueryParams::default())).await.unwrap();
+        let technologies = page.data;
         assert_eq!(technologies.len(), 1);
         assert_eq!(technologies[0].name, "Rust");
     }
@@ -147,12 +914,608 @@ mod tests {
         };
 
         // Create first technology
-        let _ = create_technology(State(state.clone()), Json(request.clone()))
+        let _ = create_technology(State(state.clone()), ValidatedJson(request.clone()))
             .await
             .unwrap();
 
         // Try to create duplicate
-        let result = create_technology(State(state), Json(request)).await;
+        let result = create_technology(State(state), ValidatedJson(request)).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_get_technology() {
+        let state = new_test_db().await;
+
+        let (_, _, Json(created)) = create_technology(
+            State(state.clone()),
+            ValidatedJson(CreateTechnologyRequest {
+                name: "Rust".to_string(),
+                description: Some("A systems programming language".to_string()),
+            }),
+        )
+        .await
+        .unwrap();
+
+        let Json(fetched) = get_technology(State(state), Path(created.id)).await.unwrap();
+        assert_eq!(fetched.id, created.id);
+        assert_eq!(fetched.name, "Rust");
+        assert_eq!(fetched.description.as_deref(), Some("A systems programming language"));
+    }
+
+    #[tokio::test]
+    async fn test_get_technology_not_found() {
+        let state = new_test_db().await;
+
+        let result = get_technology(State(state), Path(Uuid::new_v4())).await;
+        assert!(matches!(result, Err(AppError::TechnologyNotFound(_))));
+    }
+
+    async fn create_named(state: &AppState, name: &str, description: Option<&str>) -> Technology {
+        let (_, _, Json(tech)) = create_technology(
+            State(state.clone()),
+            ValidatedJson(CreateTechnologyRequest {
+                name: name.to_string(),
+                description: description.map(str::to_string),
+            }),
+        )
+        .await
+        .unwrap();
+        tech
+    }
+
+    #[tokio::test]
+    async fn test_update_technology_rename() {
+        let state = new_test_db().await;
+        let tech = create_named(&state, "Javascript", Some("Scripting")).await;
+
+        let update = UpdateTechnologyRequest {
+            name: Some("JavaScript".to_string()),
+            description: None,
+        };
+        let Json(updated) = update_technology(State(state.clone()), Path(tech.id), Json(update))
+            .await
+            .unwrap();
+        assert_eq!(updated.name, "JavaScript");
+        assert_eq!(updated.description.as_deref(), Some("Scripting"));
+
+        let update = UpdateTechnologyRequest {
+            name: Some("ECMAScript".to_string()),
+            description: None,
+        };
+        let _ = update_technology(State(state.clone()), Path(tech.id), Json(update))
+            .await
+            .unwrap();
+        let Json(fetched) = get_technology(State(state.clone()), Path(tech.id)).await.unwrap();
+        assert_eq!(fetched.name, "ECMAScript");
+
+        // The old name is free again, the new one is taken (in any casing)
+        create_named(&state, "javascript", None).await;
+        let result = create_technology(
+            State(state),
+            ValidatedJson(CreateTechnologyRequest {
+                name: "ecmascript".to_string(),
+                description: None,
+            }),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::DuplicateResource(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_technology_description_only() {
+        let state = new_test_db().await;
+        let tech = create_named(&state, "Rust", Some("Old")).await;
+
+        let update = UpdateTechnologyRequest {
+            name: None,
+            description: Some("A systems programming language".to_string()),
+        };
+        let Json(updated) = update_technology(State(state.clone()), Path(tech.id), Json(update))
+            .await
+            .unwrap();
+        assert_eq!(updated.name, "Rust");
+
+        let Json(fetched) = get_technology(State(state), Path(tech.id)).await.unwrap();
+        assert_eq!(fetched.name, "Rust");
+        assert_eq!(fetched.description.as_deref(), Some("A systems programming language"));
+    }
+
+    #[tokio::test]
+    async fn test_update_technology_advances_updated_at() {
+        let state = new_test_db().await;
+        let tech = create_named(&state, "Rust", None).await;
+        assert_eq!(tech.updated_at, tech.created_at);
+
+        let update = UpdateTechnologyRequest {
+            name: None,
+            description: Some("Fearless concurrency".to_string()),
+        };
+        let Json(updated) = update_technology(State(state.clone()), Path(tech.id), Json(update))
+            .await
+            .unwrap();
+        assert!(updated.updated_at > tech.updated_at);
+        assert_eq!(updated.created_at, tech.created_at);
+
+        let Json(fetched) = get_technology(State(state), Path(tech.id)).await.unwrap();
+        assert_eq!(fetched.updated_at, updated.updated_at);
+    }
+
+    #[tokio::test]
+    async fn test_update_technology_rename_collision() {
+        let state = new_test_db().await;
+        create_named(&state, "Rust", None).await;
+        let go = create_named(&state, "Go", None).await;
+
+        let update = UpdateTechnologyRequest {
+            name: Some("RUST".to_string()),
+            description: None,
+        };
+        let result = update_technology(State(state.clone()), Path(go.id), Json(update)).await;
+        assert!(matches!(result, Err(AppError::DuplicateResource(_))));
+
+        let Json(fetched) = get_technology(State(state), Path(go.id)).await.unwrap();
+        assert_eq!(fetched.name, "Go");
+    }
+
+    #[tokio::test]
+    async fn test_delete_technology() {
+        let state = new_test_db().await;
+
+        let (_, _, Json(tech)) = create_technology(
+            State(state.clone()),
+            ValidatedJson(CreateTechnologyRequest {
+                name: "Rust".to_string(),
+                description: None,
+            }),
+        )
+        .await
+        .unwrap();
+
+        let status = delete_technology(State(state.clone()), Path(tech.id)).await.unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let Json(page) = list_technologies(State(state.clone()), None, Query(ListQueryParams::default())).await.unwrap();
+        let technologies = page.data;
+        assert!(technologies.is_empty());
+
+        // Deleting again reports it as missing
+        let result = delete_technology(State(state), Path(tech.id)).await;
+        assert!(matches!(result, Err(AppError::TechnologyNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_technology_in_use_conflicts() {
+        let state = new_test_db().await;
+
+        let (_, _, Json(tech)) = create_technology(
+            State(state.clone()),
+            ValidatedJson(CreateTechnologyRequest {
+                name: "Rust".to_string(),
+                description: None,
+            }),
+        )
+        .await
+        .unwrap();
+
+        let now = Utc::now();
+        for i in 0..2 {
+            let project_id = Uuid::new_v4().to_string();
+            sqlx::query(
+                "INSERT INTO projects (id, name, description, repository_url, language, created_at, updated_at)
+                 VALUES (?, ?, 'desc', 'https://github.com/test/repo', 'Rust', ?, ?)"
+            )
+            .bind(&project_id)
+            .bind(format!("Project {}", i))
+            .bind(now)
+            .bind(now)
+            .execute(&state.db)
+            .await
+            .unwrap();
+            sqlx::query(
+                "INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)"
+            )
+            .bind(&project_id)
+            .bind(tech.id.to_string())
+            .bind(now)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        }
+
+        let result = delete_technology(State(state.clone()), Path(tech.id)).await;
+        match result {
+            Err(AppError::Conflict(message)) => assert!(message.contains("2 project(s)")),
+            other => panic!("expected conflict, got {:?}", other),
+        }
+
+        // Nothing was removed
+        let Json(page) = list_technologies(State(state.clone()), None, Query(ListQueryParams::default())).await.unwrap();
+        let technologies = page.data;
+        assert_eq!(technologies.len(), 1);
+        let (associations,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM project_technologies")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(associations, 2);
+    }
+
+    /// Inserts a live project named `name` using `technologies`
+    async fn insert_project_using(state: &AppState, name: &str, technologies: &[Uuid]) -> Uuid {
+        let now = Utc::now();
+        let project_id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO projects (id, name, description, repository_url, language, created_at, updated_at)
+             VALUES (?, ?, 'desc', 'https://github.com/test/repo', 'Rust', ?, ?)"
+        )
+        .bind(project_id.to_string())
+        .bind(name)
+        .bind(now)
+        .bind(now)
+        .execute(&state.db)
+        .await
+        .unwrap();
+        for technology_id in technologies {
+            sqlx::query("INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)")
+                .bind(project_id.to_string())
+                .bind(technology_id.to_string())
+                .bind(now)
+                .execute(&state.db)
+                .await
+                .unwrap();
+        }
+        project_id
+    }
+
+    #[tokio::test]
+    async fn test_get_technology_projects() {
+        let state = new_test_db().await;
+        let rust = create_named(&state, "Rust", None).await;
+        let go = create_named(&state, "Go", None).await;
+        let unused = create_named(&state, "Elixir", None).await;
+        let alpha = insert_project_using(&state, "Alpha", &[rust.id, go.id]).await;
+        let beta = insert_project_using(&state, "Beta", &[rust.id]).await;
+        insert_project_using(&state, "Gamma", &[go.id]).await;
+
+        let params = ListQueryParams {
+            sort: Some("name".to_string()),
+            order: Some("asc".to_string()),
+            ..Default::default()
+        };
+        let Json(page) = get_technology_projects(State(state.clone()), Path(rust.id), Query(params))
+            .await
+            .unwrap();
+        let ids: Vec<Uuid> = page.data.iter().map(|project| project.id).collect();
+        assert_eq!(ids, vec![alpha, beta]);
+        assert_eq!(page.pagination.total_items, Some(2));
+
+        // Known but unused yields an empty page, unknown a 404
+        let Json(page) = get_technology_projects(State(state.clone()), Path(unused.id), Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        assert!(page.data.is_empty());
+        assert_eq!(page.pagination.total_items, Some(0));
+
+        let result = get_technology_projects(State(state), Path(Uuid::new_v4()), Query(ListQueryParams::default())).await;
+        assert!(matches!(result, Err(AppError::TechnologyNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_technologies_gets_or_creates() {
+        let state = new_test_db().await;
+        let rust = create_named(&state, "Rust", Some("Systems")).await;
+
+        let names = ["rust", "React", "Go", "react", "RUST"].map(str::to_string).to_vec();
+        let Json(resolved) = resolve_technologies(State(state.clone()), Json(names)).await.unwrap();
+        let resolved_names: Vec<&str> = resolved.iter().map(|technology| technology.name.as_str()).collect();
+        assert_eq!(resolved_names, vec!["Rust", "React", "Go"]);
+        assert_eq!(resolved[0].id, rust.id);
+        assert_eq!(resolved[0].description.as_deref(), Some("Systems"));
+
+        // Resolving again only finds the same rows
+        let Json(again) = resolve_technologies(State(state.clone()), Json(vec!["go".to_string(), "REACT".to_string()]))
+            .await
+            .unwrap();
+        assert_eq!(again[0].id, resolved[2].id);
+        assert_eq!(again[1].id, resolved[1].id);
+
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM technologies")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_technologies_rejects_invalid_names_atomically() {
+        let state = new_test_db().await;
+
+        let result = resolve_technologies(State(state.clone()), Json(vec!["Rust".to_string(), String::new()])).await;
+        assert!(matches!(result, Err(AppError::ValidationError(message)) if message.starts_with("Name 1")));
+
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM technologies")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_technology_name_is_case_insensitive() {
+        let state = new_test_db().await;
+
+        let request = |name: &str| {
+            ValidatedJson(CreateTechnologyRequest {
+                name: name.to_string(),
+                description: None,
+            })
+        };
+
+        let (_, _, Json(created)) = create_technology(State(state.clone()), request("React"))
+            .await
+            .unwrap();
+        assert_eq!(created.name, "React");
+
+        // Case variants collide with the existing technology
+        for variant in ["react", "REACT", "rEaCt"] {
+            let result = create_technology(State(state.clone()), request(variant)).await;
+            assert!(matches!(result, Err(AppError::DuplicateResource(_))));
+        }
+
+        // Display casing is preserved and the key is stored lowercased
+        let Json(page) = list_technologies(State(state.clone()), None, Query(ListQueryParams::default())).await.unwrap();
+        let technologies = page.data;
+        assert_eq!(technologies.len(), 1);
+        assert_eq!(technologies[0].name, "React");
+
+        let name_key: String = sqlx::query_scalar("SELECT name_key FROM technologies WHERE id = ?")
+            .bind(created.id.to_string())
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(name_key, "react");
+
+        // Searches match regardless of casing
+        let params = ListQueryParams {
+            search: Some("REA".to_string()),
+            ..Default::default()
+        };
+        let Json(unused) = list_unused_technologies(State(state), None, Query(params))
+            .await
+            .unwrap();
+        assert_eq!(unused.data.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_case_variant_rejected_by_database_constraint() {
+        let state = new_test_db().await;
+        create_named(&state, "Rust", None).await;
+
+        // Bypassing the handler's pre-check, the unique name_key index still refuses
+        // the variant, and the violation surfaces as a 409 conflict
+        let error: AppError = sqlx::query("INSERT INTO technologies (id, name, created_at) VALUES (?, ?, ?)")
+            .bind(Uuid::new_v4().to_string())
+            .bind("rust")
+            .bind(Utc::now())
+            .execute(&state.db)
+            .await
+            .unwrap_err()
+            .into();
+        assert!(matches!(error, AppError::DuplicateResource(_)), "{:?}", error);
+    }
+
+    #[tokio::test]
+    async fn test_name_key_matches_database_folding() {
+        let state = new_test_db().await;
+
+        // Rows keyed by the insert trigger (SQLite's lower()) and by the handlers agree
+        for name in ["Élan", "ÑODE", "Café.JS"] {
+            let id = Uuid::new_v4();
+            sqlx::query("INSERT INTO technologies (id, name, created_at) VALUES (?, ?, ?)")
+                .bind(id.to_string())
+                .bind(name)
+                .bind(Utc::now())
+                .execute(&state.db)
+                .await
+                .unwrap();
+            let stored: String = sqlx::query_scalar("SELECT name_key FROM technologies WHERE id = ?")
+                .bind(id.to_string())
+                .fetch_one(&state.db)
+                .await
+                .unwrap();
+            assert_eq!(stored, Technology::name_key(name));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merge_preview_matches_merge() {
+        let state = new_test_db().await;
+
+        let mut tech_ids = Vec::new();
+        for name in ["Rust", "rust-lang"] {
+            let (_, _, Json(tech)) = create_technology(
+                State(state.clone()),
+                ValidatedJson(CreateTechnologyRequest {
+                    name: name.to_string(),
+                    description: None,
+                }),
+            )
+            .await
+            .unwrap();
+            tech_ids.push(tech.id);
+        }
+        let (keep, duplicate) = (tech_ids[0], tech_ids[1]);
+
+        // Three projects: one uses both technologies, two only the duplicate
+        let now = Utc::now();
+        for (i, techs) in [vec![keep, duplicate], vec![duplicate], vec![duplicate]]
+            .into_iter()
+            .enumerate()
+        {
+            let project_id = Uuid::new_v4().to_string();
+            sqlx::query(
+                "INSERT INTO projects (id, name, description, repository_url, language, created_at, updated_at)
+                 VALUES (?, ?, 'desc', 'https://github.com/test/repo', 'Rust', ?, ?)"
+            )
+            .bind(&project_id)
+            .bind(format!("Project {}", i))
+            .bind(now)
+            .bind(now)
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+            for tech_id in techs {
+                sqlx::query(
+                    "INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)"
+                )
+                .bind(&project_id)
+                .bind(tech_id.to_string())
+                .bind(now)
+                .execute(&state.db)
+                .await
+                .unwrap();
+            }
+        }
+
+        let request = MergeTechnologiesRequest {
+            from: duplicate,
+            into: keep,
+        };
+
+        let Json(preview) = preview_merge_technologies(State(state.clone()), Query(request.clone()))
+            .await
+            .unwrap();
+        assert_eq!(preview.moved, 2);
+        assert_eq!(preview.duplicates_dropped, 1);
+        assert_eq!(preview.affected_project_ids.len(), 3);
+
+        // Preview must not have changed anything
+        let (remaining,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM project_technologies WHERE technology_id = ?"
+        )
+        .bind(duplicate.to_string())
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+        assert_eq!(remaining, 3);
+
+        let Json(merged) = merge_technologies(State(state.clone()), Json(request))
+            .await
+            .unwrap();
+        assert_eq!(merged.moved, preview.moved);
+        assert_eq!(merged.duplicates_dropped, preview.duplicates_dropped);
+        assert_eq!(merged.affected_project_ids, preview.affected_project_ids);
+
+        let (total,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM project_technologies WHERE technology_id = ?"
+        )
+        .bind(keep.to_string())
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+        assert_eq!(total, 3);
+
+        let Json(page) = list_technologies(State(state), None, Query(ListQueryParams::default())).await.unwrap();
+        let technologies = page.data;
+        assert_eq!(technologies.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_technologies_paginated() {
+        let state = new_test_db().await;
+
+        for name in ["Axum", "Diesel", "React", "Rocket", "Rust"] {
+            let (_, _, Json(_)) = create_technology(
+                State(state.clone()),
+                ValidatedJson(CreateTechnologyRequest {
+                    name: name.to_string(),
+                    description: None,
+                }),
+            )
+            .await
+            .unwrap();
+        }
+
+        let params = ListQueryParams {
+            page: Some(2),
+            page_size: Some(2),
+            ..Default::default()
+        };
+        let Json(page) = list_technologies(State(state.clone()), None, Query(params)).await.unwrap();
+        let names: Vec<&str> = page.data.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["React", "Rocket"]);
+        assert_eq!(page.pagination.total_items, Some(5));
+        assert_eq!(page.pagination.total_pages, Some(3));
+
+        // Search is case-insensitive and counted before paging
+        let params = ListQueryParams {
+            search: Some("R".to_string()),
+            page_size: Some(2),
+            ..Default::default()
+        };
+        let Json(page) = list_technologies(State(state), None, Query(params)).await.unwrap();
+        assert_eq!(page.data.len(), 2);
+        assert_eq!(page.pagination.total_items, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_list_unused_technologies() {
+        let state = new_test_db().await;
+
+        let mut ids = Vec::new();
+        for name in ["Rust", "JavaScript", "TypeScript"] {
+            let (_, _, Json(tech)) = create_technology(
+                State(state.clone()),
+                ValidatedJson(CreateTechnologyRequest {
+                    name: name.to_string(),
+                    description: None,
+                }),
+            )
+            .await
+            .unwrap();
+            ids.push(tech.id);
+        }
+
+        // Only Rust is used by a project
+        let now = Utc::now();
+        let project_id = Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO projects (id, name, description, repository_url, language, created_at, updated_at)
+             VALUES (?, 'Used', 'desc', 'https://github.com/test/repo', 'Rust', ?, ?)"
+        )
+        .bind(&project_id)
+        .bind(now)
+        .bind(now)
+        .execute(&state.db)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)"
+        )
+        .bind(&project_id)
+        .bind(ids[0].to_string())
+        .bind(now)
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+        let Json(unused) = list_unused_technologies(State(state.clone()), None, Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        let names: Vec<&str> = unused.data.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["JavaScript", "TypeScript"]);
+        assert_eq!(unused.pagination.total_items, Some(2));
+
+        // Composes with the search filter
+        let params = ListQueryParams {
+            search: Some("Type".to_string()),
+            ..Default::default()
+        };
+        let Json(unused) = list_unused_technologies(State(state), None, Query(params))
+            .await
+            .unwrap();
+        assert_eq!(unused.data.len(), 1);
+        assert_eq!(unused.data[0].name, "TypeScript");
+    }
 }
@@ -1,42 +1,104 @@
 use axum::{
-    Json,
-    extract::State,
-    http::StatusCode,
+    Extension, Json,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
 };
+use chrono::Utc;
+use sqlx::Row;
+use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
     error::{AppError, ErrorResponse, Result},
-    models::{CreateUserRequest, User},
-    state::AppState,
+    extractors::ValidatedJson,
+    handlers::{created_location, reviews::refresh_project_rating},
+    models::{
+        CreateUserRequest, ListQueryParams, PaginatedResponse, ProjectWithRole, UpdateUserRequest, User,
+        pagination::{LIKE_ESCAPE, ListQuery, PROJECT_SEARCH_BLOB, contains_pattern, search_key},
+    },
+    state::{AppState, CallerRole},
 };
 
-/// List all users
+/// List users with pagination
 ///
 /// # Endpoint
-/// GET /users
+/// GET /users?search=alice&page=1&page_size=10
+///
+/// # Query Parameters
+/// - `search` - Filter by name or email (case-insensitive substring)
+/// - `page` - Page number (default: 1)
+/// - `page_size` - Items per page (default: 10 or the caller role's configured default; default max 100, configurable via `MAX_PAGE_SIZE`)
 ///
 /// # Returns
-/// - `200 OK` - List of all users
+/// - `200 OK` - Paginated list of users ordered by name
 #[utoipa::path(
     get,
     path = "/users",
     tag = "users",
+    params(
+        ("search" = Option<String>, Query, description = "Filter by name or email"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("page_size" = Option<u32>, Query, description = "Items per page (default max 100, configurable via MAX_PAGE_SIZE)"),
+    ),
     responses(
-        (status = 200, description = "List of all users", body = [User]),
+        (status = 200, description = "Paginated list of users", body = PaginatedResponse<User>),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
 #[tracing::instrument(skip(state))]
-pub async fn list_users(State(state): State<AppState>) -> Result<Json<Vec<User>>> {
-    let users = sqlx::query_as::<_, User>(
-        "SELECT * FROM users ORDER BY name ASC"
-    )
-    .fetch_all(&state.db)
-    .await?;
+pub async fn list_users(
+    State(state): State<AppState>,
+    caller_role: Option<Extension<CallerRole>>,
+    Query(mut params): Query<ListQueryParams>,
+) -> Result<Json<PaginatedResponse<User>>> {
+    params
+        .page_size
+        .get_or_insert(state.config.default_page_size(caller_role.as_deref()));
+
+    let search_pattern = params.search.as_deref().map(contains_pattern);
+
+    let mut list_query = ListQuery::new("users u");
+    if let Some(ref pattern) = search_pattern {
+        list_query.filter(move |b| {
+            b.push("(u.name LIKE ");
+            b.push_bind(pattern.clone());
+            b.push(LIKE_ESCAPE);
+            b.push(" OR u.email LIKE ");
+            b.push_bind(pattern.clone());
+            b.push(LIKE_ESCAPE);
+            b.push(")");
+        });
+    }
 
-    tracing::info!("Listed {} users", users.len());
-    Ok(Json(users))
+    let total_items: i64 = list_query
+        .count_query()
+        .build()
+        .fetch_one(&state.db)
+        .await?
+        .try_get("count")?;
+    params.resolve_page(total_items, state.config.max_page_size, state.config.page_overflow)?;
+
+    let page_size = params.page_size(state.config.max_page_size);
+
+    let users = list_query
+        .page_query("u.*", "u.name ASC", page_size, params.offset(state.config.max_page_size))
+        .build_query_as::<User>()
+        .fetch_all(&state.db)
+        .await?;
+
+    tracing::info!(
+        "Listed {} users (page {}, total {})",
+        users.len(),
+        params.page(),
+        total_items
+    );
+
+    Ok(Json(PaginatedResponse::new(
+        users,
+        params.page(),
+        page_size,
+        total_items,
+    )))
 }
 
 /// Create a new user
@@ -48,10 +110,14 @@ pub async fn list_users(State(state): State<AppState>) -> Result<Json<Vec<User>>
 /// ```json
 /// {
 ///   "name": "John Doe",
-///   "email": "john@example.com"
+///   "email": "john@example.com",
+///   "avatar_url": "https://example.com/john.png",
+///   "bio": "Backend developer"
 /// }
 /// ```
 ///
+/// `avatar_url` and `bio` are optional.
+///
 /// # Returns
 /// - `201 Created` - Created user
 /// - `400 Bad Request` - Validation error
@@ -62,7 +128,8 @@ pub async fn list_users(State(state): State<AppState>) -> Result<Json<Vec<User>>
     tag = "users",
     request_body = CreateUserRequest,
     responses(
-        (status = 201, description = "User created successfully", body = User),
+        (status = 201, description = "User created successfully", body = User,
+            headers(("Location" = String, description = "URI of the created user"))),
         (status = 400, description = "Validation error", body = ErrorResponse),
         (status = 409, description = "User already exists", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
@@ -71,11 +138,8 @@ pub async fn list_users(State(state): State<AppState>) -> Result<Json<Vec<User>>
 #[tracing::instrument(skip(state))]
 pub async fn create_user(
     State(state): State<AppState>,
-    Json(request): Json<CreateUserRequest>,
-) -> Result<(StatusCode, Json<User>)> {
-    // Validate request
-    request.validate()?;
-
+    ValidatedJson(request): ValidatedJson<CreateUserRequest>,
+) -> Result<(StatusCode, HeaderMap, Json<User>)> {
     // Check if user with this email already exists
     let existing = sqlx::query_as::<_, User>(
         "SELECT * FROM users WHERE email = ?"
@@ -96,23 +160,385 @@ pub async fn create_user(
 
     // Insert into database
     sqlx::query(
-        "INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, ?)"
+        "INSERT INTO users (id, name, email, avatar_url, bio, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)"
     )
     .bind(user.id.to_string())
     .bind(&user.name)
     .bind(&user.email)
+    .bind(&user.avatar_url)
+    .bind(&user.bio)
     .bind(user.created_at)
+    .bind(user.updated_at)
     .execute(&state.db)
     .await?;
 
     tracing::info!("Created user: {}", user.id);
-    Ok((StatusCode::CREATED, Json(user)))
+    let location = created_location(&format!("/users/{}", user.id))?;
+    Ok((StatusCode::CREATED, location, Json(user)))
+}
+
+/// Get a specific user by ID
+///
+/// # Endpoint
+/// GET /users/{id}
+///
+/// # Arguments
+/// - `id` - UUID of the user
+///
+/// # Returns
+/// - `200 OK` - User details
+/// - `404 Not Found` - User not found
+#[utoipa::path(
+    get,
+    path = "/users/{id}",
+    tag = "users",
+    params(
+        ("id" = Uuid, Path, description = "User UUID")
+    ),
+    responses(
+        (status = 200, description = "User found", body = User),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn get_user(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<User>> {
+    let user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or_else(|| AppError::UserNotFound(id.to_string()))?;
+
+    Ok(Json(user))
+}
+
+/// List the projects a user belongs to
+///
+/// # Endpoint
+/// GET /users/{id}/projects?sort=name&order=asc&page=1&page_size=10
+///
+/// # Query Parameters
+/// - `search` - Search text in project name and description
+/// - `language` - Filter by language (case-insensitive)
+/// - `min_rating` / `max_rating` - Rating range
+/// - `sort` / `order` - Same fields as `GET /projects` (default `created_at` DESC)
+/// - `page` / `page_size` - Pagination
+///
+/// Soft-deleted projects are left out.
+///
+/// # Returns
+/// - `200 OK` - Paginated list of projects, each with the user's `role`
+/// - `400 Bad Request` - Unknown `sort` or `order` value
+/// - `404 Not Found` - User not found
+#[utoipa::path(
+    get,
+    path = "/users/{id}/projects",
+    tag = "users",
+    params(
+        ("id" = Uuid, Path, description = "User UUID"),
+        ("search" = Option<String>, Query, description = "Search text in project name and description"),
+        ("language" = Option<String>, Query, description = "Filter by language (case-insensitive)"),
+        ("min_rating" = Option<f64>, Query, description = "Minimum rating"),
+        ("max_rating" = Option<f64>, Query, description = "Maximum rating"),
+        ("sort" = Option<String>, Query, description = "Comma-separated sort fields (name, created_at, updated_at, rating)"),
+        ("order" = Option<String>, Query, description = "Comma-separated sort orders matching `sort` (asc, desc)"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("page_size" = Option<u32>, Query, description = "Items per page (default max 100, configurable via MAX_PAGE_SIZE)"),
+    ),
+    responses(
+        (status = 200, description = "Paginated list of the user's projects", body = PaginatedResponse<ProjectWithRole>),
+        (status = 400, description = "Unknown sort field or order", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn get_user_projects(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(mut params): Query<ListQueryParams>,
+) -> Result<Json<PaginatedResponse<ProjectWithRole>>> {
+    params.validate_sort()?;
+
+    let exists = sqlx::query("SELECT 1 FROM users WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_optional(&state.db)
+        .await?;
+    if exists.is_none() {
+        return Err(AppError::UserNotFound(id.to_string()));
+    }
+
+    let search_pattern = params.search.as_deref().map(|s| contains_pattern(&search_key(s)));
+    let language = params.language();
+
+    let mut list_query = ListQuery::new("project_users pu JOIN projects p ON p.id = pu.project_id");
+    list_query
+        .filter(move |b| {
+            b.push("pu.user_id = ");
+            b.push_bind(id.to_string());
+        })
+        .filter(|b| {
+            b.push("p.deleted_at IS NULL");
+        });
+    if let Some(ref pattern) = search_pattern {
+        list_query.filter(move |b| {
+            b.push(format!("{} LIKE ", PROJECT_SEARCH_BLOB));
+            b.push_bind(pattern.clone());
+            b.push(LIKE_ESCAPE);
+        });
+    }
+    if let Some(ref language) = language {
+        list_query.filter(move |b| {
+            b.push("lower(trim(p.language)) = ");
+            b.push_bind(language.clone());
+        });
+    }
+    if let Some(min_rating) = params.min_rating {
+        list_query.filter(move |b| {
+            b.push("p.rating >= ");
+            b.push_bind(min_rating);
+        });
+    }
+    if let Some(max_rating) = params.max_rating {
+        list_query.filter(move |b| {
+            b.push("p.rating <= ");
+            b.push_bind(max_rating);
+        });
+    }
+
+    let total_items: i64 = list_query
+        .count_query()
+        .build()
+        .fetch_one(&state.db)
+        .await?
+        .try_get("count")?;
+    params.resolve_page(total_items, state.config.max_page_size, state.config.page_overflow)?;
+
+    // Without a search index here, `relevance` has nothing to rank by
+    let mut order_by = params
+        .sort_clauses()
+        .into_iter()
+        .filter(|(field, _)| *field != "relevance")
+        .map(|(field, order)| format!("p.{} {}", field, order))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if order_by.is_empty() {
+        order_by = "p.created_at DESC".to_string();
+    }
+    let page_size = params.page_size(state.config.max_page_size);
+
+    let projects = list_query
+        .page_query("p.*, pu.role, pu.created_at AS associated_at", &order_by, page_size, params.offset(state.config.max_page_size))
+        .build_query_as::<ProjectWithRole>()
+        .fetch_all(&state.db)
+        .await?;
+
+    tracing::info!(
+        "Listed {} projects of user {} (page {}, total {})",
+        projects.len(),
+        id,
+        params.page(),
+        total_items
+    );
+
+    Ok(Json(PaginatedResponse::new(
+        projects,
+        params.page(),
+        page_size,
+        total_items,
+    )))
+}
+
+/// Update a user's name or email
+///
+/// # Endpoint
+/// PUT /users/{id}
+///
+/// # Request Body
+/// All fields are optional; omitted fields are left unchanged.
+/// ```json
+/// {
+///   "name": "Jane Doe",
+///   "email": "jane@example.com"
+/// }
+/// ```
+///
+/// # Returns
+/// - `200 OK` - Updated user
+/// - `400 Bad Request` - Validation error
+/// - `404 Not Found` - User not found
+/// - `409 Conflict` - Another user already has this email
+#[utoipa::path(
+    put,
+    path = "/users/{id}",
+    tag = "users",
+    params(
+        ("id" = Uuid, Path, description = "User UUID")
+    ),
+    request_body = UpdateUserRequest,
+    responses(
+        (status = 200, description = "User updated successfully", body = User),
+        (status = 400, description = "Validation error", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 409, description = "Email already taken", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn update_user(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(update): Json<UpdateUserRequest>,
+) -> Result<Json<User>> {
+    update.validate()?;
+
+    let mut tx = state.db.begin().await?;
+
+    let mut user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::UserNotFound(id.to_string()))?;
+
+    if let Some(name) = update.name {
+        user.name = name;
+    }
+    if let Some(email) = update.email {
+        // The email may only collide with *other* users (keeping your own is fine)
+        let taken = sqlx::query("SELECT 1 FROM users WHERE email = ? AND id != ?")
+            .bind(&email)
+            .bind(id.to_string())
+            .fetch_optional(&mut *tx)
+            .await?;
+        if taken.is_some() {
+            return Err(AppError::DuplicateResource(format!(
+                "User with email '{}' already exists",
+                email
+            )));
+        }
+        user.email = email;
+    }
+    user.updated_at = Utc::now();
+
+    sqlx::query("UPDATE users SET name = ?, email = ?, updated_at = ? WHERE id = ?")
+        .bind(&user.name)
+        .bind(&user.email)
+        .bind(user.updated_at)
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Updated user: {}", id);
+    Ok(Json(user))
+}
+
+/// Delete a user
+///
+/// # Endpoint
+/// DELETE /users/{id}
+///
+/// # Behavior
+/// The user's project memberships are removed along with the user, in one
+/// transaction. Deleting the sole owner of a project is refused, since it
+/// would leave the project without an owner: transfer ownership first.
+///
+/// # Returns
+/// - `204 No Content` - Successfully deleted
+/// - `404 Not Found` - User not found
+/// - `409 Conflict` - User is the sole owner of at least one project
+#[utoipa::path(
+    delete,
+    path = "/users/{id}",
+    tag = "users",
+    params(
+        ("id" = Uuid, Path, description = "User UUID")
+    ),
+    responses(
+        (status = 204, description = "User deleted successfully"),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 409, description = "User is the sole owner of a project", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[tracing::instrument(skip(state))]
+pub async fn delete_user(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode> {
+    let mut tx = state.db.begin().await?;
+
+    let exists = sqlx::query("SELECT 1 FROM users WHERE id = ?")
+        .bind(id.to_string())
+        .fetch_optional(&mut *tx)
+        .await?;
+    if exists.is_none() {
+        return Err(AppError::UserNotFound(id.to_string()));
+    }
+
+    let sole_owned: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM project_users pu
+         WHERE pu.user_id = ? AND pu.role = 'owner'
+           AND NOT EXISTS (
+               SELECT 1 FROM project_users other
+               WHERE other.project_id = pu.project_id
+                 AND other.role = 'owner'
+                 AND other.user_id != pu.user_id
+           )"
+    )
+    .bind(id.to_string())
+    .fetch_one(&mut *tx)
+    .await?;
+    if sole_owned > 0 {
+        return Err(AppError::Conflict(format!(
+            "User {} is the sole owner of {} project(s)",
+            id, sole_owned
+        )));
+    }
+
+    // Membership changes count as a project update
+    sqlx::query(
+        "UPDATE projects SET updated_at = ?
+         WHERE id IN (SELECT project_id FROM project_users WHERE user_id = ?)"
+    )
+    .bind(Utc::now())
+    .bind(id.to_string())
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("DELETE FROM project_users WHERE user_id = ?")
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    // Their reviews go too, so the reviewed projects' ratings are recomputed
+    let reviewed: Vec<String> = sqlx::query_scalar("DELETE FROM reviews WHERE user_id = ? RETURNING project_id")
+        .bind(id.to_string())
+        .fetch_all(&mut *tx)
+        .await?;
+    for project_id in &reviewed {
+        refresh_project_rating(&mut tx, project_id, state.config.default_rating).await?;
+    }
+
+    sqlx::query("DELETE FROM users WHERE id = ?")
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    tracing::info!("Deleted user: {}", id);
+    Ok(StatusCode::NO_CONTENT)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::tests::new_test_db;
+    use crate::{models::UserRole, state::tests::new_test_db};
 
     #[tokio::test]
     async fn test_create_and_list_user() {
@@ -121,23 +547,137 @@ mod tests {
         let request = CreateUserRequest {
             name: "John Doe".to_string(),
             email: "john@example.com".to_string(),
+            avatar_url: None,
+            bio: None,
         };
 
         // Create user
-        let (status, Json(created)) = create_user(State(state.clone()), Json(request))
+        let (status, headers, Json(created)) = create_user(State(state.clone()), ValidatedJson(request))
             .await
             .unwrap();
 
         assert_eq!(status, StatusCode::CREATED);
         assert_eq!(created.name, "John Doe");
+        assert_eq!(
+            headers.get(axum::http::header::LOCATION).unwrap(),
+            &format!("/users/{}", created.id)
+        );
         assert_eq!(created.email, "john@example.com");
 
         // List users
-        let Json(users) = list_users(State(state)).await.unwrap();
+        let Json(page) = list_users(State(state), None, Query(ListQueryParams::default())).await.unwrap();
+        let users = page.data;
         assert_eq!(users.len(), 1);
         assert_eq!(users[0].name, "John Doe");
     }
 
+    #[tokio::test]
+    async fn test_new_user_updated_at_equals_created_at() {
+        let state = new_test_db().await;
+
+        let (_, _, Json(created)) = create_user(
+            State(state.clone()),
+            ValidatedJson(CreateUserRequest {
+                name: "Jane Doe".to_string(),
+                email: "jane@example.com".to_string(),
+                avatar_url: None,
+                bio: None,
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(created.updated_at, created.created_at);
+
+        // Rows inserted without updated_at (raw SQL, seeds) get created_at too
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, ?)")
+            .bind(id.to_string())
+            .bind("Raw")
+            .bind("raw@example.com")
+            .bind(chrono::Utc::now())
+            .execute(&state.db)
+            .await
+            .unwrap();
+        let Json(raw) = get_user(State(state), Path(id)).await.unwrap();
+        assert_eq!(raw.updated_at, raw.created_at);
+    }
+
+    #[tokio::test]
+    async fn test_user_profile_fields_round_trip() {
+        let state = new_test_db().await;
+
+        let (_, _, Json(created)) = create_user(
+            State(state.clone()),
+            ValidatedJson(CreateUserRequest {
+                name: "Ada".to_string(),
+                email: "ada@example.com".to_string(),
+                avatar_url: Some("https://example.com/ada.png".to_string()),
+                bio: Some("Wrote the first program".to_string()),
+            }),
+        )
+        .await
+        .unwrap();
+
+        let Json(fetched) = get_user(State(state.clone()), Path(created.id)).await.unwrap();
+        assert_eq!(fetched.avatar_url.as_deref(), Some("https://example.com/ada.png"));
+        assert_eq!(fetched.bio.as_deref(), Some("Wrote the first program"));
+
+        // Clients that don't send them get nulls back
+        let request: CreateUserRequest =
+            serde_json::from_str(r#"{"name": "Bob", "email": "bob@example.com"}"#).unwrap();
+        let (_, _, Json(plain)) = create_user(State(state), ValidatedJson(request)).await.unwrap();
+        assert!(plain.avatar_url.is_none() && plain.bio.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_list_users_paginated() {
+        let state = new_test_db().await;
+
+        for (name, email) in [
+            ("Alice", "alice@example.com"),
+            ("Bob", "bob@corp.io"),
+            ("Carol", "carol@example.com"),
+        ] {
+            let (_, _, Json(_)) = create_user(
+                State(state.clone()),
+                ValidatedJson(CreateUserRequest {
+                    name: name.to_string(),
+                    email: email.to_string(),
+                    avatar_url: None,
+                    bio: None,
+                }),
+            )
+            .await
+            .unwrap();
+        }
+
+        let params = ListQueryParams {
+            page_size: Some(2),
+            ..Default::default()
+        };
+        let Json(page) = list_users(State(state.clone()), None, Query(params)).await.unwrap();
+        let names: Vec<&str> = page.data.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(names, vec!["Alice", "Bob"]);
+        assert_eq!(page.pagination.total_items, Some(3));
+        assert_eq!(page.pagination.total_pages, Some(2));
+
+        // Search matches names and emails
+        let params = ListQueryParams {
+            search: Some("example.com".to_string()),
+            ..Default::default()
+        };
+        let Json(page) = list_users(State(state.clone()), None, Query(params)).await.unwrap();
+        assert_eq!(page.pagination.total_items, Some(2));
+
+        let params = ListQueryParams {
+            search: Some("bob".to_string()),
+            ..Default::default()
+        };
+        let Json(page) = list_users(State(state), None, Query(params)).await.unwrap();
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0].email, "bob@corp.io");
+    }
+
     #[tokio::test]
     async fn test_duplicate_user_email() {
         let state = new_test_db().await;
@@ -145,15 +685,250 @@ mod tests {
         let request = CreateUserRequest {
             name: "John Doe".to_string(),
             email: "john@example.com".to_string(),
+            avatar_url: None,
+            bio: None,
         };
 
         // Create first user
-        let _ = create_user(State(state.clone()), Json(request.clone()))
+        let _ = create_user(State(state.clone()), ValidatedJson(request.clone()))
             .await
             .unwrap();
 
         // Try to create duplicate
-        let result = create_user(State(state), Json(request)).await;
+        let result = create_user(State(state), ValidatedJson(request)).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_update_user() {
+        let state = new_test_db().await;
+        let create = |name: &str, email: &str| {
+            let request = CreateUserRequest {
+                name: name.to_string(),
+                email: email.to_string(),
+                avatar_url: None,
+                bio: Some("Unchanged".to_string()),
+            };
+            let state = state.clone();
+            async move {
+                let (_, _, Json(user)) = create_user(State(state), ValidatedJson(request)).await.unwrap();
+                user
+            }
+        };
+        let john = create("John Doe", "john@example.com").await;
+        let jane = create("Jane Doe", "jane@example.com").await;
+        let update = |name: Option<&str>, email: Option<&str>| UpdateUserRequest {
+            name: name.map(str::to_string),
+            email: email.map(str::to_string),
+        };
+
+        // Name only: the email and other fields are kept
+        let Json(renamed) = update_user(State(state.clone()), Path(john.id), Json(update(Some("Johnny"), None)))
+            .await
+            .unwrap();
+        assert_eq!(renamed.name, "Johnny");
+        assert_eq!(renamed.email, "john@example.com");
+        assert_eq!(renamed.bio.as_deref(), Some("Unchanged"));
+        assert!(renamed.updated_at > john.updated_at);
+
+        // Email to a free address, or to the user's own
+        for email in ["johnny@example.com", "johnny@example.com"] {
+            let Json(moved) = update_user(State(state.clone()), Path(john.id), Json(update(None, Some(email))))
+                .await
+                .unwrap();
+            assert_eq!(moved.email, email);
+        }
+        let Json(stored) = get_user(State(state.clone()), Path(john.id)).await.unwrap();
+        assert_eq!((stored.name.as_str(), stored.email.as_str()), ("Johnny", "johnny@example.com"));
+
+        // Email taken by another user
+        let result = update_user(State(state.clone()), Path(john.id), Json(update(None, Some(&jane.email)))).await;
+        assert!(matches!(result, Err(AppError::DuplicateResource(_))));
+        let Json(stored) = get_user(State(state.clone()), Path(john.id)).await.unwrap();
+        assert_eq!(stored.email, "johnny@example.com");
+
+        // Invalid values and unknown users
+        let result = update_user(State(state.clone()), Path(john.id), Json(update(None, Some("not-an-email")))).await;
+        assert!(matches!(result, Err(AppError::FieldValidation(_))));
+        let result = update_user(State(state), Path(Uuid::new_v4()), Json(update(Some("Ghost"), None))).await;
+        assert!(matches!(result, Err(AppError::UserNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_duplicate_email_is_conflict() {
+        let state = new_test_db().await;
+
+        let request = |name: &str| CreateUserRequest {
+            name: name.to_string(),
+            email: "race@example.com".to_string(),
+            avatar_url: None,
+            bio: None,
+        };
+        let (first, second) = tokio::join!(
+            create_user(State(state.clone()), ValidatedJson(request("First"))),
+            create_user(State(state.clone()), ValidatedJson(request("Second"))),
+        );
+
+        let mut statuses: Vec<StatusCode> = [first, second]
+            .into_iter()
+            .map(|result| match result {
+                Ok((status, _, _)) => status,
+                Err(error) => axum::response::IntoResponse::into_response(error).status(),
+            })
+            .collect();
+        statuses.sort();
+        assert_eq!(statuses, vec![StatusCode::CREATED, StatusCode::CONFLICT]);
+
+        // A unique violation that slips past the pre-check maps to 409 as well
+        let error = sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, ?)")
+            .bind(Uuid::new_v4().to_string())
+            .bind("Third")
+            .bind("race@example.com")
+            .bind(chrono::Utc::now())
+            .execute(&state.db)
+            .await
+            .unwrap_err();
+        match AppError::from(error) {
+            AppError::DuplicateResource(message) => assert!(message.contains("users.email")),
+            other => panic!("expected duplicate resource, got {:?}", other),
+        }
+    }
+
+    async fn insert_project_with_members(state: &AppState, members: &[(Uuid, &str)]) -> Uuid {
+        let now = chrono::Utc::now();
+        let project_id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO projects (id, name, description, repository_url, language, created_at, updated_at)
+             VALUES (?, 'Shared', 'desc', 'https://github.com/test/repo', 'Rust', ?, ?)"
+        )
+        .bind(project_id.to_string())
+        .bind(now)
+        .bind(now)
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+        for (user_id, role) in members {
+            sqlx::query(
+                "INSERT INTO project_users (project_id, user_id, role, created_at) VALUES (?, ?, ?, ?)"
+            )
+            .bind(project_id.to_string())
+            .bind(user_id.to_string())
+            .bind(role)
+            .bind(now)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        }
+        project_id
+    }
+
+    async fn create_named(state: &AppState, name: &str) -> User {
+        let (_, _, Json(user)) = create_user(
+            State(state.clone()),
+            ValidatedJson(CreateUserRequest {
+                name: name.to_string(),
+                email: format!("{}@example.com", name.to_lowercase()),
+                avatar_url: None,
+                bio: None,
+            }),
+        )
+        .await
+        .unwrap();
+        user
+    }
+
+    #[tokio::test]
+    async fn test_get_user() {
+        let state = new_test_db().await;
+        let created = create_named(&state, "Jane").await;
+
+        let Json(fetched) = get_user(State(state.clone()), Path(created.id)).await.unwrap();
+        assert_eq!(fetched.id, created.id);
+        assert_eq!(fetched.email, "jane@example.com");
+
+        let result = get_user(State(state), Path(Uuid::new_v4())).await;
+        assert!(matches!(result, Err(AppError::UserNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_removes_memberships() {
+        let state = new_test_db().await;
+        let owner = create_named(&state, "Owner").await;
+        let member = create_named(&state, "Member").await;
+        insert_project_with_members(&state, &[(owner.id, "owner"), (member.id, "contributor")]).await;
+
+        let status = delete_user(State(state.clone()), Path(member.id)).await.unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let result = get_user(State(state.clone()), Path(member.id)).await;
+        assert!(matches!(result, Err(AppError::UserNotFound(_))));
+
+        let (memberships,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM project_users WHERE user_id = ?")
+            .bind(member.id.to_string())
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(memberships, 0);
+
+        let result = delete_user(State(state), Path(member.id)).await;
+        assert!(matches!(result, Err(AppError::UserNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_user_projects_with_roles() {
+        let state = new_test_db().await;
+        let alice = create_named(&state, "Alice").await;
+        let bob = create_named(&state, "Bob").await;
+        let owned = insert_project_with_members(&state, &[(alice.id, "owner"), (bob.id, "viewer")]).await;
+        let contributed = insert_project_with_members(&state, &[(bob.id, "owner"), (alice.id, "contributor")]).await;
+        insert_project_with_members(&state, &[(bob.id, "owner")]).await;
+
+        let Json(page) = get_user_projects(State(state.clone()), Path(alice.id), Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        assert_eq!(page.pagination.total_items, Some(2));
+        let roles: std::collections::HashMap<Uuid, UserRole> =
+            page.data.iter().map(|entry| (entry.project.id, entry.role)).collect();
+        assert_eq!(roles.len(), 2);
+        assert_eq!(roles[&owned], UserRole::Owner);
+        assert_eq!(roles[&contributed], UserRole::Contributor);
+
+        // Soft-deleted projects drop out
+        sqlx::query("UPDATE projects SET deleted_at = ? WHERE id = ?")
+            .bind(chrono::Utc::now())
+            .bind(contributed.to_string())
+            .execute(&state.db)
+            .await
+            .unwrap();
+        let Json(page) = get_user_projects(State(state.clone()), Path(alice.id), Query(ListQueryParams::default()))
+            .await
+            .unwrap();
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0].project.id, owned);
+
+        let result = get_user_projects(State(state), Path(Uuid::new_v4()), Query(ListQueryParams::default())).await;
+        assert!(matches!(result, Err(AppError::UserNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_sole_owner_conflicts() {
+        let state = new_test_db().await;
+        let owner = create_named(&state, "Owner").await;
+        let member = create_named(&state, "Member").await;
+        insert_project_with_members(&state, &[(owner.id, "owner"), (member.id, "viewer")]).await;
+
+        let result = delete_user(State(state.clone()), Path(owner.id)).await;
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+
+        // Nothing was removed
+        let Json(page) = list_users(State(state.clone()), None, Query(ListQueryParams::default())).await.unwrap();
+        let users = page.data;
+        assert_eq!(users.len(), 2);
+        let (memberships,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM project_users")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(memberships, 2);
+    }
 }
@@ -42,6 +42,7 @@ pub mod handlers;
 pub mod middleware;
 pub mod models;
 pub mod routes;
+pub mod server;
 pub mod state;
 
 // Re-export commonly used types
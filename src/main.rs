@@ -1,5 +1,17 @@
-use proyects_api::{routes, state::AppState, middleware::RateLimiter};
+use proyects_api::{
+    error::ErrorDetail,
+    middleware::{API_KEY_HEADER, JwtAuth, RateLimiter, SharedRateLimiter, SqliteRateLimiter},
+    models::pagination::PageOverflow,
+    routes,
+    server::{self, ServerConfig},
+    state::{
+        AppConfig, AppState, PoolConfig, connect_pool, parse_default_rating, parse_language_set, parse_max_page_size, parse_role_page_sizes,
+        retention::{PurgeTask, RetentionConfig},
+    },
+};
 use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
 use tower_http::{
     cors::{AllowOrigin, CorsLayer},
     trace::TraceLayer,
@@ -7,8 +19,11 @@ use tower_http::{
 use axum::{
     Extension,
     http::{
-        header::{AUTHORIZATION, CONTENT_TYPE},
-        HeaderValue, Method,
+        header::{
+            AUTHORIZATION, CONTENT_ENCODING, CONTENT_TYPE, ETAG, IF_NONE_MATCH, IF_UNMODIFIED_SINCE, LINK,
+            LOCATION, WWW_AUTHENTICATE,
+        },
+        HeaderName, HeaderValue, Method,
     },
 };
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -27,19 +42,216 @@ async fn main() {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    // Configure how much detail error responses expose (full in dev, minimal in prod)
+    let error_detail = std::env::var("ERROR_DETAIL")
+        .ok()
+        .and_then(|v| {
+            v.parse::<ErrorDetail>()
+                .map_err(|e| tracing::warn!("{}. Using default 'full'", e))
+                .ok()
+        })
+        .unwrap_or_default();
+
+    tracing::info!("Error detail level: {:?}", error_detail);
+
     // Get database URL from environment or use default
     let database_url = std::env::var("DATABASE_URL")
         .unwrap_or_else(|_| "sqlite:proyects.db?mode=rwc".to_string());
 
     tracing::info!("Connecting to database: {}", database_url);
 
+    // Connection pool sizing and timeouts
+    let pool_defaults = PoolConfig::default();
+    let max_connections = std::env::var("DB_MAX_CONNECTIONS")
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(pool_defaults.max_connections);
+    let min_connections = std::env::var("DB_MIN_CONNECTIONS")
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(pool_defaults.min_connections);
+    let acquire_timeout = std::env::var("DB_ACQUIRE_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|v| *v > 0)
+        .map(Duration::from_secs)
+        .unwrap_or(pool_defaults.acquire_timeout);
+    // 0 keeps idle connections open indefinitely
+    let idle_timeout = match std::env::var("DB_IDLE_TIMEOUT_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+        Some(0) => None,
+        Some(secs) => Some(Duration::from_secs(secs)),
+        None => pool_defaults.idle_timeout,
+    };
+    // SQLite PRAGMAs applied to every pooled connection
+    let foreign_keys = std::env::var("DB_FOREIGN_KEYS")
+        .ok()
+        .and_then(|v| v.parse::<bool>().ok())
+        .unwrap_or(pool_defaults.foreign_keys);
+    let wal = std::env::var("DB_WAL")
+        .ok()
+        .and_then(|v| v.parse::<bool>().ok())
+        .unwrap_or(pool_defaults.wal);
+    let busy_timeout = std::env::var("DB_BUSY_TIMEOUT_MS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(pool_defaults.busy_timeout);
+    let pool_config = PoolConfig {
+        max_connections,
+        min_connections,
+        acquire_timeout,
+        idle_timeout,
+        foreign_keys,
+        wal,
+        busy_timeout,
+    };
+
+    tracing::info!("Database pool: {:?}", pool_config);
+
     // Initialize application state with database connection
-    let state = AppState::new(&database_url)
+    let state = AppState::with_config(&database_url, pool_config.clone())
         .await
         .expect("Failed to initialize database");
 
     tracing::info!("Database initialized successfully");
 
+    // Sample data is opt-in so production databases start empty
+    let seed_data = std::env::var("SEED_DATA")
+        .ok()
+        .and_then(|v| v.parse::<bool>().ok())
+        .unwrap_or(false);
+    if seed_data {
+        state.seed_test_data().await.expect("Failed to seed test data");
+    }
+
+    // Cap how many DB operations a bulk create or import may run concurrently
+    let max_concurrent_db_ops = std::env::var("MAX_CONCURRENT_DB_OPS_PER_REQUEST")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(AppConfig::default().max_concurrent_db_ops);
+
+    // Optional per-role default page sizes, e.g. "admin=50,internal=25"
+    let role_page_sizes = std::env::var("DEFAULT_PAGE_SIZE_BY_ROLE")
+        .ok()
+        .and_then(|v| {
+            parse_role_page_sizes(&v)
+                .map_err(|e| tracing::warn!("{}. Ignoring DEFAULT_PAGE_SIZE_BY_ROLE", e))
+                .ok()
+        })
+        .unwrap_or_default();
+
+    tracing::info!("Max concurrent DB operations per request: {}", max_concurrent_db_ops);
+    // Languages whose projects must carry a rating, e.g. "rust,go" (none by default)
+    let rating_required_languages = std::env::var("RATING_REQUIRED_LANGUAGES")
+        .map(|v| parse_language_set(&v))
+        .unwrap_or_default();
+
+    // Rating of projects with neither reviews nor a manual rating (default: none)
+    let default_rating = std::env::var("DEFAULT_RATING")
+        .ok()
+        .and_then(|v| {
+            parse_default_rating(&v)
+                .map_err(|e| tracing::warn!("{}. Ignoring DEFAULT_RATING", e))
+                .ok()
+        });
+
+    // Optional cap on the total number of projects
+    let max_projects = std::env::var("MAX_PROJECTS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok());
+
+    // Optional cap on client-requested page sizes (default: 100)
+    let max_page_size = std::env::var("MAX_PAGE_SIZE")
+        .ok()
+        .and_then(|v| {
+            parse_max_page_size(&v)
+                .map_err(|e| tracing::warn!("{}. Ignoring MAX_PAGE_SIZE", e))
+                .ok()
+        })
+        .unwrap_or(AppConfig::default().max_page_size);
+
+    // Pages past the last one are served as the last page, or refused with `reject`
+    let page_overflow = std::env::var("PAGE_OVERFLOW")
+        .ok()
+        .and_then(|v| {
+            v.parse::<PageOverflow>()
+                .map_err(|e| tracing::warn!("{}. Using default 'clamp'", e))
+                .ok()
+        })
+        .unwrap_or_default();
+
+    // Largest accepted request body in bytes (default: 1 MiB)
+    let max_body_bytes = std::env::var("MAX_BODY_BYTES")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(AppConfig::default().max_body_bytes);
+
+    // Largest accepted import body in bytes (default: 32 MiB)
+    let max_import_bytes = std::env::var("MAX_IMPORT_BYTES")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(AppConfig::default().max_import_bytes);
+
+    // Seconds a request may take before it is aborted with 504 (default: 30)
+    let request_timeout = std::env::var("REQUEST_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|v| *v > 0)
+        .map(Duration::from_secs)
+        .unwrap_or(AppConfig::default().request_timeout);
+
+    // Whether a repository_url another project uses is refused with 409 or only warned about
+    let reject_duplicate_repository_urls = std::env::var("REJECT_DUPLICATE_REPOSITORY_URLS")
+        .ok()
+        .and_then(|v| v.parse::<bool>().ok())
+        .unwrap_or(AppConfig::default().reject_duplicate_repository_urls);
+
+    // Base URL clients reach the API at, advertised as the OpenAPI server
+    let public_base_url = std::env::var("PUBLIC_BASE_URL")
+        .ok()
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty());
+
+    tracing::info!("Default page sizes by role: {:?}", role_page_sizes);
+    tracing::info!("Languages requiring a rating: {:?}", rating_required_languages);
+    tracing::info!("Default rating: {:?}", default_rating);
+    tracing::info!("Project quota: {:?}", max_projects);
+    tracing::info!("Max page size: {}", max_page_size);
+    tracing::info!("Out-of-range pages: {:?}", page_overflow);
+    tracing::info!("Max request body: {} bytes", max_body_bytes);
+    tracing::info!("Max import body: {} bytes", max_import_bytes);
+    tracing::info!("Request timeout: {:?}", request_timeout);
+    tracing::info!("Reject duplicate repository URLs: {}", reject_duplicate_repository_urls);
+    tracing::info!("Public base URL: {:?}", public_base_url);
+
+    let state = state.with_app_config(AppConfig {
+        max_concurrent_db_ops,
+        role_page_sizes,
+        rating_required_languages,
+        default_rating,
+        max_projects,
+        max_page_size,
+        page_overflow,
+        max_body_bytes,
+        max_import_bytes,
+        request_timeout,
+        reject_duplicate_repository_urls,
+        public_base_url,
+        error_detail,
+    });
+
+    let rerated = state
+        .apply_default_rating()
+        .await
+        .expect("Failed to apply the default rating");
+    if rerated > 0 {
+        tracing::info!("Applied the default rating to {} projects", rerated);
+    }
+
     // Configure CORS with allowed origins from environment
     let allowed_origins = std::env::var("ALLOWED_ORIGINS")
         .unwrap_or_else(|_| "http://localhost:3000,http://localhost:3001".to_string())
@@ -65,13 +277,37 @@ async fn main() {
             Method::GET,
             Method::POST,
             Method::PUT,
+            Method::PATCH,
             Method::DELETE,
             Method::OPTIONS,
         ])
-        .allow_headers([CONTENT_TYPE, AUTHORIZATION])
+        .allow_headers([
+            CONTENT_TYPE,
+            CONTENT_ENCODING,
+            AUTHORIZATION,
+            HeaderName::from_static("x-request-id"),
+            API_KEY_HEADER.clone(),
+            IF_NONE_MATCH,
+            IF_UNMODIFIED_SINCE,
+        ])
+        // Let browser clients see deprecation signals (e.g. the `tech` query alias),
+        // rate limit backoff information, cache validators, page links, created resource locations,
+        // authentication challenges and the request correlation id
+        .expose_headers([
+            ETAG,
+            LINK,
+            LOCATION,
+            WWW_AUTHENTICATE,
+            HeaderName::from_static("deprecation"),
+            HeaderName::from_static("sunset"),
+            HeaderName::from_static("x-ratelimit-limit"),
+            HeaderName::from_static("x-ratelimit-remaining"),
+            HeaderName::from_static("retry-after"),
+            HeaderName::from_static("x-request-id"),
+        ])
         .allow_credentials(true);
 
-    // Configure rate limiting: 100 requests per second with burst of 20
+    // Configure rate limiting: token bucket refilling 100 requests per second, burst of 20
     // This prevents API abuse and protects against DoS attacks
     let rate_limit_per_second = std::env::var("RATE_LIMIT_PER_SECOND")
         .ok()
@@ -83,17 +319,95 @@ async fn main() {
         .and_then(|v| v.parse::<u32>().ok())
         .unwrap_or(20);
 
-    let rate_limiter = RateLimiter::new(rate_limit_per_second, rate_limit_burst);
+    // Writes can be given a tighter burst than reads (defaults to RATE_LIMIT_BURST)
+    let rate_limit_write_burst = std::env::var("RATE_LIMIT_WRITE_BURST")
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(rate_limit_burst);
+
+    // `database` keeps the buckets in the rate_limits table, so limits survive restarts
+    // and are shared by instances on the same database; anything else keeps them in memory
+    let rate_limit_backend = match std::env::var("RATE_LIMIT_BACKEND") {
+        Ok(backend) if backend.eq_ignore_ascii_case("database") => "database",
+        _ => "memory",
+    };
+    let rate_limiter: SharedRateLimiter = if rate_limit_backend == "database" {
+        // Every request writes its bucket, so the limiter gets two connections of its
+        // own rather than competing with handlers for the application pool
+        let limiter_pool = PoolConfig {
+            max_connections: 2,
+            min_connections: 0,
+            ..pool_config
+        };
+        let limiter_db = connect_pool(&database_url, &limiter_pool)
+            .await
+            .expect("Failed to open the rate limit pool");
+        Arc::new(
+            SqliteRateLimiter::new(limiter_db, rate_limit_per_second, rate_limit_burst)
+                .with_write_burst_size(rate_limit_write_burst),
+        )
+    } else {
+        Arc::new(
+            RateLimiter::new(rate_limit_per_second, rate_limit_burst).with_write_burst_size(rate_limit_write_burst),
+        )
+    };
 
     tracing::info!(
-        "Rate limiting configured: {} req/s, burst size: {}",
+        "Rate limiting configured: {} req/s, burst size: {} (writes: {}), {} backend",
         rate_limit_per_second,
-        rate_limit_burst
+        rate_limit_burst,
+        rate_limit_write_burst,
+        rate_limit_backend
     );
 
+    // Bearer-token authentication; without JWT_SECRET the API stays open
+    let jwt_auth = match std::env::var("JWT_SECRET").ok().filter(|secret| !secret.is_empty()) {
+        Some(secret) => {
+            let protect_reads = std::env::var("AUTH_PROTECT_READS")
+                .ok()
+                .and_then(|v| v.parse::<bool>().ok())
+                .unwrap_or(false);
+            tracing::info!("JWT authentication enabled (reads protected: {})", protect_reads);
+            Some(JwtAuth::new(secret.as_bytes()).with_protected_reads(protect_reads))
+        }
+        None => {
+            tracing::warn!("JWT_SECRET is not set; authentication is disabled");
+            None
+        }
+    };
+
+    // Kept to close the pool once the server has stopped
+    let db = state.db.clone();
+
+    // Soft-deleted projects are purged for good once their retention period has passed
+    let retention_defaults = RetentionConfig::default();
+    let retention_config = RetentionConfig {
+        retention: std::env::var("DELETED_PROJECT_RETENTION_DAYS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(|days| Duration::from_secs(days * 24 * 60 * 60))
+            .unwrap_or(retention_defaults.retention),
+        interval: std::env::var("PURGE_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(retention_defaults.interval),
+    };
+    tracing::info!(
+        "Purging soft-deleted projects after {} days, every {}s",
+        retention_config.retention.as_secs() / (24 * 60 * 60),
+        retention_config.interval.as_secs()
+    );
+    let purge_task = PurgeTask::spawn(db.clone(), retention_config);
+
     // Create router with routes and middleware
-    let app = routes::create_router(state)
-        .layer(Extension(rate_limiter))
+    let app = routes::create_router(state);
+    let app = match jwt_auth {
+        Some(jwt_auth) => app.layer(Extension(jwt_auth)),
+        None => app,
+    };
+    let app = routes::with_rate_limiter(app, rate_limiter, error_detail)
         .layer(TraceLayer::new_for_http())
         .layer(cors_layer);
 
@@ -116,6 +430,37 @@ async fn main() {
 
     let addr = SocketAddr::from((host, port));
 
+    // Configure HTTP/2 support and keep-alive/idle timeouts
+    let defaults = ServerConfig::default();
+    let env_secs = |name: &str| {
+        std::env::var(name)
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_secs)
+    };
+
+    let server_config = ServerConfig {
+        http2: std::env::var("HTTP2_ENABLED")
+            .ok()
+            .and_then(|v| v.parse::<bool>().ok())
+            .unwrap_or(defaults.http2),
+        http1_keep_alive: std::env::var("HTTP1_KEEP_ALIVE")
+            .ok()
+            .and_then(|v| v.parse::<bool>().ok())
+            .unwrap_or(defaults.http1_keep_alive),
+        idle_timeout: env_secs("IDLE_TIMEOUT_SECS").unwrap_or(defaults.idle_timeout),
+        // 0 disables HTTP/2 keep-alive pings
+        http2_keep_alive_interval: match env_secs("HTTP2_KEEP_ALIVE_INTERVAL_SECS") {
+            Some(interval) if interval.is_zero() => None,
+            Some(interval) => Some(interval),
+            None => defaults.http2_keep_alive_interval,
+        },
+        http2_keep_alive_timeout: env_secs("HTTP2_KEEP_ALIVE_TIMEOUT_SECS")
+            .unwrap_or(defaults.http2_keep_alive_timeout),
+    };
+
+    tracing::info!("Server configuration: {:?}", server_config);
+
     tracing::info!("Starting server on {}", addr);
 
     // Create TCP listener
@@ -127,10 +472,14 @@ async fn main() {
     tracing::info!("Health check available at http://{}/health", addr);
     tracing::info!("Projects API available at http://{}/projects", addr);
     tracing::info!("Swagger UI available at http://{}/swagger-ui/", addr);
-    tracing::info!("OpenAPI spec available at http://{}/api-docs/openapi.json", addr);
+    tracing::info!("OpenAPI spec available at http://{}/api-docs/v1/openapi.json", addr);
 
-    // Start server
-    axum::serve(listener, app)
+    // Start server; SIGINT/SIGTERM stop accepting connections and let in-flight requests finish
+    server::serve(listener, app, server_config, server::shutdown_signal())
         .await
         .expect("Failed to start server");
+
+    purge_task.shutdown().await;
+    db.close().await;
+    tracing::info!("Server stopped, database pool closed");
 }
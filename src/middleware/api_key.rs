@@ -0,0 +1,186 @@
+use axum::{
+    extract::{Request, State},
+    http::HeaderName,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    error::AppError,
+    extractors::AuthUser,
+    models::ApiKey,
+    state::{AppState, CallerRole},
+};
+
+/// Header carrying a static API key
+pub static API_KEY_HEADER: HeaderName = HeaderName::from_static("x-api-key");
+
+/// Looks up the key, records its use and returns the caller it acts for
+async fn authenticate(state: &AppState, key: &str) -> Result<AuthUser, AppError> {
+    let row: Option<(String, String)> = sqlx::query_as(
+        "UPDATE api_keys SET last_used_at = ? WHERE key_hash = ? RETURNING user_id, role",
+    )
+    .bind(Utc::now())
+    .bind(ApiKey::hash(key))
+    .fetch_optional(&state.db)
+    .await?;
+
+    let (user_id, role) = row.ok_or_else(|| AppError::Unauthorized("Invalid API key".to_string()))?;
+    let user_id = Uuid::parse_str(&user_id).map_err(|e| AppError::InternalError(format!("Invalid API key owner: {}", e)))?;
+    Ok(AuthUser { user_id, role })
+}
+
+/// Middleware authenticating requests that carry an `X-API-Key` header
+///
+/// A known key attaches its user as an [`AuthUser`] (plus a [`CallerRole`]) and
+/// satisfies the bearer-token requirement of [`super::auth_middleware`]; an unknown
+/// key is rejected with `401`. Requests without the header pass through untouched.
+pub async fn api_key_middleware(State(state): State<AppState>, mut request: Request, next: Next) -> Response {
+    let Some(value) = request.headers().get(&API_KEY_HEADER) else {
+        return next.run(request).await;
+    };
+    let Ok(key) = value.to_str().map(|key| key.trim().to_string()) else {
+        return AppError::Unauthorized("Invalid API key".to_string()).into_response();
+    };
+
+    match authenticate(&state, &key).await {
+        Ok(user) => {
+            request.extensions_mut().insert(CallerRole(user.role.clone()));
+            request.extensions_mut().insert(user);
+            next.run(request).await
+        }
+        Err(error) => {
+            tracing::debug!("Rejected API key: {}", error);
+            error.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        middleware::{Claims, JwtAuth},
+        routes::create_router,
+        state::tests::new_test_db,
+    };
+    use axum::{Extension, body::Body, http::{StatusCode, header}};
+    use tower::ServiceExt;
+
+    /// Stores a key for a fresh user and returns `(user_id, plaintext key)`
+    async fn insert_key(state: &AppState, role: &str) -> (Uuid, String) {
+        let user_id = Uuid::new_v4();
+        sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, ?)")
+            .bind(user_id.to_string())
+            .bind("Integrator")
+            .bind(format!("{}@example.com", user_id))
+            .bind(Utc::now())
+            .execute(&state.db)
+            .await
+            .unwrap();
+
+        let (api_key, key) = ApiKey::generate(crate::models::CreateApiKeyRequest {
+            name: "CI".to_string(),
+            user_id,
+            role: role.to_string(),
+        });
+        sqlx::query("INSERT INTO api_keys (id, name, key_hash, user_id, role, created_at) VALUES (?, ?, ?, ?, ?, ?)")
+            .bind(api_key.id.to_string())
+            .bind(&api_key.name)
+            .bind(ApiKey::hash(&key))
+            .bind(user_id.to_string())
+            .bind(&api_key.role)
+            .bind(api_key.created_at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        (user_id, key)
+    }
+
+    fn create_technology(key: Option<&str>) -> Request {
+        let mut builder = Request::builder()
+            .method("POST")
+            .uri("/technologies")
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(key) = key {
+            builder = builder.header(&API_KEY_HEADER, key);
+        }
+        builder.body(Body::from(r#"{"name":"Rust"}"#)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_valid_api_key_replaces_bearer_token() {
+        let state = new_test_db().await;
+        let (_, key) = insert_key(&state, "integration").await;
+        let app = create_router(state.clone()).layer(Extension(JwtAuth::new(b"secret")));
+
+        let response = app.clone().oneshot(create_technology(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app.oneshot(create_technology(Some(&key))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let last_used: Option<chrono::DateTime<Utc>> = sqlx::query_scalar("SELECT last_used_at FROM api_keys")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert!(last_used.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_api_key_is_rejected() {
+        let state = new_test_db().await;
+        insert_key(&state, "integration").await;
+        let app = create_router(state);
+
+        let response = app
+            .clone()
+            .oneshot(create_technology(Some("pak_not-a-real-key")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        // Reads with an unknown key are rejected too
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/technologies")
+                    .header(&API_KEY_HEADER, "pak_not-a-real-key")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_api_key_identity_reaches_handlers() {
+        let state = new_test_db().await;
+        let (user_id, key) = insert_key(&state, "integration").await;
+
+        let app = axum::Router::new()
+            .route(
+                "/whoami",
+                axum::routing::get(|user: AuthUser, claims: Option<Extension<Claims>>| async move {
+                    format!("{} {} {}", user.user_id, user.role, claims.is_some())
+                }),
+            )
+            .layer(axum::middleware::from_fn_with_state(state, api_key_middleware));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/whoami")
+                    .header(&API_KEY_HEADER, key)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, format!("{} integration false", user_id));
+    }
+}
@@ -0,0 +1,297 @@
+use axum::{
+    extract::Request,
+    http::{HeaderMap, HeaderValue, Method, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, errors::ErrorKind};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{error::AppError, extractors::AuthUser, state::CallerRole};
+
+/// Claims carried by an access token
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Claims {
+    /// Id of the authenticated user
+    pub sub: Uuid,
+    /// Caller role (e.g. `admin`), also used for role-based defaults such as page sizes
+    pub role: String,
+    /// Expiry as a Unix timestamp in seconds
+    pub exp: u64,
+}
+
+/// HS256 bearer-token verifier, shared with [`auth_middleware`] as a request extension
+///
+/// Without it in the extensions the middleware lets every request through, so
+/// authentication stays off unless a secret is configured.
+#[derive(Clone)]
+pub struct JwtAuth {
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    validation: Validation,
+    /// Also require a token for reads (GET/HEAD/OPTIONS); health checks stay public
+    protect_reads: bool,
+}
+
+impl JwtAuth {
+    /// Creates a verifier for tokens signed with `secret`; reads stay public
+    pub fn new(secret: &[u8]) -> Self {
+        Self {
+            encoding_key: EncodingKey::from_secret(secret),
+            decoding_key: DecodingKey::from_secret(secret),
+            validation: Validation::new(Algorithm::HS256),
+            protect_reads: false,
+        }
+    }
+
+    /// Sets whether reads require a token too
+    pub fn with_protected_reads(mut self, protect_reads: bool) -> Self {
+        self.protect_reads = protect_reads;
+        self
+    }
+
+    /// Signs `claims` into a token this verifier accepts
+    pub fn issue(&self, claims: &Claims) -> Result<String, AppError> {
+        jsonwebtoken::encode(&Header::new(Algorithm::HS256), claims, &self.encoding_key)
+            .map_err(|e| AppError::InternalError(format!("Failed to sign token: {}", e)))
+    }
+
+    /// Checks the signature and expiry of `token` and returns its claims
+    pub fn verify(&self, token: &str) -> Result<Claims, AppError> {
+        jsonwebtoken::decode::<Claims>(token, &self.decoding_key, &self.validation)
+            .map(|data| data.claims)
+            .map_err(|e| match e.kind() {
+                ErrorKind::ExpiredSignature => AppError::Unauthorized("Token has expired".to_string()),
+                _ => AppError::Unauthorized("Invalid token".to_string()),
+            })
+    }
+
+    /// Whether a request must carry a token
+    fn requires_token(&self, method: &Method, path: &str) -> bool {
+        if path == "/health" || path.starts_with("/health/") {
+            return false;
+        }
+        match *method {
+            Method::GET | Method::HEAD | Method::OPTIONS => self.protect_reads,
+            _ => true,
+        }
+    }
+}
+
+/// Token from an `Authorization: Bearer <token>` header, `None` when the header is absent
+fn bearer_token(headers: &HeaderMap) -> Result<Option<&str>, AppError> {
+    let Some(value) = headers.get(header::AUTHORIZATION) else {
+        return Ok(None);
+    };
+
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(Some)
+        .ok_or_else(|| AppError::Unauthorized("Authorization header must be 'Bearer <token>'".to_string()))
+}
+
+/// 401 response carrying the `WWW-Authenticate` challenge
+fn unauthorized(error: AppError) -> Response {
+    let mut response = error.into_response();
+    response
+        .headers_mut()
+        .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+    response
+}
+
+/// Middleware validating `Authorization: Bearer <jwt>` headers
+///
+/// Writes always need a valid token; reads only when [`JwtAuth::with_protected_reads`]
+/// is set, and `/health` never does. A token sent to a public route must still be
+/// valid, while a request already authenticated by an API key needs no token. The
+/// decoded [`Claims`], an [`AuthUser`] and a [`CallerRole`] are inserted into the
+/// request extensions for handlers.
+pub async fn auth_middleware(mut request: Request, next: Next) -> Response {
+    let Some(auth) = request.extensions().get::<JwtAuth>().cloned() else {
+        return next.run(request).await;
+    };
+
+    let token = match bearer_token(request.headers()) {
+        Ok(token) => token.map(str::to_string),
+        Err(error) => return unauthorized(error),
+    };
+
+    match token {
+        Some(token) => match auth.verify(&token) {
+            Ok(claims) => {
+                request.extensions_mut().insert(CallerRole(claims.role.clone()));
+                request.extensions_mut().insert(AuthUser::from(&claims));
+                request.extensions_mut().insert(claims);
+            }
+            Err(error) => {
+                tracing::debug!("Rejected bearer token: {}", error);
+                return unauthorized(error);
+            }
+        },
+        // Already authenticated by an API key
+        None if request.extensions().get::<AuthUser>().is_some() => {}
+        None if auth.requires_token(request.method(), request.uri().path()) => {
+            return unauthorized(AppError::Unauthorized("Missing bearer token".to_string()));
+        }
+        None => {}
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{routes::create_router, state::tests::new_test_db};
+    use axum::{Extension, Router, body::Body, http::StatusCode, middleware, routing::get};
+    use tower::ServiceExt;
+
+    const SECRET: &[u8] = b"test-secret";
+
+    fn claims(expires_in: i64) -> Claims {
+        Claims {
+            sub: Uuid::new_v4(),
+            role: "admin".to_string(),
+            exp: (chrono::Utc::now().timestamp() + expires_in) as u64,
+        }
+    }
+
+    fn create_technology(token: Option<&str>) -> Request {
+        let mut builder = Request::builder()
+            .method("POST")
+            .uri("/technologies")
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(token) = token {
+            builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        builder.body(Body::from(r#"{"name":"Rust"}"#)).unwrap()
+    }
+
+    async fn app(auth: JwtAuth) -> Router {
+        create_router(new_test_db().await).layer(Extension(auth))
+    }
+
+    #[tokio::test]
+    async fn test_valid_token_allows_writes() {
+        let auth = JwtAuth::new(SECRET);
+        let token = auth.issue(&claims(3600)).unwrap();
+
+        let response = app(auth).await.oneshot(create_technology(Some(&token))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_missing_token_rejects_writes_only() {
+        let app = app(JwtAuth::new(SECRET)).await;
+
+        let response = app.clone().oneshot(create_technology(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+
+        let response = app
+            .oneshot(Request::builder().uri("/technologies").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_protected_reads_keep_health_public() {
+        let app = app(JwtAuth::new(SECRET).with_protected_reads(true)).await;
+
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri("/technologies").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_expired_token_is_rejected() {
+        let auth = JwtAuth::new(SECRET);
+        let token = auth.issue(&claims(-3600)).unwrap();
+        assert!(matches!(auth.verify(&token), Err(AppError::Unauthorized(msg)) if msg == "Token has expired"));
+
+        let response = app(auth).await.oneshot(create_technology(Some(&token))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_tampered_token_is_rejected() {
+        let auth = JwtAuth::new(SECRET);
+        let token = auth.issue(&claims(3600)).unwrap();
+
+        // Payload swapped for one claiming another role, original signature kept
+        let mut parts: Vec<String> = token.split('.').map(str::to_string).collect();
+        let forged = JwtAuth::new(b"other-secret")
+            .issue(&Claims {
+                role: "superuser".to_string(),
+                ..claims(3600)
+            })
+            .unwrap();
+        parts[1] = forged.split('.').nth(1).unwrap().to_string();
+        let tampered = parts.join(".");
+
+        for token in [tampered.as_str(), forged.as_str(), "not-a-jwt"] {
+            let response = app(auth.clone()).await.oneshot(create_technology(Some(token))).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "token {} was accepted", token);
+        }
+
+        // A non-bearer scheme is rejected as well, even on public reads
+        let response = app(auth)
+            .await
+            .oneshot(
+                Request::builder()
+                    .uri("/technologies")
+                    .header(header::AUTHORIZATION, "Basic dXNlcjpwYXNz")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_claims_reach_handlers() {
+        let auth = JwtAuth::new(SECRET);
+        let claims = claims(3600);
+        let token = auth.issue(&claims).unwrap();
+
+        let app = Router::new()
+            .route(
+                "/whoami",
+                get(|user: AuthUser, Extension(role): Extension<CallerRole>| async move {
+                    format!("{} {} {}", user.user_id, user.role, role.0)
+                }),
+            )
+            .layer(middleware::from_fn(auth_middleware))
+            .layer(Extension(auth));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/whoami")
+                    .header(header::AUTHORIZATION, format!("Bearer {}", token))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, format!("{} admin admin", claims.sub));
+    }
+}
@@ -0,0 +1,150 @@
+use axum::{
+    body::{Body, to_bytes},
+    extract::{Request, State},
+    http::header::CONTENT_LENGTH,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::error::AppError;
+
+/// Default maximum request body size in bytes (1 MiB)
+pub const DEFAULT_MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Default maximum body size of `POST /projects/import` in bytes (32 MiB)
+pub const DEFAULT_MAX_IMPORT_BYTES: usize = 32 * 1024 * 1024;
+
+/// Largest request body accepted, in bytes
+///
+/// Inserted into the request extensions by [`body_limit_middleware`] so gzip
+/// bodies are held to the same limit once decompressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyLimit(pub usize);
+
+impl Default for BodyLimit {
+    fn default() -> Self {
+        Self(DEFAULT_MAX_BODY_BYTES)
+    }
+}
+
+/// Middleware rejecting request bodies larger than the [`BodyLimit`] with `413 Payload Too Large`
+///
+/// A `Content-Length` over the limit is refused before anything is read; bodies
+/// without one (chunked) are read up to the limit and refused once they pass it,
+/// so an oversized payload never reaches a JSON extractor.
+pub async fn body_limit_middleware(State(limit): State<BodyLimit>, request: Request, next: Next) -> Response {
+    let too_large = || AppError::PayloadTooLarge(format!("Request body exceeds {} bytes", limit.0)).into_response();
+
+    let declared = request
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok());
+    if declared.is_some_and(|length| length > limit.0 as u64) {
+        return too_large();
+    }
+
+    let (mut parts, body) = request.into_parts();
+    let bytes = match to_bytes(body, limit.0).await {
+        Ok(bytes) => bytes,
+        Err(_) => return too_large(),
+    };
+
+    parts.extensions.insert(limit);
+    next.run(Request::from_parts(parts, Body::from(bytes))).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{routes::create_router, state::AppConfig, state::tests::new_test_db};
+    use axum::http::StatusCode;
+    use futures::stream;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    fn project_body(description_len: usize) -> String {
+        json!({
+            "name": "Large",
+            "description": "x".repeat(description_len),
+            "repository_url": "https://github.com/test/large",
+            "language": "Rust"
+        })
+        .to_string()
+    }
+
+    fn create_project(body: Body) -> Request {
+        Request::builder()
+            .method("POST")
+            .uri("/projects")
+            .header("content-type", "application/json")
+            .body(body)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_is_rejected() {
+        let app = create_router(new_test_db().await);
+
+        let response = app
+            .oneshot(create_project(Body::from(project_body(DEFAULT_MAX_BODY_BYTES))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "PAYLOAD_TOO_LARGE");
+    }
+
+    #[tokio::test]
+    async fn test_configured_limit_applies_without_content_length() {
+        let state = new_test_db().await.with_app_config(AppConfig {
+            max_body_bytes: 256,
+            ..Default::default()
+        });
+        let app = create_router(state);
+
+        // Streamed in chunks, so there is no Content-Length to check up front
+        let chunks: Vec<Result<String, std::io::Error>> = vec![Ok(project_body(200)), Ok(project_body(200))];
+        let response = app
+            .clone()
+            .oneshot(create_project(Body::from_stream(stream::iter(chunks))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let response = app.oneshot(create_project(Body::from(project_body(10)))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_import_has_its_own_limit() {
+        let state = new_test_db().await.with_app_config(AppConfig {
+            max_body_bytes: 256,
+            max_import_bytes: 4096,
+            ..Default::default()
+        });
+        let app = create_router(state);
+
+        // An empty export padded past the general limit is still a valid import
+        let export = format!("[{}]", " ".repeat(1024));
+        let import = |body: String| {
+            Request::builder()
+                .method("POST")
+                .uri("/projects/import")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .unwrap()
+        };
+        let response = app.clone().oneshot(import(export)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app.clone().oneshot(import(format!("[{}]", " ".repeat(4096)))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        // Other routes keep the general limit
+        let response = app.oneshot(create_project(Body::from(project_body(1024)))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -0,0 +1,144 @@
+use axum::{
+    body::{Body, to_bytes},
+    extract::Request,
+    http::header::{CONTENT_ENCODING, CONTENT_LENGTH},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use flate2::read::GzDecoder;
+use std::io::Read;
+
+use super::body_limit::BodyLimit;
+use crate::error::AppError;
+
+/// Middleware that transparently decompresses `Content-Encoding: gzip` request bodies
+///
+/// The decompressed size is capped at the request's [`BodyLimit`] (default
+/// [`super::DEFAULT_MAX_BODY_BYTES`]) so a small compressed payload cannot expand into
+/// an unbounded buffer (zip bomb). Requests without a gzip encoding are passed
+/// through untouched.
+pub async fn decompress_request_middleware(request: Request, next: Next) -> Response {
+    let is_gzip = request
+        .headers()
+        .get(CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().eq_ignore_ascii_case("gzip") || v.trim().eq_ignore_ascii_case("x-gzip"))
+        .unwrap_or(false);
+
+    if !is_gzip {
+        return next.run(request).await;
+    }
+
+    let limit = request.extensions().get::<BodyLimit>().copied().unwrap_or_default().0;
+    let (mut parts, body) = request.into_parts();
+
+    // The compressed body can never legitimately be larger than the decompressed limit
+    let compressed = match to_bytes(body, limit).await {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return AppError::PayloadTooLarge(format!(
+                "Request body exceeds {} bytes",
+                limit
+            ))
+            .into_response();
+        }
+    };
+
+    let decompressed = match gunzip_limited(&compressed, limit) {
+        Ok(bytes) => bytes,
+        Err(error) => return error.into_response(),
+    };
+
+    parts.headers.remove(CONTENT_ENCODING);
+    parts.headers.remove(CONTENT_LENGTH);
+
+    next.run(Request::from_parts(parts, Body::from(decompressed))).await
+}
+
+/// Decompresses a gzip payload, refusing to produce more than `limit` bytes
+fn gunzip_limited(compressed: &[u8], limit: usize) -> Result<Vec<u8>, AppError> {
+    let mut decompressed = Vec::new();
+    GzDecoder::new(compressed)
+        .take(limit as u64 + 1)
+        .read_to_end(&mut decompressed)
+        .map_err(|e| AppError::ValidationError(format!("Invalid gzip request body: {}", e)))?;
+
+    if decompressed.len() > limit {
+        return Err(AppError::PayloadTooLarge(format!(
+            "Decompressed request body exceeds {} bytes",
+            limit
+        )));
+    }
+
+    Ok(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{middleware::DEFAULT_MAX_BODY_BYTES, routes::create_router, state::tests::new_test_db};
+    use axum::http::StatusCode;
+    use flate2::{Compression, write::GzEncoder};
+    use serde_json::json;
+    use std::io::Write;
+    use tower::ServiceExt;
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_gzip_project_create_is_accepted() {
+        let state = new_test_db().await;
+        let app = create_router(state);
+
+        let body = json!({
+            "name": "Compressed Project",
+            "description": "Sent with Content-Encoding: gzip",
+            "repository_url": "https://github.com/test/gzip",
+            "language": "Rust"
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/projects")
+                    .header("content-type", "application/json")
+                    .header("content-encoding", "gzip")
+                    .body(Body::from(gzip(body.to_string().as_bytes())))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_gzip_bomb_is_rejected() {
+        let state = new_test_db().await;
+        let app = create_router(state);
+
+        // Compresses to a few KiB but expands past the limit
+        let bomb = gzip(&vec![b' '; DEFAULT_MAX_BODY_BYTES * 4]);
+        assert!(bomb.len() < DEFAULT_MAX_BODY_BYTES);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/projects")
+                    .header("content-type", "application/json")
+                    .header("content-encoding", "gzip")
+                    .body(Body::from(bomb))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -0,0 +1,175 @@
+use axum::{
+    Json,
+    body::{HttpBody, to_bytes},
+    extract::Request,
+    http::{
+        HeaderMap,
+        header::{CONTENT_LENGTH, CONTENT_TYPE},
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::{Value, json};
+
+use crate::error::AppError;
+
+/// Largest response body the envelope middleware buffers (1 MiB); larger ones are left bare
+const MAX_ENVELOPE_BYTES: usize = 1024 * 1024;
+
+/// Whether the response is plain `application/json` (parameters such as `charset` ignored)
+fn is_plain_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .is_some_and(|media_type| media_type.trim().eq_ignore_ascii_case("application/json"))
+}
+
+/// Middleware that wraps single-resource responses as `{ "data": ... }` on request
+///
+/// Enabled per request with `?envelope=true`, so the shape matches the `data`
+/// key of paginated list responses. Without the flag (the default) the bare
+/// object is returned for compatibility. Error responses are never wrapped, and
+/// neither are other media types (a JSON:API document already has its own `data`)
+/// or bodies over `MAX_ENVELOPE_BYTES`.
+pub async fn envelope_middleware(request: Request, next: Next) -> Response {
+    let wants_envelope = request
+        .uri()
+        .query()
+        .map(|query| {
+            query
+                .split('&')
+                .any(|pair| pair.eq_ignore_ascii_case("envelope=true"))
+        })
+        .unwrap_or(false);
+
+    let response = next.run(request).await;
+
+    if !wants_envelope
+        || !response.status().is_success()
+        || !is_plain_json(response.headers())
+        || response.body().size_hint().lower() > MAX_ENVELOPE_BYTES as u64
+    {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+
+    let bytes = match to_bytes(body, MAX_ENVELOPE_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            return AppError::InternalError(format!("Failed to read response body: {}", e))
+                .into_response();
+        }
+    };
+
+    let data: Value = match serde_json::from_slice(&bytes) {
+        Ok(value) => value,
+        Err(e) => {
+            return AppError::InternalError(format!("Response body is not JSON: {}", e))
+                .into_response();
+        }
+    };
+
+    parts.headers.remove(CONTENT_LENGTH);
+    (parts, Json(json!({ "data": data }))).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{models::jsonapi::JSON_API_MEDIA_TYPE, routes::create_router, state::tests::new_test_db};
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    async fn create_project(app: &axum::Router, uri: &str) -> (StatusCode, Value) {
+        let body = json!({
+            "name": "Envelope Project",
+            "description": "Checks the response envelope",
+            "repository_url": "https://github.com/test/envelope",
+            "language": "Rust"
+        });
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_bare_shape_by_default() {
+        let app = create_router(new_test_db().await);
+
+        let (status, created) = create_project(&app, "/projects").await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(created.get("data").is_none());
+        assert_eq!(created["name"], "Envelope Project");
+    }
+
+    #[tokio::test]
+    async fn test_enveloped_shape_when_requested() {
+        let app = create_router(new_test_db().await);
+
+        let (status, created) = create_project(&app, "/projects?envelope=true").await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["data"]["name"], "Envelope Project");
+
+        let id = created["data"]["id"].as_str().unwrap();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/projects/{}?envelope=true", id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let fetched: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(fetched["data"]["id"], id);
+        assert!(fetched["data"]["technologies"].is_array());
+    }
+
+    #[tokio::test]
+    async fn test_json_api_document_is_not_wrapped() {
+        let app = create_router(new_test_db().await);
+
+        let (_, created) = create_project(&app, "/projects").await;
+        let id = created["id"].as_str().unwrap();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/projects/{}?envelope=true", id))
+                    .header("accept", JSON_API_MEDIA_TYPE)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], JSON_API_MEDIA_TYPE);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let document: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(document["data"]["id"], id);
+        assert_eq!(document["data"]["type"], "projects");
+        assert!(document["data"].get("data").is_none());
+    }
+}
@@ -0,0 +1,75 @@
+use axum::{
+    Json,
+    extract::{Request, State},
+    http::header::CONTENT_LENGTH,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::error::{ErrorDetail, ErrorSummary};
+
+/// Middleware trimming error responses to the configured [`ErrorDetail`]
+///
+/// At the `Minimal` level the body of any response built from an `AppError` is
+/// replaced by its code and the generic message for that code; status and other
+/// headers are kept. `Full` responses pass through untouched.
+pub async fn error_detail_middleware(State(detail): State<ErrorDetail>, request: Request, next: Next) -> Response {
+    let response = next.run(request).await;
+    if detail == ErrorDetail::Full {
+        return response;
+    }
+    let Some(summary) = response.extensions().get::<ErrorSummary>().copied() else {
+        return response;
+    };
+
+    let (mut parts, _) = response.into_parts();
+    parts.headers.remove(CONTENT_LENGTH);
+    let (_, body) = Json(summary.body()).into_response().into_parts();
+    Response::from_parts(parts, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ErrorDetail,
+        routes::create_router,
+        state::{AppConfig, tests::new_test_db},
+    };
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn missing_project(error_detail: ErrorDetail) -> (StatusCode, Value) {
+        let state = new_test_db().await.with_app_config(AppConfig {
+            error_detail,
+            ..Default::default()
+        });
+        let response = create_router(state)
+            .oneshot(
+                Request::builder()
+                    .uri("/projects/00000000-0000-0000-0000-000000000042")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_minimal_detail_hides_client_error_messages() {
+        let (status, body) = missing_project(ErrorDetail::Full).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(body["error"].as_str().unwrap().contains("00000000-0000-0000-0000-000000000042"));
+
+        let (status, body) = missing_project(ErrorDetail::Minimal).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "PROJECT_NOT_FOUND");
+        assert_eq!(body["error"], "Project not found");
+    }
+}
@@ -0,0 +1,153 @@
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Instant;
+
+/// Upper bounds (in seconds) of the handler latency histogram buckets
+const LATENCY_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Minimal in-process metrics registry rendered in Prometheus text format
+///
+/// Requests are labelled with their route template (e.g. `/projects/{id}`) rather
+/// than the concrete path, so label cardinality stays bounded.
+#[derive(Clone, Default)]
+pub struct Metrics {
+    inner: Arc<Mutex<MetricsInner>>,
+}
+
+#[derive(Default)]
+struct MetricsInner {
+    /// (method, route, status) -> request count
+    requests: BTreeMap<(String, String, u16), u64>,
+    /// (method, route) -> latency histogram
+    latencies: BTreeMap<(String, String), Histogram>,
+}
+
+#[derive(Default)]
+struct Histogram {
+    /// Cumulative counts per entry of `LATENCY_BUCKETS`
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        for (bucket, bound) in self.buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if seconds <= bound {
+                *bucket += 1;
+            }
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+impl Metrics {
+    /// Records one finished request
+    pub fn record(&self, method: &str, route: &str, status: u16, seconds: f64) {
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        *inner
+            .requests
+            .entry((method.to_string(), route.to_string(), status))
+            .or_default() += 1;
+        inner
+            .latencies
+            .entry((method.to_string(), route.to_string()))
+            .or_default()
+            .observe(seconds);
+    }
+
+    /// Renders all metrics in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut out = String::new();
+
+        out.push_str("# HELP http_requests_total Total HTTP requests by route and status\n");
+        out.push_str("# TYPE http_requests_total counter\n");
+        for ((method, route, status), count) in &inner.requests {
+            let _ = writeln!(
+                out,
+                "http_requests_total{{method=\"{}\",route=\"{}\",status=\"{}\"}} {}",
+                method, route, status, count
+            );
+        }
+
+        out.push_str("# HELP http_request_duration_seconds Handler latency by route\n");
+        out.push_str("# TYPE http_request_duration_seconds histogram\n");
+        for ((method, route), histogram) in &inner.latencies {
+            let labels = format!("method=\"{}\",route=\"{}\"", method, route);
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(histogram.buckets) {
+                let _ = writeln!(
+                    out,
+                    "http_request_duration_seconds_bucket{{{},le=\"{}\"}} {}",
+                    labels, bound, count
+                );
+            }
+            let _ = writeln!(
+                out,
+                "http_request_duration_seconds_bucket{{{},le=\"+Inf\"}} {}",
+                labels, histogram.count
+            );
+            let _ = writeln!(out, "http_request_duration_seconds_sum{{{}}} {}", labels, histogram.sum);
+            let _ = writeln!(out, "http_request_duration_seconds_count{{{}}} {}", labels, histogram.count);
+        }
+
+        out
+    }
+}
+
+/// Middleware recording request counts and latency into the `Metrics` extension
+pub async fn metrics_middleware(request: Request, next: Next) -> Response {
+    let metrics = request
+        .extensions()
+        .get::<Metrics>()
+        .cloned()
+        .expect("Metrics not found in extensions");
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let started = Instant::now();
+    let response = next.run(request).await;
+
+    metrics.record(
+        &method,
+        &route,
+        response.status().as_u16(),
+        started.elapsed().as_secs_f64(),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_counters_and_histogram() {
+        let metrics = Metrics::default();
+        metrics.record("GET", "/projects", 200, 0.003);
+        metrics.record("GET", "/projects", 200, 0.2);
+        metrics.record("GET", "/projects", 400, 0.001);
+
+        let text = metrics.render();
+        assert!(text.contains("http_requests_total{method=\"GET\",route=\"/projects\",status=\"200\"} 2"));
+        assert!(text.contains("http_requests_total{method=\"GET\",route=\"/projects\",status=\"400\"} 1"));
+        assert!(text.contains(
+            "http_request_duration_seconds_bucket{method=\"GET\",route=\"/projects\",le=\"0.005\"} 2"
+        ));
+        assert!(text.contains(
+            "http_request_duration_seconds_bucket{method=\"GET\",route=\"/projects\",le=\"+Inf\"} 3"
+        ));
+        assert!(text.contains("http_request_duration_seconds_count{method=\"GET\",route=\"/projects\"} 3"));
+    }
+}
@@ -1,3 +1,23 @@
+pub mod api_key;
+pub mod auth;
+pub mod body_limit;
+pub mod decompression;
+pub mod envelope;
+pub mod error_detail;
+pub mod metrics;
 pub mod rate_limit;
+pub mod request_id;
+pub mod timeout;
 
-pub use rate_limit::{rate_limit_middleware, RateLimiter};
+pub use api_key::{API_KEY_HEADER, api_key_middleware};
+pub use auth::{Claims, JwtAuth, auth_middleware};
+pub use body_limit::{BodyLimit, DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_IMPORT_BYTES, body_limit_middleware};
+pub use decompression::decompress_request_middleware;
+pub use envelope::envelope_middleware;
+pub use error_detail::error_detail_middleware;
+pub use metrics::{Metrics, metrics_middleware};
+pub use rate_limit::{
+    RateLimitBackend, RateLimitStatus, RateLimiter, SharedRateLimiter, SqliteRateLimiter, rate_limit_middleware,
+};
+pub use request_id::{RequestId, request_id_middleware};
+pub use timeout::{DEFAULT_REQUEST_TIMEOUT, RequestTimeout, timeout_middleware};
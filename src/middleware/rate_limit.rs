@@ -1,86 +1,350 @@
 use axum::{
     extract::Request,
-    http::StatusCode,
+    http::{HeaderValue, Method},
     middleware::Next,
     response::{IntoResponse, Response},
 };
+use futures::future::{BoxFuture, FutureExt};
+use sqlx::SqlitePool;
 use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::net::IpAddr;
-use std::sync::{Arc, Mutex};
-use std::time::{Duration, Instant};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-/// Simple rate limiter based on IP address
-/// Tracks requests per IP and enforces limits
+use crate::error::AppError;
+
+/// Idle buckets are forgotten after this long (they have refilled by then)
+const IDLE_BUCKET_TTL: Duration = Duration::from_secs(60);
+
+/// Where token buckets are kept; the middleware works against any backend
+///
+/// [`RateLimiter`] keeps them in process memory, [`SqliteRateLimiter`] in the
+/// database so they survive restarts and are shared between instances. Another
+/// shared store (e.g. Redis) only needs to implement this trait.
+pub trait RateLimitBackend: Send + Sync {
+    /// Spends a token for a request with `method` from `ip` and reports the bucket state
+    ///
+    /// GET, HEAD and OPTIONS count against the read bucket, every other method
+    /// against the write bucket.
+    fn check(&self, ip: IpAddr, method: &Method) -> BoxFuture<'_, RateLimitStatus>;
+}
+
+/// The rate limit backend in use, as found in the request extensions by [`rate_limit_middleware`]
+pub type SharedRateLimiter = Arc<dyn RateLimitBackend>;
+
+/// Token-bucket rate limiter keyed by IP address
+///
+/// Each bucket holds up to `burst_size` tokens and refills at `per_second` tokens
+/// per second; a request spends one token. Sustained traffic at `per_second` is
+/// therefore never dropped, while short spikes are capped at the burst size.
 #[derive(Clone)]
 pub struct RateLimiter {
-    /// Requests per second allowed per IP
-    #[allow(dead_code)]
+    /// Sustained requests per second allowed per IP (bucket refill rate)
     per_second: u64,
     /// Burst size for short spikes
     burst_size: u32,
-    /// State tracking requests per IP
-    state: Arc<Mutex<RateLimiterState>>,
+    /// Burst size for write requests (POST/PUT/PATCH/DELETE)
+    write_burst_size: u32,
+    /// State tracking requests per IP, split into independently locked shards
+    shards: Arc<[Mutex<RateLimiterState>]>,
+}
+
+/// Requests are limited separately for reads and writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MethodClass {
+    Read,
+    Write,
+}
+
+impl MethodClass {
+    fn of(method: &Method) -> Self {
+        match *method {
+            Method::GET | Method::HEAD | Method::OPTIONS => MethodClass::Read,
+            _ => MethodClass::Write,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            MethodClass::Read => "read",
+            MethodClass::Write => "write",
+        }
+    }
+}
+
+/// Outcome of a rate limit check, used to fill the `X-RateLimit-*` headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Whether the request may proceed
+    pub allowed: bool,
+    /// Burst size of the bucket the request counted against
+    pub limit: u32,
+    /// Whole tokens left in the bucket after this request
+    pub remaining: u32,
+    /// Seconds until at least one token is available (0 if one already is)
+    pub retry_after_secs: u64,
 }
 
+impl RateLimitStatus {
+    /// Status after a request left `tokens` in a bucket of `limit` refilling at `per_second`
+    fn new(allowed: bool, limit: u32, tokens: f64, per_second: u64) -> Self {
+        // A limiter that never refills still reports a finite backoff
+        let deficit = (1.0 - tokens).max(0.0);
+        let retry_after_secs = (deficit / per_second.max(1) as f64).ceil() as u64;
+
+        Self {
+            allowed,
+            limit,
+            remaining: tokens.floor() as u32,
+            retry_after_secs,
+        }
+    }
+}
+
+/// Number of independently locked shards; an IP always maps to the same shard
+const SHARD_COUNT: usize = 16;
+
+/// One shard of the limiter state
 struct RateLimiterState {
-    /// Map of IP -> request tracking
-    requests: HashMap<IpAddr, RequestTracker>,
+    /// Map of (IP, method class) -> request tracking
+    requests: HashMap<(IpAddr, MethodClass), RequestTracker>,
     /// Last cleanup time
     last_cleanup: Instant,
 }
 
 struct RequestTracker {
-    /// Timestamps of recent requests
-    requests: Vec<Instant>,
+    /// Tokens currently available (fractional between refills)
+    tokens: f64,
+    /// When `tokens` was last topped up
+    last_refill: Instant,
     /// Last request time
     last_request: Instant,
 }
 
 impl RateLimiter {
     /// Create a new rate limiter
+    ///
+    /// Writes share the same burst size until `with_write_burst_size` is used.
     pub fn new(per_second: u64, burst_size: u32) -> Self {
         Self {
             per_second,
             burst_size,
-            state: Arc::new(Mutex::new(RateLimiterState {
-                requests: HashMap::new(),
-                last_cleanup: Instant::now(),
-            })),
+            write_burst_size: burst_size,
+            shards: (0..SHARD_COUNT)
+                .map(|_| {
+                    Mutex::new(RateLimiterState {
+                        requests: HashMap::new(),
+                        last_cleanup: Instant::now(),
+                    })
+                })
+                .collect(),
         }
     }
 
+    /// Use a separate (typically tighter) burst size for write requests
+    pub fn with_write_burst_size(mut self, write_burst_size: u32) -> Self {
+        self.write_burst_size = write_burst_size;
+        self
+    }
+
     /// Check if a request from this IP is allowed
+    ///
+    /// Counts against the read bucket; use `check_rate_limit_for` to apply the
+    /// write limit to mutating requests.
     pub fn check_rate_limit(&self, ip: IpAddr) -> bool {
-        let mut state = self.state.lock().unwrap();
+        self.check(ip, MethodClass::Read)
+    }
+
+    /// Check if a request with this method from this IP is allowed
+    ///
+    /// GET, HEAD and OPTIONS count against the read bucket, every other method
+    /// against the write bucket.
+    pub fn check_rate_limit_for(&self, ip: IpAddr, method: &Method) -> bool {
+        self.check_rate_limit_status(ip, method).allowed
+    }
+
+    /// Like `check_rate_limit_for`, but also reports the remaining tokens and
+    /// how long until the bucket refills
+    pub fn check_rate_limit_status(&self, ip: IpAddr, method: &Method) -> RateLimitStatus {
+        self.check_at(ip, MethodClass::of(method), Instant::now())
+    }
+
+    fn check(&self, ip: IpAddr, class: MethodClass) -> bool {
+        self.check_at(ip, class, Instant::now()).allowed
+    }
 
-        // Clean up old entries every 60 seconds
-        if state.last_cleanup.elapsed() > Duration::from_secs(60) {
+    /// Spends a token from the `(ip, class)` bucket as of `now`
+    fn check_at(&self, ip: IpAddr, class: MethodClass, now: Instant) -> RateLimitStatus {
+        let limit = match class {
+            MethodClass::Read => self.burst_size,
+            MethodClass::Write => self.write_burst_size,
+        };
+        let capacity = f64::from(limit);
+
+        let mut hasher = DefaultHasher::new();
+        ip.hash(&mut hasher);
+        let shard = &self.shards[hasher.finish() as usize % SHARD_COUNT];
+
+        // A panic while holding the lock cannot leave a bucket half-updated in a
+        // way that matters, so a poisoned shard is simply reused
+        let mut state = shard.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Clean up this shard's old entries every 60 seconds
+        if now.saturating_duration_since(state.last_cleanup) > IDLE_BUCKET_TTL {
             state.requests.retain(|_, tracker| {
-                tracker.last_request.elapsed() < Duration::from_secs(60)
+                now.saturating_duration_since(tracker.last_request) < IDLE_BUCKET_TTL
             });
-            state.last_cleanup = Instant::now();
+            state.last_cleanup = now;
         }
 
-        let now = Instant::now();
-        let one_second_ago = now - Duration::from_secs(1);
-
-        // Get or create tracker for this IP
-        let tracker = state.requests.entry(ip).or_insert_with(|| RequestTracker {
-            requests: Vec::new(),
+        // New clients start with a full bucket
+        let tracker = state.requests.entry((ip, class)).or_insert_with(|| RequestTracker {
+            tokens: capacity,
+            last_refill: now,
             last_request: now,
         });
 
-        // Remove requests older than 1 second
-        tracker.requests.retain(|&time| time > one_second_ago);
+        // Refill for the time elapsed since the last request, up to the burst size
+        let elapsed = now.saturating_duration_since(tracker.last_refill).as_secs_f64();
+        tracker.tokens = (tracker.tokens + elapsed * self.per_second as f64).min(capacity);
+        tracker.last_refill = now;
+        tracker.last_request = now;
 
-        // Check if we're within limits
-        if tracker.requests.len() < self.burst_size as usize {
-            tracker.requests.push(now);
-            tracker.last_request = now;
-            true
-        } else {
-            false
+        let allowed = tracker.tokens >= 1.0;
+        if allowed {
+            tracker.tokens -= 1.0;
         }
+
+        RateLimitStatus::new(allowed, limit, tracker.tokens, self.per_second)
+    }
+}
+
+impl RateLimitBackend for RateLimiter {
+    fn check(&self, ip: IpAddr, method: &Method) -> BoxFuture<'_, RateLimitStatus> {
+        futures::future::ready(self.check_rate_limit_status(ip, method)).boxed()
+    }
+}
+
+/// Token-bucket rate limiter keeping its buckets in the `rate_limits` table
+///
+/// Same limits as [`RateLimiter`], but the buckets survive restarts and are shared
+/// by every instance using the database. Each check is one upsert that refills,
+/// spends and reports the bucket atomically, so concurrent requests, even from
+/// other instances, never overdraw it.
+///
+/// Every request, reads included, costs a write, and SQLite has a single writer:
+/// under load the limiter queues behind (and in front of) the API's own writes.
+/// Give it a small pool of its own so it never takes connections from handlers,
+/// and prefer the in-memory backend unless limits must be shared or persisted.
+#[derive(Clone)]
+pub struct SqliteRateLimiter {
+    db: SqlitePool,
+    /// Sustained requests per second allowed per IP (bucket refill rate)
+    per_second: u64,
+    /// Burst size for short spikes
+    burst_size: u32,
+    /// Burst size for write requests (POST/PUT/PATCH/DELETE)
+    write_burst_size: u32,
+    /// Unix time (seconds) of the last sweep of idle buckets
+    last_cleanup: Arc<AtomicU64>,
+}
+
+impl SqliteRateLimiter {
+    /// Create a rate limiter storing its buckets in `db`
+    ///
+    /// `db` is best a small pool dedicated to the limiter (see the type docs).
+    /// Writes share the same burst size until `with_write_burst_size` is used.
+    pub fn new(db: SqlitePool, per_second: u64, burst_size: u32) -> Self {
+        Self {
+            db,
+            per_second,
+            burst_size,
+            write_burst_size: burst_size,
+            last_cleanup: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Use a separate (typically tighter) burst size for write requests
+    pub fn with_write_burst_size(mut self, write_burst_size: u32) -> Self {
+        self.write_burst_size = write_burst_size;
+        self
+    }
+
+    fn limit(&self, class: MethodClass) -> u32 {
+        match class {
+            MethodClass::Read => self.burst_size,
+            MethodClass::Write => self.write_burst_size,
+        }
+    }
+
+    /// Spends a token from the `(ip, class)` bucket as of `now` (Unix time in seconds)
+    async fn check_at(&self, ip: IpAddr, class: MethodClass, now: f64) -> Result<RateLimitStatus, sqlx::Error> {
+        let limit = self.limit(class);
+        let capacity = f64::from(limit);
+        let client = ip.to_string();
+
+        // Sweep idle buckets every 60 seconds; one instance winning the race is enough
+        let last_cleanup = self.last_cleanup.load(Ordering::Relaxed);
+        if now as u64 > last_cleanup + IDLE_BUCKET_TTL.as_secs()
+            && self
+                .last_cleanup
+                .compare_exchange(last_cleanup, now as u64, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            sqlx::query("DELETE FROM rate_limits WHERE refilled_at < ?")
+                .bind(now - IDLE_BUCKET_TTL.as_secs_f64())
+                .execute(&self.db)
+                .await?;
+        }
+
+        // New clients start with a full bucket; others are refilled for the time
+        // elapsed, never past the burst size nor backwards if clocks disagree. A
+        // token is spent when one is available. SET expressions all read the row as
+        // it was, so the refilled level is spelled out for each column.
+        let (tokens, allowed): (f64, bool) = sqlx::query_as(
+            "INSERT INTO rate_limits (client, class, tokens, refilled_at, allowed)
+             VALUES (?1, ?2, MAX(?3 - 1, 0), ?4, ?3 >= 1)
+             ON CONFLICT (client, class) DO UPDATE SET
+                 tokens = MIN(?3, tokens + MAX(excluded.refilled_at - refilled_at, 0) * ?5)
+                     - (MIN(?3, tokens + MAX(excluded.refilled_at - refilled_at, 0) * ?5) >= 1),
+                 allowed = MIN(?3, tokens + MAX(excluded.refilled_at - refilled_at, 0) * ?5) >= 1,
+                 refilled_at = MAX(refilled_at, excluded.refilled_at)
+             RETURNING tokens, allowed",
+        )
+        .bind(&client)
+        .bind(class.as_str())
+        .bind(capacity)
+        .bind(now)
+        .bind(self.per_second as f64)
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(RateLimitStatus::new(allowed, limit, tokens, self.per_second))
+    }
+}
+
+impl RateLimitBackend for SqliteRateLimiter {
+    fn check(&self, ip: IpAddr, method: &Method) -> BoxFuture<'_, RateLimitStatus> {
+        let class = MethodClass::of(method);
+        async move {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs_f64();
+            match self.check_at(ip, class, now).await {
+                Ok(status) => status,
+                // An unavailable store should not take the whole API down with it
+                Err(e) => {
+                    tracing::warn!("Rate limit store unavailable, letting the request through: {}", e);
+                    let limit = self.limit(class);
+                    RateLimitStatus::new(true, limit, f64::from(limit), self.per_second)
+                }
+            }
+        }
+        .boxed()
     }
 }
 
@@ -107,18 +371,233 @@ pub async fn rate_limit_middleware(
     // Get rate limiter from extensions
     let rate_limiter = request
         .extensions()
-        .get::<RateLimiter>()
-        .expect("RateLimiter not found in extensions");
+        .get::<SharedRateLimiter>()
+        .expect("SharedRateLimiter not found in extensions")
+        .clone();
 
-    if rate_limiter.check_rate_limit(ip) {
-        // Request allowed
+    let status = rate_limiter.check(ip, request.method()).await;
+
+    let mut response = if status.allowed {
         next.run(request).await
     } else {
-        // Rate limit exceeded
-        (
-            StatusCode::TOO_MANY_REQUESTS,
-            "Rate limit exceeded. Please try again later.",
-        )
-            .into_response()
+        AppError::RateLimited("Rate limit exceeded. Please try again later.".to_string()).into_response()
+    };
+
+    // Backoff information on every response, allowed or not
+    let headers = response.headers_mut();
+    headers.insert("x-ratelimit-limit", HeaderValue::from(status.limit));
+    headers.insert("x-ratelimit-remaining", HeaderValue::from(status.remaining));
+    headers.insert("retry-after", HeaderValue::from(status.retry_after_secs));
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_bucket_is_limited_separately() {
+        // No refill, so only the burst sizes matter
+        let limiter = RateLimiter::new(0, 5).with_write_burst_size(2);
+        let ip = IpAddr::from([10, 0, 0, 1]);
+
+        assert!(limiter.check_rate_limit_for(ip, &Method::POST));
+        assert!(limiter.check_rate_limit_for(ip, &Method::DELETE));
+        assert!(!limiter.check_rate_limit_for(ip, &Method::PUT));
+
+        // Reads still have their own budget
+        for _ in 0..5 {
+            assert!(limiter.check_rate_limit_for(ip, &Method::GET));
+        }
+        assert!(!limiter.check_rate_limit_for(ip, &Method::GET));
+
+        // Other clients are unaffected
+        assert!(limiter.check_rate_limit_for(IpAddr::from([10, 0, 0, 2]), &Method::POST));
+    }
+
+    #[test]
+    fn test_sustained_rate_is_not_dropped() {
+        let limiter = RateLimiter::new(10, 5);
+        let ip = IpAddr::from([10, 0, 0, 1]);
+        let start = Instant::now();
+
+        // Exactly per_second requests per second, evenly spaced, for two seconds
+        for i in 0..20 {
+            let now = start + Duration::from_millis(100 * i);
+            assert!(limiter.check_at(ip, MethodClass::Read, now).allowed, "request {} dropped", i);
+        }
+    }
+
+    #[test]
+    fn test_burst_beyond_burst_size_is_dropped() {
+        let limiter = RateLimiter::new(10, 5);
+        let ip = IpAddr::from([10, 0, 0, 1]);
+        let now = Instant::now();
+
+        for _ in 0..5 {
+            assert!(limiter.check_at(ip, MethodClass::Read, now).allowed);
+        }
+        assert!(!limiter.check_at(ip, MethodClass::Read, now).allowed);
+
+        // One token is back after 1 / per_second
+        let later = now + Duration::from_millis(100);
+        assert!(limiter.check_at(ip, MethodClass::Read, later).allowed);
+        assert!(!limiter.check_at(ip, MethodClass::Read, later).allowed);
+    }
+
+    #[test]
+    fn test_check_rate_limit_uses_read_bucket() {
+        let limiter = RateLimiter::new(0, 2).with_write_burst_size(1);
+        let ip = IpAddr::from([10, 0, 0, 1]);
+
+        assert!(limiter.check_rate_limit_for(ip, &Method::PATCH));
+        assert!(limiter.check_rate_limit(ip));
+        assert!(limiter.check_rate_limit(ip));
+        assert!(!limiter.check_rate_limit(ip));
+    }
+
+    #[test]
+    fn test_status_reports_remaining_and_retry_after() {
+        let limiter = RateLimiter::new(2, 3);
+        let ip = IpAddr::from([10, 0, 0, 1]);
+        let now = Instant::now();
+
+        let status = limiter.check_at(ip, MethodClass::Read, now);
+        assert_eq!(
+            status,
+            RateLimitStatus { allowed: true, limit: 3, remaining: 2, retry_after_secs: 0 }
+        );
+
+        limiter.check_at(ip, MethodClass::Read, now);
+        limiter.check_at(ip, MethodClass::Read, now);
+        let status = limiter.check_at(ip, MethodClass::Read, now);
+        assert!(!status.allowed);
+        assert_eq!(status.remaining, 0);
+        // Half a second per token at 2/s, rounded up
+        assert_eq!(status.retry_after_secs, 1);
+    }
+
+    #[tokio::test]
+    async fn test_middleware_sets_rate_limit_headers() {
+        use axum::{Extension, Router, body::Body, http::StatusCode, middleware, routing::get};
+        use tower::ServiceExt;
+
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(middleware::from_fn(rate_limit_middleware))
+            .layer(Extension(Arc::new(RateLimiter::new(1, 2)) as SharedRateLimiter));
+
+        let header = |response: &Response, name: &str| -> u64 {
+            response.headers()[name].to_str().unwrap().parse().unwrap()
+        };
+        let request = || Request::builder().uri("/").body(Body::empty()).unwrap();
+
+        let response = app.clone().oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(header(&response, "x-ratelimit-limit"), 2);
+        assert_eq!(header(&response, "x-ratelimit-remaining"), 1);
+        assert_eq!(header(&response, "retry-after"), 0);
+
+        app.clone().oneshot(request()).await.unwrap();
+        let response = app.oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(header(&response, "x-ratelimit-limit"), 2);
+        assert_eq!(header(&response, "x-ratelimit-remaining"), 0);
+        assert_eq!(header(&response, "retry-after"), 1);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "RATE_LIMITED");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_clients_are_limited_independently() {
+        let limiter = RateLimiter::new(0, 10);
+        let started = Instant::now();
+
+        // 64 clients, each sending 15 requests from its own task
+        let handles: Vec<_> = (0..64u8)
+            .map(|client| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move {
+                    let ip = IpAddr::from([10, 0, 1, client]);
+                    (0..15).filter(|_| limiter.check_rate_limit(ip)).count()
+                })
+            })
+            .collect();
+
+        // Every client gets exactly its own burst, regardless of interleaving
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), 10);
+        }
+
+        // ~1000 checks should take nowhere near this long even on a slow machine
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_database_backend_enforces_limit() {
+        let db = crate::state::tests::new_test_db().await.db;
+        let limiter = SqliteRateLimiter::new(db, 1, 3).with_write_burst_size(1);
+        let ip = IpAddr::from([10, 0, 0, 1]);
+        let now = 1_000_000.0;
+
+        for remaining in [2, 1, 0] {
+            let status = limiter.check_at(ip, MethodClass::Read, now).await.unwrap();
+            assert!(status.allowed);
+            assert_eq!(status.remaining, remaining);
+        }
+        let status = limiter.check_at(ip, MethodClass::Read, now).await.unwrap();
+        assert_eq!(
+            status,
+            RateLimitStatus { allowed: false, limit: 3, remaining: 0, retry_after_secs: 1 }
+        );
+
+        // Writes and other clients have their own buckets
+        assert!(limiter.check_at(ip, MethodClass::Write, now).await.unwrap().allowed);
+        assert!(!limiter.check_at(ip, MethodClass::Write, now).await.unwrap().allowed);
+        assert!(limiter.check_at(IpAddr::from([10, 0, 0, 2]), MethodClass::Read, now).await.unwrap().allowed);
+
+        // One token is back after 1 / per_second, and a clock running behind refills nothing
+        assert!(limiter.check_at(ip, MethodClass::Read, now + 1.0).await.unwrap().allowed);
+        assert!(!limiter.check_at(ip, MethodClass::Read, now + 1.0).await.unwrap().allowed);
+        assert!(!limiter.check_at(ip, MethodClass::Read, now - 5.0).await.unwrap().allowed);
+    }
+
+    #[tokio::test]
+    async fn test_database_backend_survives_restart() {
+        let db = crate::state::tests::new_test_db().await.db;
+        let ip = IpAddr::from([10, 0, 0, 1]);
+
+        let limiter: SharedRateLimiter = Arc::new(SqliteRateLimiter::new(db.clone(), 0, 3));
+        assert!(limiter.check(ip, &Method::GET).await.allowed);
+        assert!(limiter.check(ip, &Method::GET).await.allowed);
+        drop(limiter);
+
+        // A new instance on the same store picks up where the old one stopped
+        let restarted: SharedRateLimiter = Arc::new(SqliteRateLimiter::new(db, 0, 3));
+        let status = restarted.check(ip, &Method::GET).await;
+        assert!(status.allowed);
+        assert_eq!(status.remaining, 0);
+        assert!(!restarted.check(ip, &Method::HEAD).await.allowed);
+    }
+
+    #[test]
+    fn test_poisoned_shard_keeps_working() {
+        let limiter = RateLimiter::new(0, 2);
+        let ip = IpAddr::from([10, 0, 0, 1]);
+        assert!(limiter.check_rate_limit(ip));
+
+        // Poison every shard
+        let shards = limiter.shards.clone();
+        let _ = std::thread::spawn(move || {
+            let _guards: Vec<_> = shards.iter().map(|shard| shard.lock().unwrap()).collect();
+            panic!("poison");
+        })
+        .join();
+
+        assert!(limiter.check_rate_limit(ip));
+        assert!(!limiter.check_rate_limit(ip));
     }
 }
@@ -0,0 +1,89 @@
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// Header carrying the request correlation id, in both directions
+pub static REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied request id that is accepted as-is
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Correlation id of the current request, available as a request extension
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+/// Middleware assigning every request a correlation id
+///
+/// Reuses the caller's `X-Request-Id` when it is present and sane, otherwise
+/// generates a UUID. The id is stored as a [`RequestId`] extension, recorded on a
+/// `request` span that wraps the handler (so `#[tracing::instrument]` spans nest
+/// under it), and echoed back in the response `X-Request-Id` header.
+pub async fn request_id_middleware(mut request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|id| !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN)
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    request.extensions_mut().insert(RequestId(request_id.clone()));
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        method = %request.method(),
+        uri = %request.uri()
+    );
+    let mut response = next.run(request).instrument(span).await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER.clone(), value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{routes::create_router, state::tests::new_test_db};
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_supplied_request_id_is_echoed() {
+        let app = create_router(new_test_db().await);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/health")
+                    .header("x-request-id", "trace-abc-123")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.headers()["x-request-id"], "trace-abc-123");
+    }
+
+    #[tokio::test]
+    async fn test_missing_request_id_is_generated() {
+        let app = create_router(new_test_db().await);
+
+        let response = app
+            .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let id = response.headers()["x-request-id"].to_str().unwrap();
+        assert!(Uuid::parse_str(id).is_ok());
+    }
+}
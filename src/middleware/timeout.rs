@@ -0,0 +1,118 @@
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::time::Duration;
+
+use crate::error::AppError;
+
+/// Default time a handler may take before the request is aborted (30 seconds)
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Longest time a request may spend in its handler
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestTimeout(pub Duration);
+
+impl Default for RequestTimeout {
+    fn default() -> Self {
+        Self(DEFAULT_REQUEST_TIMEOUT)
+    }
+}
+
+/// Middleware aborting requests that run past the [`RequestTimeout`] with `504 Gateway Timeout`
+///
+/// The handler future is dropped when the deadline passes, and with it any open
+/// `sqlx` transaction, which rolls back instead of being committed half-way. Only
+/// producing the response is timed; streaming its body (e.g. an export) is not.
+pub async fn timeout_middleware(State(timeout): State<RequestTimeout>, request: Request, next: Next) -> Response {
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+
+    match tokio::time::timeout(timeout.0, next.run(request)).await {
+        Ok(response) => response,
+        Err(_) => {
+            tracing::warn!("{} {} timed out after {:?}", method, path, timeout.0);
+            AppError::GatewayTimeout(format!("Request did not complete within {:?}", timeout.0)).into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{AppState, tests::new_test_db};
+    use axum::{Router, body::Body, http::StatusCode, middleware, routing::get, routing::post};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    fn app(state: AppState, timeout: Duration) -> Router {
+        Router::new()
+            .route("/fast", get(|| async { "done" }))
+            .route(
+                "/slow",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    "done"
+                }),
+            )
+            .route(
+                "/slow-write",
+                post(|State(state): State<AppState>| async move {
+                    let mut tx = state.db.begin().await.unwrap();
+                    sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, CURRENT_TIMESTAMP)")
+                        .bind(uuid::Uuid::new_v4().to_string())
+                        .bind("Slow")
+                        .bind("slow@example.com")
+                        .execute(&mut *tx)
+                        .await
+                        .unwrap();
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    tx.commit().await.unwrap();
+                }),
+            )
+            .layer(middleware::from_fn_with_state(RequestTimeout(timeout), timeout_middleware))
+            .with_state(state)
+    }
+
+    fn request(method: &str, uri: &str) -> Request {
+        Request::builder().method(method).uri(uri).body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_slow_handler_times_out() {
+        let app = app(new_test_db().await, Duration::from_millis(50));
+
+        let response = app.clone().oneshot(request("GET", "/slow")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "GATEWAY_TIMEOUT");
+
+        let response = app.oneshot(request("GET", "/fast")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_transaction_is_rolled_back() {
+        let state = new_test_db().await;
+        let app = app(state.clone(), Duration::from_millis(100));
+
+        let response = app.oneshot(request("POST", "/slow-write")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+
+        // The insert never became visible and the database still accepts writes
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+        sqlx::query("INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, CURRENT_TIMESTAMP)")
+            .bind(uuid::Uuid::new_v4().to_string())
+            .bind("Fast")
+            .bind("fast@example.com")
+            .execute(&state.db)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::{FromRow, sqlite::SqliteRow, Row};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Maximum length of an API key name
+pub const API_KEY_NAME_MAX_LEN: u64 = 255;
+/// Prefix of every generated API key, making leaked keys easy to recognize
+pub const API_KEY_PREFIX: &str = "pak_";
+
+/// API key metadata; the key itself is never stored or returned after creation
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiKey {
+    /// Unique identifier for the key
+    pub id: Uuid,
+    /// Human-readable label (e.g. the integration using it)
+    pub name: String,
+    /// User the key acts on behalf of
+    pub user_id: Uuid,
+    /// Caller role granted to requests using the key
+    pub role: String,
+    /// Timestamp when the key was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp of the last request authenticated with the key
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+// Custom FromRow implementation to handle UUID as TEXT in SQLite
+impl FromRow<'_, SqliteRow> for ApiKey {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        let id_str: String = row.try_get("id")?;
+        let id = Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let user_id_str: String = row.try_get("user_id")?;
+        let user_id = Uuid::parse_str(&user_id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+        Ok(ApiKey {
+            id,
+            name: row.try_get("name")?,
+            user_id,
+            role: row.try_get("role")?,
+            created_at: row.try_get("created_at")?,
+            last_used_at: row.try_get("last_used_at")?,
+        })
+    }
+}
+
+impl ApiKey {
+    /// Creates a new key from a request, returning its metadata and the plaintext key
+    pub fn generate(request: CreateApiKeyRequest) -> (Self, String) {
+        let key = format!(
+            "{}{}{}",
+            API_KEY_PREFIX,
+            Uuid::new_v4().simple(),
+            Uuid::new_v4().simple()
+        );
+        let api_key = Self {
+            id: Uuid::new_v4(),
+            name: request.name,
+            user_id: request.user_id,
+            role: request.role.trim().to_string(),
+            created_at: Utc::now(),
+            last_used_at: None,
+        };
+        (api_key, key)
+    }
+
+    /// Hex-encoded SHA-256 hash of a plaintext key, as stored in `api_keys.key_hash`
+    pub fn hash(key: &str) -> String {
+        Sha256::digest(key.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+/// Request payload for minting an API key
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateApiKeyRequest {
+    /// Human-readable label for the key
+    #[validate(length(
+        min = 1,
+        max = API_KEY_NAME_MAX_LEN,
+        message = "Name must be between 1 and 255 characters"
+    ))]
+    pub name: String,
+    /// User the key acts on behalf of
+    pub user_id: Uuid,
+    /// Caller role granted to requests using the key (e.g. `integration`)
+    #[validate(length(min = 1, max = 64, message = "Role must be between 1 and 64 characters"))]
+    pub role: String,
+}
+
+/// A freshly minted API key; the plaintext `key` is only ever returned here
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreatedApiKey {
+    /// Key metadata
+    #[serde(flatten)]
+    pub api_key: ApiKey,
+    /// Plaintext key to send in the `X-API-Key` header; store it now, it cannot be retrieved again
+    pub key: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_api_key() {
+        let request = CreateApiKeyRequest {
+            name: "CI".to_string(),
+            user_id: Uuid::new_v4(),
+            role: " integration ".to_string(),
+        };
+
+        let (api_key, key) = ApiKey::generate(request.clone());
+        assert_eq!(api_key.user_id, request.user_id);
+        assert_eq!(api_key.role, "integration");
+        assert!(key.starts_with(API_KEY_PREFIX));
+        assert_eq!(key.len(), API_KEY_PREFIX.len() + 64);
+
+        let (_, other) = ApiKey::generate(request);
+        assert_ne!(key, other);
+    }
+
+    #[test]
+    fn test_hash_api_key() {
+        assert_eq!(
+            ApiKey::hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_ne!(ApiKey::hash("abc"), ApiKey::hash("abd"));
+    }
+}
@@ -0,0 +1,225 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{
+    pagination::PaginatedResponse, project::ProjectWithRelations, technology::ProjectTechnology, user::UserWithRole,
+    Project,
+};
+
+/// Media type selecting JSON:API documents (`Accept: application/vnd.api+json`)
+pub const JSON_API_MEDIA_TYPE: &str = "application/vnd.api+json";
+
+/// Reference to a resource inside a relationship
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ResourceIdentifier {
+    /// Resource type (`technologies`, `users`)
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Resource id
+    pub id: Uuid,
+    /// Details of the link itself, e.g. a member's `role` (omitted when empty)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Value>,
+}
+
+/// Linkage of a to-many relationship
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Relationship {
+    /// Linked resources
+    pub data: Vec<ResourceIdentifier>,
+}
+
+/// A JSON:API resource object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Resource {
+    /// Resource type (`projects`, `technologies`, `users`)
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Resource id
+    pub id: Uuid,
+    /// Every serialized field except `id`
+    #[schema(value_type = Object)]
+    pub attributes: Map<String, Value>,
+    /// Related resources by name (omitted when empty)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub relationships: BTreeMap<String, Relationship>,
+}
+
+impl Resource {
+    /// Resource of type `kind` whose attributes are `value`'s serialized fields minus `id`
+    pub fn new(kind: &str, id: Uuid, value: &impl Serialize) -> serde_json::Result<Self> {
+        let mut attributes = match serde_json::to_value(value)? {
+            Value::Object(attributes) => attributes,
+            _ => Map::new(),
+        };
+        attributes.remove("id");
+        Ok(Self {
+            kind: kind.to_string(),
+            id,
+            attributes,
+            relationships: BTreeMap::new(),
+        })
+    }
+
+    /// `projects` resource without relationships
+    pub fn project(project: &Project) -> serde_json::Result<Self> {
+        Self::new("projects", project.id, project)
+    }
+
+    /// Adds a to-many relationship
+    pub fn with_relationship(mut self, name: &str, data: Vec<ResourceIdentifier>) -> Self {
+        self.relationships.insert(name.to_string(), Relationship { data });
+        self
+    }
+
+    /// Keeps only the attributes named in `fields`; all of them when `fields` is empty
+    pub fn retain_attributes(&mut self, fields: &[&str]) {
+        if !fields.is_empty() {
+            self.attributes.retain(|name, _| fields.contains(&name.as_str()));
+        }
+    }
+}
+
+impl ResourceIdentifier {
+    /// Identifier of a `technologies` resource
+    pub fn technology(id: Uuid) -> Self {
+        Self {
+            kind: "technologies".to_string(),
+            id,
+            meta: None,
+        }
+    }
+
+    /// Identifier of a `users` resource, carrying the member's project `role`
+    pub fn member(id: Uuid, role: &str) -> Self {
+        Self {
+            kind: "users".to_string(),
+            id,
+            meta: Some(json!({ "role": role })),
+        }
+    }
+}
+
+/// Top-level JSON:API document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Document<T> {
+    /// Primary data: one resource or a list of them
+    pub data: T,
+    /// Related resources referenced from `data` (omitted when empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub included: Vec<Resource>,
+    /// Non-standard information such as pagination (omitted when empty)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Value>,
+}
+
+impl Document<Resource> {
+    /// Compound document for a project, its technologies and users included
+    pub fn project(project: &ProjectWithRelations) -> serde_json::Result<Self> {
+        let technologies = project
+            .technologies
+            .iter()
+            .map(|technology| ResourceIdentifier::technology(technology.technology.id))
+            .collect();
+        let users = project
+            .users
+            .iter()
+            .map(|user| ResourceIdentifier::member(user.user.id, user.role.as_str()))
+            .collect();
+        let data = Resource::project(&project.project)?
+            .with_relationship("technologies", technologies)
+            .with_relationship("users", users);
+
+        let mut included = project
+            .technologies
+            .iter()
+            .map(|ProjectTechnology { technology, .. }| Resource::new("technologies", technology.id, technology))
+            .collect::<serde_json::Result<Vec<_>>>()?;
+        for UserWithRole { user, .. } in &project.users {
+            included.push(Resource::new("users", user.id, user)?);
+        }
+
+        let meta = (!project.warnings.is_empty()).then(|| json!({ "warnings": project.warnings }));
+        Ok(Self { data, included, meta })
+    }
+}
+
+impl Document<Vec<Resource>> {
+    /// List document whose `meta` carries the page's pagination and warnings
+    pub fn page<T>(data: Vec<Resource>, page: &PaginatedResponse<T>) -> Self {
+        let mut meta = json!({ "pagination": page.pagination });
+        if !page.warnings.is_empty() {
+            meta["warnings"] = json!(page.warnings);
+        }
+        Self {
+            data,
+            included: Vec::new(),
+            meta: Some(meta),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CreateTechnologyRequest, CreateUserRequest, Technology, User, UserRole};
+    use chrono::Utc;
+
+    #[test]
+    fn test_project_document() {
+        let technology = Technology::new(CreateTechnologyRequest {
+            name: "Rust".to_string(),
+            description: None,
+        });
+        let user = User::new(CreateUserRequest {
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+            avatar_url: None,
+            bio: None,
+        });
+        let project = ProjectWithRelations {
+            project: Project {
+                id: Uuid::new_v4(),
+                name: "Atlas".to_string(),
+                description: "Maps".to_string(),
+                repository_url: "https://github.com/test/atlas".to_string(),
+                language: "Rust".to_string(),
+                rating: Some(4.0),
+                tags: Vec::new(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                deleted_at: None,
+            },
+            technologies: vec![ProjectTechnology {
+                technology: technology.clone(),
+                associated_at: Utc::now(),
+            }],
+            users: vec![UserWithRole {
+                user: user.clone(),
+                role: UserRole::Owner,
+                associated_at: Utc::now(),
+            }],
+            warnings: Vec::new(),
+        };
+
+        let document = serde_json::to_value(Document::project(&project).unwrap()).unwrap();
+        assert_eq!(document["data"]["type"], "projects");
+        assert_eq!(document["data"]["id"], project.project.id.to_string());
+        assert_eq!(document["data"]["attributes"]["name"], "Atlas");
+        assert!(document["data"]["attributes"].get("id").is_none());
+        assert_eq!(
+            document["data"]["relationships"]["technologies"]["data"],
+            json!([{ "type": "technologies", "id": technology.id }])
+        );
+        assert_eq!(
+            document["data"]["relationships"]["users"]["data"],
+            json!([{ "type": "users", "id": user.id, "meta": { "role": "owner" } }])
+        );
+        assert_eq!(document["included"][0]["attributes"]["name"], "Rust");
+        assert_eq!(document["included"][1]["attributes"]["email"], "alice@example.com");
+        assert!(document.get("meta").is_none());
+    }
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+use super::pagination::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE, PROJECT_SORT_FIELDS, SORT_ORDERS};
+use super::project::{
+    PROJECT_DESCRIPTION_MAX_LEN, PROJECT_LANGUAGE_MAX_LEN, PROJECT_NAME_MAX_LEN, RATING_MAX,
+    RATING_MIN,
+};
+use super::technology::{TECHNOLOGY_DESCRIPTION_MAX_LEN, TECHNOLOGY_NAME_MAX_LEN};
+use super::user::{USER_BIO_MAX_LEN, USER_NAME_MAX_LEN, UserRole};
+
+/// Inclusive bounds accepted for a numeric field
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NumericBounds {
+    pub min: f64,
+    pub max: f64,
+}
+
+/// Length limits (in characters) enforced on a string field
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FieldLimit {
+    /// Resource the field belongs to (e.g. `projects`)
+    pub resource: String,
+    /// Field name as it appears in request bodies
+    pub field: String,
+    /// Minimum length, if the field may not be empty
+    pub min_length: Option<u64>,
+    /// Maximum length
+    pub max_length: u64,
+}
+
+/// Validation limits and enum values clients can use to build forms
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiMetadata {
+    /// Valid project membership roles
+    pub roles: Vec<UserRole>,
+    /// Fields accepted by the `sort` parameter, per resource
+    pub sortable_fields: BTreeMap<String, Vec<String>>,
+    /// Values accepted by the `order` parameter
+    pub sort_orders: Vec<String>,
+    /// Page size used when `page_size` is omitted
+    pub default_page_size: u32,
+    /// Largest accepted `page_size`
+    pub max_page_size: u32,
+    /// Allowed project rating range
+    pub rating: NumericBounds,
+    /// String length limits per resource field
+    pub field_limits: Vec<FieldLimit>,
+}
+
+impl ApiMetadata {
+    /// Builds the metadata from the constants the validators use
+    pub fn current() -> Self {
+        let limit = |resource: &str, field: &str, min_length: Option<u64>, max_length: u64| {
+            FieldLimit {
+                resource: resource.to_string(),
+                field: field.to_string(),
+                min_length,
+                max_length,
+            }
+        };
+
+        ApiMetadata {
+            roles: UserRole::ALL.to_vec(),
+            sortable_fields: BTreeMap::from([(
+                "projects".to_string(),
+                PROJECT_SORT_FIELDS.iter().map(|field| field.to_string()).collect(),
+            )]),
+            sort_orders: SORT_ORDERS.iter().map(|order| order.to_string()).collect(),
+            default_page_size: DEFAULT_PAGE_SIZE,
+            max_page_size: MAX_PAGE_SIZE,
+            rating: NumericBounds {
+                min: RATING_MIN,
+                max: RATING_MAX,
+            },
+            field_limits: vec![
+                limit("projects", "name", Some(1), PROJECT_NAME_MAX_LEN),
+                limit("projects", "description", Some(1), PROJECT_DESCRIPTION_MAX_LEN),
+                limit("projects", "language", Some(1), PROJECT_LANGUAGE_MAX_LEN),
+                limit("technologies", "name", Some(1), TECHNOLOGY_NAME_MAX_LEN),
+                limit("technologies", "description", None, TECHNOLOGY_DESCRIPTION_MAX_LEN),
+                limit("users", "name", Some(1), USER_NAME_MAX_LEN),
+                limit("users", "bio", None, USER_BIO_MAX_LEN),
+            ],
+        }
+    }
+}
@@ -1,9 +1,25 @@
+pub mod api_key;
+pub mod jsonapi;
+pub mod meta;
 pub mod pagination;
 pub mod project;
+pub mod review;
 pub mod technology;
 pub mod user;
 
+pub use api_key::{ApiKey, CreateApiKeyRequest, CreatedApiKey};
+pub use meta::{ApiMetadata, FieldLimit, NumericBounds};
 pub use pagination::{ListQueryParams, PaginatedResponse, PaginationMetadata};
-pub use project::{CreateProjectRequest, Project, ProjectWithRelations, UpdateProjectRequest};
-pub use technology::{CreateTechnologyRequest, Technology};
-pub use user::{CreateUserRequest, User, UserRole, UserWithRole};
+pub use project::{
+    BulkRatingUpdateResult, CreateProjectOptions, CreateProjectRequest, ImportOptions, ImportRecordError, ImportSummary,
+    LanguageCount, Project, ProjectStats, ProjectValidation, ProjectWithRelations, ProjectWithRole, RatingUpdate, SimilarProjectsQuery,
+    TechnologyCount, UpdateProjectRequest,
+};
+pub use review::{CreateReviewRequest, Review};
+pub use technology::{
+    AttachTechnologyRequest, CreateTechnologyRequest, MergeTechnologiesRequest, ProjectTechnology, Technology,
+    TechnologyMergeSummary, UpdateTechnologyRequest,
+};
+pub use user::{
+    CreateUserRequest, ProjectMemberRequest, RoleUpdateRequest, UpdateUserRequest, User, UserRole, UserWithRole,
+};
@@ -1,6 +1,70 @@
+use axum::http::Uri;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sqlx::{QueryBuilder, Sqlite};
+use std::str::FromStr;
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
 use utoipa::ToSchema;
 
+use super::technology::Technology;
+use crate::error::AppError;
+
+/// Page size used when `page_size` is omitted
+pub const DEFAULT_PAGE_SIZE: u32 = 10;
+/// Largest page size a client may request, unless the operator configures another cap
+pub const MAX_PAGE_SIZE: u32 = 100;
+/// Highest page size cap an operator may configure
+pub const MAX_PAGE_SIZE_LIMIT: u32 = 10_000;
+/// Fields `GET /projects` can be sorted by
+///
+/// `relevance` ranks full-text `search` matches and is ignored without a search term.
+pub const PROJECT_SORT_FIELDS: [&str; 5] = ["name", "created_at", "updated_at", "rating", "relevance"];
+/// Fields a `GET /projects` response can be narrowed to with `fields`
+pub const PROJECT_FIELDS: [&str; 10] = [
+    "id",
+    "name",
+    "description",
+    "repository_url",
+    "language",
+    "rating",
+    "tags",
+    "created_at",
+    "updated_at",
+    "deleted_at",
+];
+/// Relations `GET /projects` can embed in each listed project with `include`
+pub const PROJECT_INCLUDES: [&str; 2] = ["technologies", "users"];
+/// Date after which the deprecated `tech` alias of `technology` may stop working (HTTP-date)
+pub const TECH_ALIAS_SUNSET: &str = "Fri, 01 Jan 2027 00:00:00 GMT";
+/// Accepted values for the `order` parameter
+pub const SORT_ORDERS: [&str; 2] = ["asc", "desc"];
+/// Accepted values for the `tech_mode` parameter
+pub const TECH_MODES: [&str; 2] = ["any", "all"];
+/// Accepted values for the `match` parameter
+pub const MATCH_MODES: [&str; 2] = ["substring", "exact"];
+
+/// What list endpoints do with a `page` past the last one, configured via `PAGE_OVERFLOW`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageOverflow {
+    /// Serve the last page instead; `pagination.page` reports the page served
+    #[default]
+    Clamp,
+    /// Refuse the request with `400 Bad Request`
+    Reject,
+}
+
+impl FromStr for PageOverflow {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "clamp" => Ok(PageOverflow::Clamp),
+            "reject" => Ok(PageOverflow::Reject),
+            _ => Err(format!("Invalid page overflow mode: {}", s)),
+        }
+    }
+}
+
 /// Pagination metadata
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PaginationMetadata {
@@ -8,20 +72,39 @@ pub struct PaginationMetadata {
     pub page: u32,
     /// Number of items per page
     pub page_size: u32,
-    /// Total number of items
-    pub total_items: i64,
-    /// Total number of pages
-    pub total_pages: u32,
+    /// Total number of items (`null` when the count was skipped with `with_total=false`)
+    pub total_items: Option<i64>,
+    /// Total number of pages (`null` when the count was skipped with `with_total=false`)
+    pub total_pages: Option<u32>,
+    /// Whether a page follows this one (without a total: whether this page came back full)
+    pub has_next: bool,
+    /// Whether a page precedes this one
+    pub has_prev: bool,
 }
 
 impl PaginationMetadata {
     pub fn new(page: u32, page_size: u32, total_items: i64) -> Self {
         let total_pages = ((total_items as f64) / (page_size as f64)).ceil() as u32;
+        let total_pages = total_pages.max(1);
+        Self {
+            page,
+            page_size,
+            total_items: Some(total_items),
+            total_pages: Some(total_pages),
+            has_next: page < total_pages,
+            has_prev: page > 1,
+        }
+    }
+
+    /// Metadata for a page whose total was not counted; `has_next` is the caller's guess
+    pub fn without_total(page: u32, page_size: u32, has_next: bool) -> Self {
         Self {
             page,
             page_size,
-            total_items,
-            total_pages: total_pages.max(1),
+            total_items: None,
+            total_pages: None,
+            has_next,
+            has_prev: page > 1,
         }
     }
 }
@@ -33,6 +116,9 @@ pub struct PaginatedResponse<T> {
     pub data: Vec<T>,
     /// Pagination metadata
     pub pagination: PaginationMetadata,
+    /// Non-fatal notices about the request, e.g. deprecated parameters (omitted when empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 impl<T> PaginatedResponse<T> {
@@ -40,34 +126,106 @@ impl<T> PaginatedResponse<T> {
         Self {
             data,
             pagination: PaginationMetadata::new(page, page_size, total_items),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Page without total counts, for callers that opted out with `with_total=false`
+    ///
+    /// A full page is taken to have a next one.
+    pub fn without_total(data: Vec<T>, page: u32, page_size: u32) -> Self {
+        let has_next = data.len() as u32 >= page_size;
+        Self {
+            data,
+            pagination: PaginationMetadata::without_total(page, page_size, has_next),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// RFC 8288 `Link` header value with `first`, `prev`, `next` and `last` page URLs
+    ///
+    /// Each URL is `uri` with its `page` parameter replaced, so every other query
+    /// parameter is kept. `prev` is omitted on the first page and `next` on the last;
+    /// without a total there is no `last`, and `next` is given while pages come back full.
+    pub fn link_header(&self, uri: &Uri) -> String {
+        let query: Vec<&str> = uri
+            .query()
+            .unwrap_or_default()
+            .split('&')
+            .filter(|pair| !pair.is_empty() && pair.split('=').next() != Some("page"))
+            .collect();
+        let link = |page: u32, rel: &str| {
+            let mut pairs = query.clone();
+            let page = format!("page={}", page);
+            pairs.push(&page);
+            format!("<{}?{}>; rel=\"{}\"", uri.path(), pairs.join("&"), rel)
+        };
+
+        let page = self.pagination.page;
+        let mut links = vec![link(1, "first")];
+        if self.pagination.has_prev {
+            links.push(link(page - 1, "prev"));
         }
+        if self.pagination.has_next {
+            links.push(link(page + 1, "next"));
+        }
+        if let Some(total_pages) = self.pagination.total_pages {
+            links.push(link(total_pages, "last"));
+        }
+        links.join(", ")
     }
 }
 
 /// Query parameters for list endpoints
-#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
 pub struct ListQueryParams {
     /// Search text in name and description
     pub search: Option<String>,
-    /// Filter by technology name
-    #[serde(alias = "tech")]
+    /// Filter by technology name; a comma-separated list matches exact names
     pub technology: Option<String>,
+    /// Deprecated alias of `technology`, still honored until [`TECH_ALIAS_SUNSET`]
+    pub tech: Option<String>,
+    /// How a technology list combines: `any` (default) or `all`
+    pub tech_mode: Option<String>,
     /// Filter by user ID
     pub user_id: Option<String>,
     /// Minimum rating filter
     pub min_rating: Option<f64>,
     /// Maximum rating filter
     pub max_rating: Option<f64>,
+    /// Only projects created at or after this instant (RFC 3339)
+    pub created_after: Option<DateTime<Utc>>,
+    /// Only projects created at or before this instant (RFC 3339)
+    pub created_before: Option<DateTime<Utc>>,
+    /// Only projects updated at or after this instant (RFC 3339)
+    pub updated_after: Option<DateTime<Utc>>,
+    /// Only projects updated at or before this instant (RFC 3339)
+    pub updated_before: Option<DateTime<Utc>>,
     /// Filter by language
     pub language: Option<String>,
+    /// Comma-separated languages; matches projects in any of them (e.g. `rust,go`)
+    pub languages: Option<String>,
+    /// Only projects carrying this tag (case-insensitive)
+    pub tag: Option<String>,
+    /// How `language` and `technology` match: `substring` (default) or `exact`
+    #[serde(rename = "match")]
+    pub match_mode: Option<String>,
     /// Field to sort by (name, created_at, updated_at, rating)
     pub sort: Option<String>,
     /// Sort order (asc, desc)
     pub order: Option<String>,
     /// Page number (default: 1)
     pub page: Option<u32>,
-    /// Items per page (default: 10, max: 100)
+    /// Items per page (default: 10; default max 100, configurable via `MAX_PAGE_SIZE`)
     pub page_size: Option<u32>,
+    /// Also list soft-deleted projects (default: false)
+    pub include_deleted: Option<bool>,
+    /// Comma-separated project fields to return (e.g. `id,name,rating`); all fields when omitted
+    pub fields: Option<String>,
+    /// Comma-separated relations to embed in each project (`technologies`, `users`)
+    pub include: Option<String>,
+    /// Count the matching items for `total_items` / `total_pages` (default: true)
+    pub with_total: Option<bool>,
 }
 
 impl ListQueryParams {
@@ -75,31 +233,382 @@ impl ListQueryParams {
         self.page.unwrap_or(1).max(1)
     }
 
-    pub fn page_size(&self) -> u32 {
-        self.page_size.unwrap_or(10).clamp(1, 100)
+    /// Requested page size clamped to `1..=max_page_size` (the configured cap)
+    pub fn page_size(&self, max_page_size: u32) -> u32 {
+        self.page_size.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, max_page_size.max(1))
+    }
+
+    /// Technology filter, from `technology` or (if absent) the deprecated `tech` alias
+    pub fn technology(&self) -> Option<&str> {
+        self.technology.as_deref().or(self.tech.as_deref())
+    }
+
+    /// Technology name keys from the (comma-separated) technology filter, de-duplicated
+    pub fn technologies(&self) -> Vec<String> {
+        let mut technologies: Vec<String> = Vec::new();
+        for name in split_list(self.technology()) {
+            let key = Technology::name_key(name);
+            if !technologies.contains(&key) {
+                technologies.push(key);
+            }
+        }
+        technologies
+    }
+
+    /// Whether a technology list must all be used by a project (`tech_mode=all`)
+    pub fn requires_all_technologies(&self) -> bool {
+        self.tech_mode.as_deref().map(str::trim) == Some("all")
+    }
+
+    /// Whether `language` / `technology` must equal the value (`match=exact`)
+    pub fn exact_match(&self) -> bool {
+        self.match_mode.as_deref().map(str::trim) == Some("exact")
     }
 
-    pub fn offset(&self) -> u32 {
-        (self.page() - 1) * self.page_size()
+    /// Rejects unknown `match` values
+    pub fn validate_match_mode(&self) -> Result<(), AppError> {
+        match self.match_mode.as_deref().map(str::trim) {
+            Some(mode) if !MATCH_MODES.contains(&mode) => Err(AppError::ValidationError(format!(
+                "Invalid match '{}', expected one of: {}",
+                mode,
+                MATCH_MODES.join(", ")
+            ))),
+            _ => Ok(()),
+        }
     }
 
-    pub fn sort_field(&self) -> &str {
-        match self.sort.as_deref() {
-            Some("name") => "name",
-            Some("created_at") => "created_at",
-            Some("updated_at") => "updated_at",
-            Some("rating") => "rating",
-            _ => "created_at",
+    /// Rejects unknown `tech_mode` values
+    pub fn validate_tech_mode(&self) -> Result<(), AppError> {
+        match self.tech_mode.as_deref().map(str::trim) {
+            Some(mode) if !TECH_MODES.contains(&mode) => Err(AppError::ValidationError(format!(
+                "Invalid tech_mode '{}', expected one of: {}",
+                mode,
+                TECH_MODES.join(", ")
+            ))),
+            _ => Ok(()),
         }
     }
 
-    pub fn sort_order(&self) -> &str {
-        match self.order.as_deref() {
-            Some("asc") => "ASC",
-            Some("desc") => "DESC",
-            _ => "DESC",
+    /// Normalized `language` filter: trimmed and lowercased, `None` if blank
+    pub fn language(&self) -> Option<String> {
+        self.language
+            .as_deref()
+            .map(|language| language.trim().to_lowercase())
+            .filter(|language| !language.is_empty())
+    }
+
+    /// Tag filter, normalized like stored tags (trimmed, lowercased); `None` when blank
+    pub fn tag(&self) -> Option<String> {
+        self.tag
+            .as_deref()
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty())
+    }
+
+    /// Lowercased, trimmed entries of the `languages` list (empty entries dropped)
+    pub fn languages(&self) -> Vec<String> {
+        self.languages
+            .as_deref()
+            .map(|languages| {
+                languages
+                    .split(',')
+                    .map(|language| language.trim().to_lowercase())
+                    .filter(|language| !language.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether to run the count query; `with_total=false` skips it
+    pub fn with_total(&self) -> bool {
+        self.with_total.unwrap_or(true)
+    }
+
+    pub fn include_deleted(&self) -> bool {
+        self.include_deleted.unwrap_or(false)
+    }
+
+    /// SQL offset of the requested page
+    ///
+    /// Saturates instead of overflowing for absurdly large pages. Under the default
+    /// `PageOverflow::Clamp`, [`resolve_page`](Self::resolve_page) has already turned
+    /// those into the last page; they only come back empty when the count is skipped.
+    pub fn offset(&self, max_page_size: u32) -> u32 {
+        (self.page() - 1).saturating_mul(self.page_size(max_page_size))
+    }
+
+    /// Applies `overflow` to a `page` past the last of `total_items`
+    ///
+    /// Clamping rewrites `page`, so the rows, offset and metadata all follow the
+    /// last page. Empty lists have no last page and are left alone either way.
+    pub fn resolve_page(&mut self, total_items: i64, max_page_size: u32, overflow: PageOverflow) -> Result<(), AppError> {
+        let page = self.page();
+        let page_size = self.page_size(max_page_size) as i64;
+        let last_page = u32::try_from((total_items.max(1) + page_size - 1) / page_size).unwrap_or(u32::MAX);
+        if total_items == 0 || page <= last_page {
+            return Ok(());
+        }
+
+        match overflow {
+            PageOverflow::Clamp => {
+                self.page = Some(last_page);
+                Ok(())
+            }
+            PageOverflow::Reject => Err(AppError::ValidationError(format!(
+                "Page {} is out of range; the last page is {}",
+                page, last_page
+            ))),
         }
     }
+
+    /// Rejects unknown `sort` / `order` values instead of silently using the defaults
+    ///
+    /// Both accept comma-separated lists (`sort=rating,name&order=desc,asc`); giving
+    /// more orders than sort fields is an error. Omitted parameters are fine and
+    /// still fall back to `created_at` / `DESC`.
+    pub fn validate_sort(&self) -> Result<(), AppError> {
+        let fields = split_list(self.sort.as_deref());
+        let orders = split_list(self.order.as_deref());
+
+        if let Some(field) = fields.iter().find(|field| !PROJECT_SORT_FIELDS.contains(field)) {
+            return Err(AppError::ValidationError(format!(
+                "Invalid sort field '{}', expected one of: {}",
+                field,
+                PROJECT_SORT_FIELDS.join(", ")
+            )));
+        }
+        if let Some(order) = orders.iter().find(|order| !SORT_ORDERS.contains(order)) {
+            return Err(AppError::ValidationError(format!(
+                "Invalid sort order '{}', expected one of: {}",
+                order,
+                SORT_ORDERS.join(", ")
+            )));
+        }
+        if orders.len() > fields.len().max(1) {
+            return Err(AppError::ValidationError(format!(
+                "Got {} sort orders for {} sort field(s)",
+                orders.len(),
+                fields.len().max(1)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Rejects date ranges whose lower bound is after their upper bound
+    pub fn validate_date_ranges(&self) -> Result<(), AppError> {
+        let ranges = [
+            ("created", self.created_after, self.created_before),
+            ("updated", self.updated_after, self.updated_before),
+        ];
+        for (field, after, before) in ranges {
+            if let (Some(after), Some(before)) = (after, before)
+                && after > before
+            {
+                return Err(AppError::ValidationError(format!(
+                    "{}_after ({}) must not be later than {}_before ({})",
+                    field,
+                    after.to_rfc3339(),
+                    field,
+                    before.to_rfc3339()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Requested response fields in `PROJECT_FIELDS` order, empty when `fields` is omitted
+    ///
+    /// Unknown names are skipped here; `validate_fields` is what reports them.
+    pub fn fields(&self) -> Vec<&'static str> {
+        let requested = split_list(self.fields.as_deref());
+        PROJECT_FIELDS
+            .iter()
+            .copied()
+            .filter(|field| requested.contains(field))
+            .collect()
+    }
+
+    /// Rejects unknown `fields` entries
+    pub fn validate_fields(&self) -> Result<(), AppError> {
+        let requested = split_list(self.fields.as_deref());
+        if let Some(field) = requested.iter().find(|field| !PROJECT_FIELDS.contains(field)) {
+            return Err(AppError::ValidationError(format!(
+                "Invalid field '{}', expected one of: {}",
+                field,
+                PROJECT_FIELDS.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Requested relations in `PROJECT_INCLUDES` order, empty when `include` is omitted
+    ///
+    /// Unknown names are skipped here; `validate_includes` is what reports them.
+    pub fn includes(&self) -> Vec<&'static str> {
+        let requested = split_list(self.include.as_deref());
+        PROJECT_INCLUDES
+            .iter()
+            .copied()
+            .filter(|relation| requested.contains(relation))
+            .collect()
+    }
+
+    /// Rejects unknown `include` entries
+    pub fn validate_includes(&self) -> Result<(), AppError> {
+        let requested = split_list(self.include.as_deref());
+        if let Some(relation) = requested.iter().find(|relation| !PROJECT_INCLUDES.contains(relation)) {
+            return Err(AppError::ValidationError(format!(
+                "Invalid include '{}', expected one of: {}",
+                relation,
+                PROJECT_INCLUDES.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether `sort` asks for full-text relevance ranking
+    pub fn sorts_by_relevance(&self) -> bool {
+        split_list(self.sort.as_deref()).contains(&"relevance")
+    }
+
+    /// `(field, direction)` pairs for the `ORDER BY` clause, in priority order
+    ///
+    /// Fields without a matching `order` entry sort `DESC`. Unknown values are
+    /// skipped here; `validate_sort` is what reports them.
+    pub fn sort_clauses(&self) -> Vec<(&'static str, &'static str)> {
+        let orders = split_list(self.order.as_deref());
+        let mut fields: Vec<&'static str> = split_list(self.sort.as_deref())
+            .into_iter()
+            .filter_map(|sort| PROJECT_SORT_FIELDS.iter().find(|field| **field == sort).copied())
+            .collect();
+        if fields.is_empty() {
+            fields.push("created_at");
+        }
+
+        fields
+            .into_iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let order = match orders.get(index).copied() {
+                    Some("asc") => "ASC",
+                    _ => "DESC",
+                };
+                (field, order)
+            })
+            .collect()
+    }
+}
+
+/// Clause to append after `LIKE ?` when the pattern comes from [`contains_pattern`]
+pub const LIKE_ESCAPE: &str = " ESCAPE '\\'";
+
+/// LIKE pattern matching values that contain `term` literally
+///
+/// `%`, `_` and the `\` escape character are escaped so user input cannot act as
+/// a wildcard; the pattern must be used together with [`LIKE_ESCAPE`].
+pub fn contains_pattern(term: &str) -> String {
+    let mut pattern = String::with_capacity(term.len() + 2);
+    pattern.push('%');
+    for c in term.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
+
+/// Case- and accent-folded form of `text` that project searches compare against
+///
+/// Characters are decomposed, combining marks dropped and the rest lowercased, so
+/// "Café", "CAFE" and "cafe" all fold to `cafe`.
+pub fn search_key(text: &str) -> String {
+    text.nfd()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !is_combining_mark(*c))
+        .collect()
+}
+
+/// Expression over `projects p` that a [`search_key`] pattern is matched against
+///
+/// Rows inserted by raw SQL since the last startup backfill
+/// (see `AppState::backfill_search_blobs`) fall back to their lowercased name and
+/// description, which is only ASCII case-insensitive.
+pub const PROJECT_SEARCH_BLOB: &str = "COALESCE(p.search_blob, lower(p.name || char(10) || p.description))";
+
+/// SQL fragment pushed into a [`ListQuery`]: a join or a filter condition
+type Clause<'q> = Box<dyn Fn(&mut QueryBuilder<'q, Sqlite>) + Send + Sync + 'q>;
+
+/// The COUNT and page queries of a list endpoint, built from one set of filters
+///
+/// Both queries read from the same source and joins and AND together the same
+/// filter conditions, so `total_items` always counts exactly what the pages list.
+/// Filters push a bare condition (`p.rating >= ?`); the `WHERE` / `AND` keywords
+/// are added here.
+pub struct ListQuery<'q> {
+    from: &'q str,
+    joins: Vec<Clause<'q>>,
+    filters: Vec<Clause<'q>>,
+}
+
+impl<'q> ListQuery<'q> {
+    /// List query over `from`, e.g. `projects p` or `project_users pu JOIN projects p ON ...`
+    pub fn new(from: &'q str) -> Self {
+        Self {
+            from,
+            joins: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+
+    /// Appends a join that needs bound values; it applies to both queries
+    pub fn join(&mut self, join: impl Fn(&mut QueryBuilder<'q, Sqlite>) + Send + Sync + 'q) -> &mut Self {
+        self.joins.push(Box::new(join));
+        self
+    }
+
+    /// Adds a condition every listed row must satisfy
+    pub fn filter(&mut self, filter: impl Fn(&mut QueryBuilder<'q, Sqlite>) + Send + Sync + 'q) -> &mut Self {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// `SELECT COUNT(*) AS count` over the filtered rows
+    pub fn count_query(&self) -> QueryBuilder<'q, Sqlite> {
+        let mut builder = QueryBuilder::new("SELECT COUNT(*) AS count");
+        self.push_source(&mut builder);
+        builder
+    }
+
+    /// `SELECT {columns}` over the filtered rows, sorted by `order_by` and paged
+    pub fn page_query(&self, columns: &str, order_by: &str, limit: u32, offset: u32) -> QueryBuilder<'q, Sqlite> {
+        let mut builder = QueryBuilder::new(format!("SELECT {}", columns));
+        self.push_source(&mut builder);
+        builder.push(format!(" ORDER BY {} LIMIT ", order_by));
+        builder.push_bind(limit);
+        builder.push(" OFFSET ");
+        builder.push_bind(offset);
+        builder
+    }
+
+    fn push_source(&self, builder: &mut QueryBuilder<'q, Sqlite>) {
+        builder.push(format!(" FROM {}", self.from));
+        for join in &self.joins {
+            join(builder);
+        }
+        for (index, filter) in self.filters.iter().enumerate() {
+            builder.push(if index == 0 { " WHERE " } else { " AND " });
+            filter(builder);
+        }
+    }
+}
+
+/// Trimmed, non-empty entries of a comma-separated query value
+fn split_list(value: Option<&str>) -> Vec<&str> {
+    value
+        .map(|value| value.split(',').map(str::trim).filter(|entry| !entry.is_empty()).collect())
+        .unwrap_or_default()
 }
 
 #[cfg(test)]
@@ -111,8 +620,86 @@ mod tests {
         let meta = PaginationMetadata::new(1, 10, 45);
         assert_eq!(meta.page, 1);
         assert_eq!(meta.page_size, 10);
-        assert_eq!(meta.total_items, 45);
-        assert_eq!(meta.total_pages, 5);
+        assert_eq!(meta.total_items, Some(45));
+        assert_eq!(meta.total_pages, Some(5));
+
+        let meta = PaginationMetadata::without_total(2, 10, true);
+        assert_eq!(meta.page, 2);
+        assert_eq!(meta.total_items, None);
+        assert_eq!(meta.total_pages, None);
+        assert!(meta.has_next && meta.has_prev);
+    }
+
+    #[test]
+    fn test_pagination_has_next_and_prev() {
+        let flags = |page, total_items| {
+            let meta = PaginationMetadata::new(page, 10, total_items);
+            (meta.has_prev, meta.has_next)
+        };
+
+        // First, middle and last of 5 pages
+        assert_eq!(flags(1, 45), (false, true));
+        assert_eq!(flags(3, 45), (true, true));
+        assert_eq!(flags(5, 45), (true, false));
+
+        // A single page, full or empty, has neither neighbour
+        assert_eq!(flags(1, 10), (false, false));
+        assert_eq!(flags(1, 0), (false, false));
+
+        // Without a total, only a full page is taken to have a next one
+        assert!(PaginatedResponse::without_total(vec![1, 2], 1, 2).pagination.has_next);
+        assert!(!PaginatedResponse::without_total(vec![1], 2, 2).pagination.has_next);
+    }
+
+    #[test]
+    fn test_resolve_out_of_range_page() {
+        let params = |page| ListQueryParams {
+            page: Some(page),
+            page_size: Some(10),
+            ..Default::default()
+        };
+
+        // 25 items make 3 pages of 10
+        let mut clamped = params(999);
+        clamped.resolve_page(25, MAX_PAGE_SIZE, PageOverflow::Clamp).unwrap();
+        assert_eq!(clamped.page(), 3);
+        assert_eq!(clamped.offset(MAX_PAGE_SIZE), 20);
+
+        let result = params(999).resolve_page(25, MAX_PAGE_SIZE, PageOverflow::Reject);
+        assert!(matches!(result, Err(AppError::ValidationError(message)) if message.contains("last page is 3")));
+
+        // In-range pages and empty lists are left alone
+        let mut last = params(3);
+        last.resolve_page(25, MAX_PAGE_SIZE, PageOverflow::Reject).unwrap();
+        assert_eq!(last.page(), 3);
+        let mut empty = params(7);
+        empty.resolve_page(0, MAX_PAGE_SIZE, PageOverflow::Reject).unwrap();
+        assert_eq!(empty.page(), 7);
+
+        // The offset of an extreme page saturates instead of overflowing
+        assert_eq!(params(u32::MAX).offset(MAX_PAGE_SIZE), u32::MAX);
+        assert_eq!("Reject".parse::<PageOverflow>(), Ok(PageOverflow::Reject));
+        assert!("wrap".parse::<PageOverflow>().is_err());
+    }
+
+    #[test]
+    fn test_link_header() {
+        let uri: Uri = "/projects?page=1&language=rust".parse().unwrap();
+        let page = PaginatedResponse::new(vec![1, 2], 1, 2, 2);
+        assert_eq!(
+            page.link_header(&uri),
+            r#"</projects?language=rust&page=1>; rel="first", </projects?language=rust&page=1>; rel="last""#
+        );
+
+        // Without a total, a full page links to the next one and there is no `last`
+        let uri: Uri = "/projects".parse().unwrap();
+        let page = PaginatedResponse::without_total(vec![1, 2], 2, 2);
+        assert_eq!(
+            page.link_header(&uri),
+            r#"</projects?page=1>; rel="first", </projects?page=1>; rel="prev", </projects?page=3>; rel="next""#
+        );
+        let page = PaginatedResponse::without_total(vec![1], 3, 2);
+        assert!(!page.link_header(&uri).contains("rel=\"next\""));
     }
 
     #[test]
@@ -120,21 +707,34 @@ mod tests {
         let params = ListQueryParams {
             search: None,
             technology: None,
+            tech: None,
+            tech_mode: None,
             user_id: None,
             min_rating: None,
             max_rating: None,
             language: None,
+            languages: None,
+            tag: None,
+            match_mode: None,
             sort: None,
             order: None,
             page: None,
             page_size: None,
+            include_deleted: None,
+            fields: None,
+            include: None,
+            with_total: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+            updated_before: None,
         };
 
         assert_eq!(params.page(), 1);
-        assert_eq!(params.page_size(), 10);
-        assert_eq!(params.offset(), 0);
-        assert_eq!(params.sort_field(), "created_at");
-        assert_eq!(params.sort_order(), "DESC");
+        assert_eq!(params.page_size(MAX_PAGE_SIZE), 10);
+        assert_eq!(params.offset(MAX_PAGE_SIZE), 0);
+        assert_eq!(params.sort_clauses(), vec![("created_at", "DESC")]);
+        assert!(params.with_total());
     }
 
     #[test]
@@ -142,21 +742,33 @@ mod tests {
         let params = ListQueryParams {
             search: None,
             technology: None,
+            tech: None,
+            tech_mode: None,
             user_id: None,
             min_rating: None,
             max_rating: None,
             language: None,
+            languages: None,
+            tag: None,
+            match_mode: None,
             sort: Some("name".to_string()),
             order: Some("asc".to_string()),
             page: Some(2),
             page_size: Some(20),
+            include_deleted: None,
+            fields: None,
+            include: None,
+            with_total: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+            updated_before: None,
         };
 
         assert_eq!(params.page(), 2);
-        assert_eq!(params.page_size(), 20);
-        assert_eq!(params.offset(), 20);
-        assert_eq!(params.sort_field(), "name");
-        assert_eq!(params.sort_order(), "ASC");
+        assert_eq!(params.page_size(MAX_PAGE_SIZE), 20);
+        assert_eq!(params.offset(MAX_PAGE_SIZE), 20);
+        assert_eq!(params.sort_clauses(), vec![("name", "ASC")]);
     }
 
     #[test]
@@ -164,16 +776,285 @@ mod tests {
         let params = ListQueryParams {
             search: None,
             technology: None,
+            tech: None,
+            tech_mode: None,
             user_id: None,
             min_rating: None,
             max_rating: None,
             language: None,
+            languages: None,
+            tag: None,
+            match_mode: None,
             sort: None,
             order: None,
             page: None,
             page_size: Some(200),
+            include_deleted: None,
+            fields: None,
+            include: None,
+            with_total: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+            updated_before: None,
+        };
+
+        assert_eq!(params.page_size(MAX_PAGE_SIZE), 100);
+
+        // An operator-raised cap lets larger pages through, still bounded by it
+        assert_eq!(params.page_size(500), 200);
+        assert_eq!(params.page_size(150), 150);
+
+        let params = ListQueryParams {
+            page: Some(3),
+            page_size: Some(200),
+            ..Default::default()
+        };
+        assert_eq!(params.offset(500), 400);
+    }
+
+    #[test]
+    fn test_validate_sort() {
+        assert!(ListQueryParams::default().validate_sort().is_ok());
+
+        let params = ListQueryParams {
+            sort: Some("rating".to_string()),
+            order: Some("asc".to_string()),
+            ..Default::default()
         };
+        assert!(params.validate_sort().is_ok());
+
+        let params = ListQueryParams {
+            sort: Some("raiting".to_string()),
+            ..Default::default()
+        };
+        match params.validate_sort() {
+            Err(AppError::ValidationError(message)) => {
+                assert!(message.contains("raiting"));
+                assert!(message.contains("name, created_at, updated_at, rating"));
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+
+        let params = ListQueryParams {
+            order: Some("sideways".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(params.validate_sort(), Err(AppError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_contains_pattern_escapes_wildcards() {
+        assert_eq!(contains_pattern("rust"), "%rust%");
+        assert_eq!(contains_pattern("50%"), "%50\\%%");
+        assert_eq!(contains_pattern("snake_case"), "%snake\\_case%");
+        assert_eq!(contains_pattern("a\\b"), "%a\\\\b%");
+    }
+
+    #[test]
+    fn test_search_key_folds_case_and_accents() {
+        assert_eq!(search_key("Café"), "cafe");
+        assert_eq!(search_key("CÔTÉ d'Ivoire"), "cote d'ivoire");
+        assert_eq!(search_key("Ñandú"), "nandu");
+        assert_eq!(search_key("plain ascii"), "plain ascii");
+    }
+
+    #[test]
+    fn test_list_query_without_filters() {
+        let query = ListQuery::new("users u");
+        assert_eq!(query.count_query().sql(), "SELECT COUNT(*) AS count FROM users u");
+        assert_eq!(
+            query.page_query("u.*", "u.name ASC", 10, 0).sql(),
+            "SELECT u.* FROM users u ORDER BY u.name ASC LIMIT ? OFFSET ?"
+        );
+    }
+
+    #[test]
+    fn test_list_query_filters_apply_to_both_queries() {
+        let pattern = contains_pattern("rust");
+        let mut query = ListQuery::new("projects p");
+        query
+            .filter(|b| {
+                b.push("p.deleted_at IS NULL");
+            })
+            .filter(|b| {
+                b.push("p.name LIKE ");
+                b.push_bind(pattern.clone());
+                b.push(LIKE_ESCAPE);
+            })
+            .filter(|b| {
+                b.push("p.rating >= ");
+                b.push_bind(4.0);
+            });
+
+        let conditions = "p.deleted_at IS NULL AND p.name LIKE ? ESCAPE '\\' AND p.rating >= ?";
+        assert_eq!(
+            query.count_query().sql(),
+            format!("SELECT COUNT(*) AS count FROM projects p WHERE {}", conditions)
+        );
+        assert_eq!(
+            query.page_query("p.*", "p.rating DESC, p.name ASC", 20, 40).sql(),
+            format!(
+                "SELECT p.* FROM projects p WHERE {} ORDER BY p.rating DESC, p.name ASC LIMIT ? OFFSET ?",
+                conditions
+            )
+        );
+    }
+
+    #[test]
+    fn test_list_query_joins_precede_filters() {
+        let mut query = ListQuery::new("project_users pu JOIN projects p ON p.id = pu.project_id");
+        query
+            .filter(|b| {
+                b.push("pu.user_id = ");
+                b.push_bind("user");
+            })
+            .join(|b| {
+                b.push(" JOIN (SELECT project_id FROM projects_fts WHERE projects_fts MATCH ");
+                b.push_bind("\"rust\"");
+                b.push(") fts ON fts.project_id = p.id");
+            });
+
+        assert_eq!(
+            query.count_query().sql(),
+            "SELECT COUNT(*) AS count FROM project_users pu JOIN projects p ON p.id = pu.project_id \
+             JOIN (SELECT project_id FROM projects_fts WHERE projects_fts MATCH ?) fts ON fts.project_id = p.id \
+             WHERE pu.user_id = ?"
+        );
+    }
+
+    #[test]
+    fn test_match_mode() {
+        let params: ListQueryParams = serde_json::from_value(serde_json::json!({ "match": "exact" })).unwrap();
+        assert!(params.exact_match());
+        assert!(params.validate_match_mode().is_ok());
+
+        assert!(!ListQueryParams::default().exact_match());
 
-        assert_eq!(params.page_size(), 100);
+        let params = ListQueryParams {
+            match_mode: Some("prefix".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(params.validate_match_mode(), Err(AppError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_technology_list() {
+        let params = ListQueryParams {
+            technology: Some(" Rust, go,,rust ".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(params.technologies(), vec!["rust", "go"]);
+        assert!(!params.requires_all_technologies());
+        assert!(params.validate_tech_mode().is_ok());
+
+        // The deprecated alias feeds the same list
+        let params = ListQueryParams {
+            tech: Some("axum".to_string()),
+            tech_mode: Some("all".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(params.technologies(), vec!["axum"]);
+        assert!(params.requires_all_technologies());
+
+        let params = ListQueryParams {
+            tech_mode: Some("every".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(params.validate_tech_mode(), Err(AppError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_validate_date_ranges() {
+        let day = |day: u32| chrono::TimeZone::with_ymd_and_hms(&Utc, 2025, 1, day, 0, 0, 0).unwrap();
+
+        let params = ListQueryParams {
+            created_after: Some(day(1)),
+            created_before: Some(day(1)),
+            updated_after: Some(day(2)),
+            ..Default::default()
+        };
+        assert!(params.validate_date_ranges().is_ok());
+
+        let params = ListQueryParams {
+            updated_after: Some(day(3)),
+            updated_before: Some(day(2)),
+            ..Default::default()
+        };
+        assert!(matches!(params.validate_date_ranges(), Err(AppError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_multi_field_sort() {
+        let params = ListQueryParams {
+            sort: Some("rating, name".to_string()),
+            order: Some("desc,asc".to_string()),
+            ..Default::default()
+        };
+        assert!(params.validate_sort().is_ok());
+        assert_eq!(params.sort_clauses(), vec![("rating", "DESC"), ("name", "ASC")]);
+
+        // Missing orders default to DESC
+        let params = ListQueryParams {
+            sort: Some("rating,name".to_string()),
+            order: Some("asc".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(params.sort_clauses(), vec![("rating", "ASC"), ("name", "DESC")]);
+
+        // More orders than fields is rejected
+        let params = ListQueryParams {
+            sort: Some("rating".to_string()),
+            order: Some("desc,asc".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(params.validate_sort(), Err(AppError::ValidationError(_))));
+
+        // Every field in the list is checked
+        let params = ListQueryParams {
+            sort: Some("rating,bogus".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(params.validate_sort(), Err(AppError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_fields() {
+        let params = ListQueryParams::default();
+        assert!(params.validate_fields().is_ok());
+        assert!(params.fields().is_empty());
+
+        let params = ListQueryParams {
+            fields: Some("rating, id,name,id".to_string()),
+            ..Default::default()
+        };
+        assert!(params.validate_fields().is_ok());
+        assert_eq!(params.fields(), vec!["id", "name", "rating"]);
+
+        let params = ListQueryParams {
+            fields: Some("id,password".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(params.validate_fields(), Err(AppError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_includes() {
+        let params = ListQueryParams::default();
+        assert!(params.validate_includes().is_ok());
+        assert!(params.includes().is_empty());
+
+        let params = ListQueryParams {
+            include: Some("users, technologies".to_string()),
+            ..Default::default()
+        };
+        assert!(params.validate_includes().is_ok());
+        assert_eq!(params.includes(), vec!["technologies", "users"]);
+
+        let params = ListQueryParams {
+            include: Some("users,reviews".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(params.validate_includes(), Err(AppError::ValidationError(_))));
     }
 }
@@ -1,12 +1,30 @@
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use sqlx::{FromRow, sqlite::SqliteRow, Row};
 use utoipa::ToSchema;
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidateUrl, ValidationError};
 
-use super::technology::Technology;
-use super::user::UserWithRole;
+use super::pagination::{DEFAULT_PAGE_SIZE, search_key};
+use super::technology::ProjectTechnology;
+use super::user::{ProjectMemberRequest, UserRole, UserWithRole};
+
+/// Maximum length of a project name
+pub const PROJECT_NAME_MAX_LEN: u64 = 255;
+/// Maximum length of a project description
+pub const PROJECT_DESCRIPTION_MAX_LEN: u64 = 2000;
+/// Maximum length of a project language
+pub const PROJECT_LANGUAGE_MAX_LEN: u64 = 100;
+/// Lowest allowed project rating
+pub const RATING_MIN: f64 = 0.0;
+/// Highest allowed project rating
+pub const RATING_MAX: f64 = 5.0;
+/// Maximum number of tags on a project
+pub const MAX_PROJECT_TAGS: usize = 20;
+/// Maximum length of a single project tag
+pub const PROJECT_TAG_MAX_LEN: usize = 50;
+/// Maximum number of projects accepted by one bulk create request
+pub const MAX_BULK_PROJECTS: usize = 500;
 
 /// Represents a code project in the system
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -23,10 +41,16 @@ pub struct Project {
     pub language: String,
     /// Project rating (0.0 - 5.0)
     pub rating: Option<f64>,
+    /// Free-form keywords, trimmed and lowercased
+    #[serde(default)]
+    pub tags: Vec<String>,
     /// Timestamp when the project was created
     pub created_at: DateTime<Utc>,
     /// Timestamp when the project was last updated
     pub updated_at: DateTime<Utc>,
+    /// Timestamp when the project was soft-deleted (absent for live projects)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 // Custom FromRow implementation to handle UUID as TEXT in SQLite
@@ -43,8 +67,10 @@ impl FromRow<'_, SqliteRow> for Project {
             repository_url: row.try_get("repository_url")?,
             language: row.try_get("language")?,
             rating: row.try_get("rating")?,
+            tags: decode_tags(row.try_get("tags")?)?,
             created_at: row.try_get("created_at")?,
             updated_at: row.try_get("updated_at")?,
+            deleted_at: row.try_get("deleted_at")?,
         })
     }
 }
@@ -64,8 +90,10 @@ impl Project {
     ///     repository_url: "https://github.com/user/repo".to_string(),
     ///     language: "Rust".to_string(),
     ///     rating: Some(4.5),
+    ///     tags: None,
     ///     technology_ids: None,
     ///     user_ids: None,
+    ///     users: None,
     /// };
     ///
     /// let project = Project::new(request);
@@ -80,8 +108,10 @@ impl Project {
             repository_url: request.repository_url,
             language: request.language,
             rating: request.rating,
+            tags: normalize_tags(request.tags.unwrap_or_default()),
             created_at: now,
             updated_at: now,
+            deleted_at: None,
         }
     }
 
@@ -103,8 +133,11 @@ impl Project {
         if let Some(language) = update.language {
             self.language = language;
         }
-        if update.rating.is_some() {
-            self.rating = update.rating;
+        if let Some(rating) = update.rating {
+            self.rating = rating;
+        }
+        if let Some(tags) = update.tags {
+            self.tags = normalize_tags(tags);
         }
         self.updated_at = Utc::now();
     }
@@ -116,7 +149,7 @@ pub struct CreateProjectRequest {
     /// Name of the project
     #[validate(length(
         min = 1,
-        max = 255,
+        max = PROJECT_NAME_MAX_LEN,
         message = "Name must be between 1 and 255 characters"
     ))]
     pub name: String,
@@ -124,32 +157,191 @@ pub struct CreateProjectRequest {
     /// Description of the project
     #[validate(length(
         min = 1,
-        max = 2000,
+        max = PROJECT_DESCRIPTION_MAX_LEN,
         message = "Description must be between 1 and 2000 characters"
     ))]
     pub description: String,
 
     /// Repository URL
-    #[validate(url(message = "Repository URL must be a valid URL"))]
+    #[validate(custom(function = "validate_repository_url"))]
     pub repository_url: String,
 
     /// Programming language
     #[validate(length(
         min = 1,
-        max = 100,
+        max = PROJECT_LANGUAGE_MAX_LEN,
         message = "Language must be between 1 and 100 characters"
     ))]
     pub language: String,
 
     /// Optional rating (0.0 - 5.0)
-    #[validate(range(min = 0.0, max = 5.0, message = "Rating must be between 0.0 and 5.0"))]
+    #[validate(range(min = RATING_MIN, max = RATING_MAX, message = "Rating must be between 0.0 and 5.0"))]
     pub rating: Option<f64>,
 
+    /// Optional tags (at most 20 of up to 50 characters; stored trimmed and lowercased)
+    #[serde(default)]
+    #[validate(custom(function = "validate_tags"))]
+    pub tags: Option<Vec<String>>,
+
     /// Optional technology IDs to associate with the project
     pub technology_ids: Option<Vec<Uuid>>,
 
-    /// Optional user IDs to associate with the project
+    /// Optional user IDs to associate with the project (the first becomes the
+    /// owner, the rest contributors)
     pub user_ids: Option<Vec<Uuid>>,
+
+    /// Optional members with explicit roles (exactly one owner); use instead of `user_ids`
+    pub users: Option<Vec<ProjectMemberRequest>>,
+}
+
+/// Checks the tag count and the length of each (trimmed) tag
+fn validate_tags(tags: &[String]) -> Result<(), ValidationError> {
+    let tags = normalize_tags(tags.to_vec());
+    if tags.len() > MAX_PROJECT_TAGS {
+        return Err(ValidationError::new("tags_count")
+            .with_message(format!("At most {} tags are allowed", MAX_PROJECT_TAGS).into()));
+    }
+    if tags.iter().any(|tag| tag.chars().count() > PROJECT_TAG_MAX_LEN) {
+        return Err(ValidationError::new("tag_length")
+            .with_message(format!("Tags must be at most {} characters", PROJECT_TAG_MAX_LEN).into()));
+    }
+
+    Ok(())
+}
+
+/// Trims and lowercases tags, dropping empty and repeated ones (first occurrence wins)
+pub fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if !tag.is_empty() && !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    normalized
+}
+
+/// Encodes tags for the `projects.tags` JSON column
+pub fn encode_tags(tags: &[String]) -> String {
+    serde_json::to_string(tags).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Decodes the `projects.tags` JSON column
+pub fn decode_tags(json: String) -> Result<Vec<String>, sqlx::Error> {
+    serde_json::from_str(&json).map_err(|e| sqlx::Error::Decode(Box::new(e)))
+}
+
+/// Value of the `projects.search_blob` column: name and description folded by [`search_key`]
+pub fn search_blob(name: &str, description: &str) -> String {
+    format!("{}\n{}", search_key(name), search_key(description))
+}
+
+/// Canonical form of a repository URL, used to spot projects sharing a repository
+///
+/// The scheme and host are lowercased, and trailing slashes and a `.git` suffix are
+/// dropped, so `https://GitHub.com/user/repo.git/` matches `https://github.com/user/repo`.
+pub fn normalize_repository_url(url: &str) -> String {
+    let url = url.trim();
+    let (scheme, rest) = url.split_once("://").unwrap_or(("", url));
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+
+    let mut path = path.trim_end_matches('/');
+    if let Some(stripped) = path.strip_suffix(".git") {
+        path = stripped.trim_end_matches('/');
+    }
+
+    let mut normalized = format!("{}://{}", scheme.to_lowercase(), host.to_lowercase());
+    if !path.is_empty() {
+        normalized.push('/');
+        normalized.push_str(path);
+    }
+    normalized
+}
+
+/// Accepts only well-formed `http` / `https` URLs as repository URLs
+fn validate_repository_url(url: &str) -> Result<(), ValidationError> {
+    if !url.validate_url() {
+        return Err(ValidationError::new("url").with_message("Repository URL must be a valid URL".into()));
+    }
+
+    let scheme = url.split(':').next().unwrap_or_default().to_ascii_lowercase();
+    if scheme != "http" && scheme != "https" {
+        return Err(ValidationError::new("url_scheme")
+            .with_message("Repository URL must use the http or https scheme".into()));
+    }
+
+    Ok(())
+}
+
+/// Deserializes a field that is present in the payload (possibly `null`) as `Some`
+///
+/// Combined with `#[serde(default)]`, an omitted field stays `None`, so
+/// `Option<Option<T>>` can tell "leave unchanged" apart from "set to null".
+fn deserialize_present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Query options for creating a project
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CreateProjectOptions {
+    /// Add a warning (instead of failing) when a project with the same name exists
+    #[serde(default)]
+    pub warn_duplicate_name: bool,
+}
+
+/// Query options for `POST /projects/import`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportOptions {
+    /// Skip invalid records and report them instead of rejecting the whole import
+    #[serde(default)]
+    pub partial: bool,
+    /// Create technologies that match neither an existing id nor an existing name
+    #[serde(default)]
+    pub create_technologies: bool,
+}
+
+/// Outcome of `POST /projects/import`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct ImportSummary {
+    /// Projects that did not exist yet
+    pub inserted: u64,
+    /// Existing projects overwritten by their record
+    pub updated: u64,
+    /// Records left out because they were invalid (only with `partial=true`)
+    pub skipped: u64,
+    /// Why each skipped record was left out (omitted when empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ImportRecordError>,
+}
+
+/// A record `POST /projects/import` could not apply
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ImportRecordError {
+    /// Zero-based position of the record in the payload
+    pub index: usize,
+    /// Project id of the record, when it could be read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Uuid>,
+    /// What was wrong with it
+    pub message: String,
+}
+
+/// Query options for `GET /projects/{id}/similar`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SimilarProjectsQuery {
+    /// Maximum number of projects to return (default 10, capped like `page_size`)
+    pub limit: Option<u32>,
+}
+
+impl SimilarProjectsQuery {
+    /// Requested limit clamped to `1..=max_page_size` (the configured cap)
+    pub fn limit(&self, max_page_size: u32) -> u32 {
+        self.limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, max_page_size.max(1))
+    }
 }
 
 /// Request payload for updating an existing project
@@ -158,7 +350,7 @@ pub struct UpdateProjectRequest {
     /// Optional new name for the project
     #[validate(length(
         min = 1,
-        max = 255,
+        max = PROJECT_NAME_MAX_LEN,
         message = "Name must be between 1 and 255 characters"
     ))]
     pub name: Option<String>,
@@ -166,32 +358,102 @@ pub struct UpdateProjectRequest {
     /// Optional new description
     #[validate(length(
         min = 1,
-        max = 2000,
+        max = PROJECT_DESCRIPTION_MAX_LEN,
         message = "Description must be between 1 and 2000 characters"
     ))]
     pub description: Option<String>,
 
     /// Optional new repository URL
-    #[validate(url(message = "Repository URL must be a valid URL"))]
+    #[validate(custom(function = "validate_repository_url"))]
     pub repository_url: Option<String>,
 
     /// Optional new language
     #[validate(length(
         min = 1,
-        max = 100,
+        max = PROJECT_LANGUAGE_MAX_LEN,
         message = "Language must be between 1 and 100 characters"
     ))]
     pub language: Option<String>,
 
-    /// Optional new rating (0.0 - 5.0)
-    #[validate(range(min = 0.0, max = 5.0, message = "Rating must be between 0.0 and 5.0"))]
-    pub rating: Option<f64>,
+    /// Optional new rating (0.0 - 5.0); omit to keep it, `null` to clear it
+    #[serde(default, deserialize_with = "deserialize_present", skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<f64>, nullable)]
+    #[validate(range(min = RATING_MIN, max = RATING_MAX, message = "Rating must be between 0.0 and 5.0"))]
+    pub rating: Option<Option<f64>>,
+
+    /// Optional tags replacing the current ones (same limits as on create)
+    #[serde(default)]
+    #[validate(custom(function = "validate_tags"))]
+    pub tags: Option<Vec<String>>,
 
     /// Optional technology IDs to replace existing associations
     pub technology_ids: Option<Vec<Uuid>>,
 
     /// Optional user IDs to replace existing associations
     pub user_ids: Option<Vec<Uuid>>,
+
+    /// `updated_at` the client last saw; the update fails with `412` if the project changed since
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_updated_at: Option<DateTime<Utc>>,
+}
+
+/// Rating change for a single project in a bulk update
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RatingUpdate {
+    /// Project to update
+    pub id: Uuid,
+    /// New rating (0.0 - 5.0)
+    #[validate(range(min = RATING_MIN, max = RATING_MAX, message = "Rating must be between 0.0 and 5.0"))]
+    pub rating: f64,
+}
+
+/// Outcome of a bulk rating update
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkRatingUpdateResult {
+    /// Projects whose rating was updated
+    pub updated: Vec<Uuid>,
+    /// Requested projects that do not exist (skipped)
+    pub missing: Vec<Uuid>,
+}
+
+/// Outcome of `POST /projects/validate` for a payload that passed every check
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectValidation {
+    /// Always `true`; invalid payloads get the same error response as a create
+    pub valid: bool,
+}
+
+/// Aggregate figures over all live projects
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectStats {
+    /// Number of live projects
+    pub total_projects: i64,
+    /// Average rating of rated projects (`null` when none are rated)
+    pub average_rating: Option<f64>,
+    /// Projects per language (lowercased, trimmed), most used first
+    pub languages: Vec<LanguageCount>,
+    /// Projects per technology, most used first; unused technologies are omitted
+    pub technologies: Vec<TechnologyCount>,
+}
+
+/// Number of projects written in a language
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, FromRow)]
+pub struct LanguageCount {
+    /// Language as matched by the `language` filter (lowercased, trimmed)
+    pub language: String,
+    /// Number of live projects in this language
+    pub count: i64,
+}
+
+/// Number of projects using a technology
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TechnologyCount {
+    /// Technology ID
+    pub technology_id: Uuid,
+    /// Technology name
+    pub name: String,
+    /// Number of live projects using this technology
+    pub count: i64,
 }
 
 /// Project with embedded related data
@@ -201,9 +463,36 @@ pub struct ProjectWithRelations {
     #[serde(flatten)]
     pub project: Project,
     /// Associated technologies
-    pub technologies: Vec<Technology>,
+    pub technologies: Vec<ProjectTechnology>,
     /// Associated users with their roles
     pub users: Vec<UserWithRole>,
+    /// Non-fatal notices about the request (omitted when empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Project as seen from one of its members, with that member's role
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectWithRole {
+    /// Project information
+    #[serde(flatten)]
+    pub project: Project,
+    /// The member's role in the project
+    pub role: UserRole,
+    /// Timestamp when the member was added to the project
+    pub associated_at: DateTime<Utc>,
+}
+
+// Expects the project columns plus the membership's `role` and `created_at` as `associated_at`
+impl FromRow<'_, SqliteRow> for ProjectWithRole {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        let role: String = row.try_get("role")?;
+        Ok(ProjectWithRole {
+            project: Project::from_row(row)?,
+            role: role.parse().map_err(|e: String| sqlx::Error::Decode(e.into()))?,
+            associated_at: row.try_get("associated_at")?,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -218,8 +507,10 @@ mod tests {
             repository_url: "https://github.com/test/repo".to_string(),
             language: "Rust".to_string(),
             rating: Some(4.5),
+            tags: None,
             technology_ids: None,
             user_ids: None,
+            users: None,
         };
 
         let project = Project::new(request.clone());
@@ -238,8 +529,10 @@ mod tests {
             repository_url: "https://github.com/original/repo".to_string(),
             language: "Rust".to_string(),
             rating: None,
+            tags: None,
             technology_ids: None,
             user_ids: None,
+            users: None,
         };
 
         let mut project = Project::new(create_request);
@@ -250,9 +543,11 @@ mod tests {
             description: None,
             repository_url: None,
             language: Some("Python".to_string()),
-            rating: Some(3.5),
+            rating: Some(Some(3.5)),
+            tags: None,
             technology_ids: None,
             user_ids: None,
+            expected_updated_at: None,
         };
 
         project.update(update_request);
@@ -264,4 +559,62 @@ mod tests {
         assert_eq!(project.created_at, original_created);
         assert!(project.updated_at > original_created);
     }
+
+    #[test]
+    fn test_repository_url_scheme() {
+        assert!(validate_repository_url("https://github.com/user/repo").is_ok());
+        assert!(validate_repository_url("HTTP://example.com/repo").is_ok());
+
+        let error = validate_repository_url("ftp://host/x").unwrap_err();
+        assert_eq!(error.code, "url_scheme");
+        assert!(validate_repository_url("javascript:alert(1)").is_err());
+
+        let error = validate_repository_url("not a url").unwrap_err();
+        assert_eq!(error.code, "url");
+
+        // Applied to updates too
+        let update = UpdateProjectRequest {
+            name: None,
+            description: None,
+            repository_url: Some("ftp://host/x".to_string()),
+            language: None,
+            rating: None,
+            tags: None,
+            technology_ids: None,
+            user_ids: None,
+            expected_updated_at: None,
+        };
+        assert!(update.validate().is_err());
+    }
+
+    #[test]
+    fn test_normalize_repository_url() {
+        let canonical = "https://github.com/user/Repo";
+        for url in [
+            "https://github.com/user/Repo",
+            "https://GitHub.com/user/Repo/",
+            "HTTPS://github.com/user/Repo.git",
+            " https://github.com/user/Repo.git/ ",
+        ] {
+            assert_eq!(normalize_repository_url(url), canonical, "{}", url);
+        }
+
+        // Paths keep their case
+        assert_ne!(normalize_repository_url("https://github.com/user/repo"), canonical);
+        assert_eq!(normalize_repository_url("https://Example.com/"), "https://example.com");
+    }
+
+    #[test]
+    fn test_tags_are_normalized_and_capped() {
+        let tags = vec![" Rust ".to_string(), "rust".to_string(), "".to_string(), "CLI".to_string()];
+        assert_eq!(normalize_tags(tags), vec!["rust", "cli"]);
+
+        let too_many: Vec<String> = (0..=MAX_PROJECT_TAGS).map(|i| format!("tag{}", i)).collect();
+        assert_eq!(validate_tags(&too_many).unwrap_err().code, "tags_count");
+        // Repeats collapse before counting
+        assert!(validate_tags(&vec!["same".to_string(); MAX_PROJECT_TAGS + 5]).is_ok());
+
+        let too_long = vec!["x".repeat(PROJECT_TAG_MAX_LEN + 1)];
+        assert_eq!(validate_tags(&too_long).unwrap_err().code, "tag_length");
+    }
 }
@@ -0,0 +1,99 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Row, sqlite::SqliteRow};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Lowest review score
+pub const REVIEW_SCORE_MIN: u8 = 1;
+/// Highest review score
+pub const REVIEW_SCORE_MAX: u8 = 5;
+/// Maximum length of a review comment
+pub const REVIEW_COMMENT_MAX_LEN: u64 = 2000;
+
+/// A user's review of a project; a project's rating is the average of its review scores
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Review {
+    /// Unique identifier for the review
+    pub id: Uuid,
+    /// Reviewed project
+    pub project_id: Uuid,
+    /// Reviewing user (one review per user and project)
+    pub user_id: Uuid,
+    /// Score from 1 to 5
+    pub score: u8,
+    /// Optional free-form comment
+    pub comment: Option<String>,
+    /// Timestamp when the review was created
+    pub created_at: DateTime<Utc>,
+}
+
+// Custom FromRow implementation to handle UUID as TEXT in SQLite
+impl FromRow<'_, SqliteRow> for Review {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        let parse = |column: &str| -> Result<Uuid, sqlx::Error> {
+            let value: String = row.try_get(column)?;
+            Uuid::parse_str(&value).map_err(|e| sqlx::Error::Decode(Box::new(e)))
+        };
+
+        Ok(Review {
+            id: parse("id")?,
+            project_id: parse("project_id")?,
+            user_id: parse("user_id")?,
+            score: row.try_get("score")?,
+            comment: row.try_get("comment")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+impl Review {
+    /// Creates a review of `project_id` from a request
+    pub fn new(project_id: Uuid, request: CreateReviewRequest) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            user_id: request.user_id,
+            score: request.score,
+            comment: request.comment,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Request payload for reviewing a project
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateReviewRequest {
+    /// Reviewing user
+    pub user_id: Uuid,
+    /// Score from 1 to 5
+    #[validate(range(min = REVIEW_SCORE_MIN, max = REVIEW_SCORE_MAX, message = "Score must be between 1 and 5"))]
+    pub score: u8,
+    /// Optional comment (max 2000 characters)
+    #[serde(default)]
+    #[validate(length(max = REVIEW_COMMENT_MAX_LEN, message = "Comment must be at most 2000 characters"))]
+    pub comment: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_review_score() {
+        let request = |score| CreateReviewRequest {
+            user_id: Uuid::new_v4(),
+            score,
+            comment: None,
+        };
+
+        assert!(request(1).validate().is_ok());
+        assert!(request(5).validate().is_ok());
+        assert!(request(0).validate().is_err());
+        assert!(request(6).validate().is_err());
+
+        let review = Review::new(Uuid::new_v4(), request(4));
+        assert_eq!(review.score, 4);
+    }
+}
@@ -5,6 +5,13 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::Validate;
 
+/// Maximum length of a technology name
+pub const TECHNOLOGY_NAME_MAX_LEN: u64 = 100;
+/// Maximum length of a technology description
+pub const TECHNOLOGY_DESCRIPTION_MAX_LEN: u64 = 500;
+/// Maximum number of names accepted by one resolve request
+pub const MAX_RESOLVE_TECHNOLOGIES: usize = 500;
+
 /// Represents a technology in the system
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Technology {
@@ -16,6 +23,8 @@ pub struct Technology {
     pub description: Option<String>,
     /// Timestamp when the technology was created
     pub created_at: DateTime<Utc>,
+    /// Timestamp when the technology was last modified (equals `created_at` until then)
+    pub updated_at: DateTime<Utc>,
 }
 
 // Custom FromRow implementation to handle UUID as TEXT in SQLite
@@ -30,41 +39,119 @@ impl FromRow<'_, SqliteRow> for Technology {
             name: row.try_get("name")?,
             description: row.try_get("description")?,
             created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
         })
     }
 }
 
 impl Technology {
+    /// Case-insensitive storage key used for uniqueness and lookups
+    ///
+    /// `name` keeps the client's casing for display; "React" and "react" share a key.
+    /// Only ASCII letters are folded, matching SQLite's `lower()`, which computes the
+    /// keys of rows written by SQL (the migration backfill and its insert trigger).
+    pub fn name_key(name: &str) -> String {
+        name.to_ascii_lowercase()
+    }
+
     /// Creates a new Technology from a CreateTechnologyRequest
     pub fn new(request: CreateTechnologyRequest) -> Self {
+        let now = Utc::now();
         Self {
             id: Uuid::new_v4(),
             name: request.name,
             description: request.description,
-            created_at: Utc::now(),
+            created_at: now,
+            updated_at: now,
         }
     }
 }
 
+/// Technology as associated with a project
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTechnology {
+    /// Technology information
+    #[serde(flatten)]
+    pub technology: Technology,
+    /// Timestamp when the technology was added to the project
+    pub associated_at: DateTime<Utc>,
+}
+
+// Expects the technology columns plus the association's `created_at` as `associated_at`
+impl FromRow<'_, SqliteRow> for ProjectTechnology {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        Ok(ProjectTechnology {
+            technology: Technology::from_row(row)?,
+            associated_at: row.try_get("associated_at")?,
+        })
+    }
+}
+
 /// Request payload for creating a new technology
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateTechnologyRequest {
     /// Name of the technology (must be unique)
     #[validate(length(
         min = 1,
-        max = 100,
+        max = TECHNOLOGY_NAME_MAX_LEN,
         message = "Name must be between 1 and 100 characters"
     ))]
     pub name: String,
 
     /// Optional description
     #[validate(length(
-        max = 500,
+        max = TECHNOLOGY_DESCRIPTION_MAX_LEN,
+        message = "Description must be at most 500 characters"
+    ))]
+    pub description: Option<String>,
+}
+
+/// Request payload for attaching a single technology to a project
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AttachTechnologyRequest {
+    /// Technology to attach
+    pub technology_id: Uuid,
+}
+
+/// Request payload for updating an existing technology
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateTechnologyRequest {
+    /// Optional new name (must stay unique)
+    #[validate(length(
+        min = 1,
+        max = TECHNOLOGY_NAME_MAX_LEN,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: Option<String>,
+
+    /// Optional new description
+    #[validate(length(
+        max = TECHNOLOGY_DESCRIPTION_MAX_LEN,
         message = "Description must be at most 500 characters"
     ))]
     pub description: Option<String>,
 }
 
+/// Source and target technologies for a merge (or a merge preview)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MergeTechnologiesRequest {
+    /// Technology to merge away (deleted by the merge)
+    pub from: Uuid,
+    /// Technology that receives the associations
+    pub into: Uuid,
+}
+
+/// Impact of merging one technology into another
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TechnologyMergeSummary {
+    /// Associations re-pointed from `from` to `into`
+    pub moved: i64,
+    /// Associations dropped because the project already uses `into`
+    pub duplicates_dropped: i64,
+    /// Projects that currently use `from`
+    pub affected_project_ids: Vec<Uuid>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
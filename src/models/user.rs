@@ -1,10 +1,15 @@
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, de};
 use sqlx::{FromRow, sqlite::SqliteRow, Row};
 use std::str::FromStr;
 use utoipa::ToSchema;
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidateUrl, ValidationError};
+
+/// Maximum length of a user name
+pub const USER_NAME_MAX_LEN: u64 = 255;
+/// Maximum length of a user bio
+pub const USER_BIO_MAX_LEN: u64 = 1000;
 
 /// Represents a user in the system
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -15,8 +20,14 @@ pub struct User {
     pub name: String,
     /// Email address (unique)
     pub email: String,
+    /// Optional http(s) URL of the user's avatar image
+    pub avatar_url: Option<String>,
+    /// Optional short biography
+    pub bio: Option<String>,
     /// Timestamp when the user was created
     pub created_at: DateTime<Utc>,
+    /// Timestamp when the user was last modified (equals `created_at` until then)
+    pub updated_at: DateTime<Utc>,
 }
 
 // Custom FromRow implementation to handle UUID as TEXT in SQLite
@@ -30,7 +41,10 @@ impl FromRow<'_, SqliteRow> for User {
             id,
             name: row.try_get("name")?,
             email: row.try_get("email")?,
+            avatar_url: row.try_get("avatar_url")?,
+            bio: row.try_get("bio")?,
             created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
         })
     }
 }
@@ -38,11 +52,15 @@ impl FromRow<'_, SqliteRow> for User {
 impl User {
     /// Creates a new User from a CreateUserRequest
     pub fn new(request: CreateUserRequest) -> Self {
+        let now = Utc::now();
         Self {
             id: Uuid::new_v4(),
             name: request.name,
             email: request.email,
-            created_at: Utc::now(),
+            avatar_url: request.avatar_url,
+            bio: request.bio,
+            created_at: now,
+            updated_at: now,
         }
     }
 }
@@ -53,7 +71,7 @@ pub struct CreateUserRequest {
     /// Name of the user
     #[validate(length(
         min = 1,
-        max = 255,
+        max = USER_NAME_MAX_LEN,
         message = "Name must be between 1 and 255 characters"
     ))]
     pub name: String,
@@ -61,10 +79,45 @@ pub struct CreateUserRequest {
     /// Email address (must be unique)
     #[validate(email(message = "Email must be a valid email address"))]
     pub email: String,
+
+    /// Optional avatar image URL (http or https)
+    #[serde(default)]
+    #[validate(custom(function = "validate_avatar_url"))]
+    pub avatar_url: Option<String>,
+
+    /// Optional short biography
+    #[serde(default)]
+    #[validate(length(max = USER_BIO_MAX_LEN, message = "Bio must be at most 1000 characters"))]
+    pub bio: Option<String>,
+}
+
+/// Request payload for updating an existing user
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateUserRequest {
+    /// Optional new name
+    #[validate(length(
+        min = 1,
+        max = USER_NAME_MAX_LEN,
+        message = "Name must be between 1 and 255 characters"
+    ))]
+    pub name: Option<String>,
+
+    /// Optional new email address (must stay unique)
+    #[validate(email(message = "Email must be a valid email address"))]
+    pub email: Option<String>,
+}
+
+fn validate_avatar_url(url: &str) -> Result<(), ValidationError> {
+    let scheme = url.split(':').next().unwrap_or_default().to_ascii_lowercase();
+    if !url.validate_url() || (scheme != "http" && scheme != "https") {
+        return Err(ValidationError::new("url").with_message("Avatar URL must be a valid http or https URL".into()));
+    }
+
+    Ok(())
 }
 
 /// User role in a project
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, ToSchema, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum UserRole {
     Owner,
@@ -73,6 +126,9 @@ pub enum UserRole {
 }
 
 impl UserRole {
+    /// Every role, in privilege order (highest first)
+    pub const ALL: [UserRole; 3] = [UserRole::Owner, UserRole::Contributor, UserRole::Viewer];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             UserRole::Owner => "owner",
@@ -80,6 +136,12 @@ impl UserRole {
             UserRole::Viewer => "viewer",
         }
     }
+
+    /// Whether this role grants at least the privileges of `min_role`
+    pub fn at_least(&self, min_role: UserRole) -> bool {
+        let rank = |role: &UserRole| UserRole::ALL.iter().position(|r| r == role);
+        rank(self) <= rank(&min_role)
+    }
 }
 
 impl FromStr for UserRole {
@@ -90,11 +152,40 @@ impl FromStr for UserRole {
             "owner" => Ok(UserRole::Owner),
             "contributor" => Ok(UserRole::Contributor),
             "viewer" => Ok(UserRole::Viewer),
-            _ => Err(format!("Invalid user role: {}", s)),
+            _ => {
+                let expected: Vec<&str> = UserRole::ALL.iter().map(UserRole::as_str).collect();
+                Err(format!("invalid role '{}', expected one of: {}", s, expected.join(", ")))
+            }
         }
     }
 }
 
+// Parsed through `FromStr` so an unknown role is reported with the accepted ones
+// rather than serde's generic "unknown variant" message
+impl<'de> Deserialize<'de> for UserRole {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let role = String::deserialize(deserializer)?;
+        role.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Desired membership of a single user in a project
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ProjectMemberRequest {
+    /// User to add to the project (`id` is accepted as an alias)
+    #[serde(alias = "id")]
+    pub user_id: Uuid,
+    /// Role of the user in the project
+    pub role: UserRole,
+}
+
+/// New role for an existing project member
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RoleUpdateRequest {
+    /// Role to assign
+    pub role: UserRole,
+}
+
 /// User with role in a project context
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UserWithRole {
@@ -103,6 +194,8 @@ pub struct UserWithRole {
     pub user: User,
     /// Role in the project
     pub role: UserRole,
+    /// Timestamp when the user was added to the project
+    pub associated_at: DateTime<Utc>,
 }
 
 #[cfg(test)]
@@ -114,6 +207,8 @@ mod tests {
         let request = CreateUserRequest {
             name: "John Doe".to_string(),
             email: "john@example.com".to_string(),
+            avatar_url: None,
+            bio: None,
         };
 
         let user = User::new(request.clone());
@@ -126,20 +221,57 @@ mod tests {
         let request = CreateUserRequest {
             name: "John Doe".to_string(),
             email: "invalid-email".to_string(),
+            avatar_url: None,
+            bio: None,
         };
 
         assert!(request.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_user_profile_fields() {
+        let request = |avatar_url: Option<&str>, bio: Option<String>| CreateUserRequest {
+            name: "John Doe".to_string(),
+            email: "john@example.com".to_string(),
+            avatar_url: avatar_url.map(str::to_string),
+            bio,
+        };
+
+        assert!(request(None, None).validate().is_ok());
+        assert!(request(Some("https://example.com/avatar.png"), Some("x".repeat(1000))).validate().is_ok());
+
+        for bad_url in ["not a url", "ftp://example.com/avatar.png", "javascript:alert(1)"] {
+            let errors = request(Some(bad_url), None).validate().unwrap_err();
+            assert!(errors.field_errors().contains_key("avatar_url"), "{} was accepted", bad_url);
+        }
+
+        let errors = request(None, Some("x".repeat(1001))).validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("bio"));
+    }
+
     #[test]
     fn test_user_role_conversion() {
         assert_eq!(UserRole::from_str("owner").unwrap(), UserRole::Owner);
         assert_eq!(UserRole::from_str("contributor").unwrap(), UserRole::Contributor);
         assert_eq!(UserRole::from_str("viewer").unwrap(), UserRole::Viewer);
-        assert!(UserRole::from_str("invalid").is_err());
+        assert_eq!(
+            UserRole::from_str("admin").unwrap_err(),
+            "invalid role 'admin', expected one of: owner, contributor, viewer"
+        );
+        assert_eq!(serde_json::from_str::<UserRole>("\"viewer\"").unwrap(), UserRole::Viewer);
+        assert!(serde_json::from_str::<UserRole>("\"Owner\"").is_err());
 
         assert_eq!(UserRole::Owner.as_str(), "owner");
         assert_eq!(UserRole::Contributor.as_str(), "contributor");
         assert_eq!(UserRole::Viewer.as_str(), "viewer");
     }
+
+    #[test]
+    fn test_user_role_privilege_order() {
+        assert!(UserRole::Owner.at_least(UserRole::Owner));
+        assert!(UserRole::Owner.at_least(UserRole::Viewer));
+        assert!(UserRole::Contributor.at_least(UserRole::Viewer));
+        assert!(!UserRole::Contributor.at_least(UserRole::Owner));
+        assert!(!UserRole::Viewer.at_least(UserRole::Contributor));
+    }
 }
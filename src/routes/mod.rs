@@ -1,19 +1,32 @@
 use axum::{
+    Extension,
     Json,
     Router,
-    routing::{delete, get, post, put},
+    extract::{DefaultBodyLimit, State},
+    http::header::CONTENT_TYPE,
+    response::IntoResponse,
+    middleware,
+    routing::{delete, get, patch, post, put},
 };
 use serde::Serialize;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 use crate::{
-    error::ErrorResponse,
+    error::{AppError, ErrorDetail, ErrorResponse},
     handlers,
+    middleware::{
+        BodyLimit, Metrics, RequestTimeout, SharedRateLimiter, api_key_middleware, auth_middleware, body_limit_middleware,
+        decompress_request_middleware, envelope_middleware, error_detail_middleware, metrics_middleware, rate_limit_middleware, request_id_middleware,
+        timeout_middleware,
+    },
     models::{
-        CreateProjectRequest, CreateTechnologyRequest, CreateUserRequest,
-        ListQueryParams, PaginatedResponse, PaginationMetadata, Project, ProjectWithRelations,
-        Technology, UpdateProjectRequest, User, UserRole, UserWithRole,
+        ApiKey, ApiMetadata, AttachTechnologyRequest, BulkRatingUpdateResult, CreateApiKeyRequest, CreateProjectRequest, CreateReviewRequest, CreateTechnologyRequest,
+        CreateUserRequest, CreatedApiKey, FieldLimit, ImportRecordError, ImportSummary, LanguageCount, ListQueryParams, MergeTechnologiesRequest, NumericBounds,
+        PaginatedResponse, PaginationMetadata, Project, ProjectMemberRequest, ProjectStats, ProjectTechnology,
+        ProjectValidation, ProjectWithRelations, ProjectWithRole, RatingUpdate, Review, RoleUpdateRequest, Technology, TechnologyCount, TechnologyMergeSummary,
+        UpdateProjectRequest, UpdateTechnologyRequest, UpdateUserRequest, User, UserRole, UserWithRole,
+        jsonapi::{Relationship, Resource, ResourceIdentifier},
     },
     state::AppState,
 };
@@ -24,27 +37,71 @@ use crate::{
     paths(
         crate::handlers::projects::list_projects,
         crate::handlers::projects::get_project,
+        crate::handlers::projects::get_similar_projects,
+        crate::handlers::projects::get_project_stats,
+        crate::handlers::projects::export_projects,
+        crate::handlers::projects::import_projects,
         crate::handlers::projects::create_project,
+        crate::handlers::projects::create_projects_bulk,
+        crate::handlers::projects::validate_project,
         crate::handlers::projects::update_project,
         crate::handlers::projects::delete_project,
+        crate::handlers::projects::restore_project,
+        crate::handlers::projects::update_project_ratings,
+        crate::handlers::projects::replace_project_users,
+        crate::handlers::projects::update_project_user_role,
+        crate::handlers::projects::copy_project_technologies,
+        crate::handlers::projects::add_project_technology,
+        crate::handlers::projects::remove_project_technology,
+        crate::handlers::reviews::list_project_reviews,
+        crate::handlers::reviews::create_project_review,
         crate::handlers::technologies::list_technologies,
+        crate::handlers::technologies::get_technology,
+        crate::handlers::technologies::get_technology_projects,
+        crate::handlers::technologies::list_unused_technologies,
         crate::handlers::technologies::create_technology,
+        crate::handlers::technologies::resolve_technologies,
+        crate::handlers::technologies::update_technology,
+        crate::handlers::technologies::delete_technology,
+        crate::handlers::technologies::preview_merge_technologies,
+        crate::handlers::technologies::merge_technologies,
         crate::handlers::users::list_users,
         crate::handlers::users::create_user,
+        crate::handlers::users::get_user,
+        crate::handlers::users::get_user_projects,
+        crate::handlers::users::update_user,
+        crate::handlers::users::delete_user,
+        crate::handlers::meta::get_meta,
+        crate::handlers::api_keys::list_api_keys,
+        crate::handlers::api_keys::create_api_key,
+        health_check,
+        readiness_check,
+        pool_health,
+        metrics_endpoint,
     ),
     components(
         schemas(
             Project, CreateProjectRequest, UpdateProjectRequest, ProjectWithRelations,
-            Technology, CreateTechnologyRequest,
-            User, CreateUserRequest, UserRole, UserWithRole,
-            PaginatedResponse<Project>, PaginationMetadata, ListQueryParams,
-            ErrorResponse, HealthResponse
+            RatingUpdate, BulkRatingUpdateResult, ProjectMemberRequest, RoleUpdateRequest, AttachTechnologyRequest,
+            ProjectWithRole, ProjectValidation, ProjectStats, LanguageCount, TechnologyCount, ImportSummary, ImportRecordError,
+            Review, CreateReviewRequest,
+            Technology, ProjectTechnology, CreateTechnologyRequest, UpdateTechnologyRequest,
+            MergeTechnologiesRequest, TechnologyMergeSummary,
+            User, CreateUserRequest, UpdateUserRequest, UserRole, UserWithRole,
+            PaginatedResponse<Project>, PaginatedResponse<Technology>, PaginatedResponse<User>,
+            PaginatedResponse<ProjectWithRole>, PaginationMetadata, ListQueryParams,
+            ApiMetadata, FieldLimit, NumericBounds,
+            ApiKey, CreateApiKeyRequest, CreatedApiKey,
+            Resource, ResourceIdentifier, Relationship,
+            ErrorResponse, HealthResponse, PoolHealth
         )
     ),
     tags(
         (name = "projects", description = "Project management endpoints"),
         (name = "technologies", description = "Technology management endpoints"),
         (name = "users", description = "User management endpoints"),
+        (name = "meta", description = "Validation metadata for clients"),
+        (name = "api-keys", description = "API key management (admin only)"),
         (name = "health", description = "Health check endpoints")
     ),
     info(
@@ -62,6 +119,21 @@ use crate::{
 )]
 pub struct ApiDoc;
 
+/// Path the OpenAPI spec is served at; `/api-docs/openapi.json` remains as an alias
+pub const OPENAPI_PATH: &str = "/api-docs/v1/openapi.json";
+
+/// The OpenAPI spec, with a `servers` entry for `public_base_url` when one is configured
+///
+/// Built at startup rather than by the derive alone, since the base URL is only
+/// known from the environment.
+pub fn openapi_spec(public_base_url: Option<&str>) -> utoipa::openapi::OpenApi {
+    let mut spec = ApiDoc::openapi();
+    if let Some(url) = public_base_url {
+        spec.servers = Some(vec![utoipa::openapi::Server::new(url)]);
+    }
+    spec
+}
+
 /// Health check response
 #[derive(Debug, Serialize, utoipa::ToSchema)]
 pub struct HealthResponse {
@@ -69,42 +141,178 @@ pub struct HealthResponse {
     pub status: String,
 }
 
+/// Database connection pool statistics
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct PoolHealth {
+    /// Connections currently open, idle or in use
+    pub size: u32,
+    /// Open connections waiting in the pool
+    pub idle: u32,
+    /// Connections checked out by requests (`size - idle`)
+    pub in_use: u32,
+    /// Upper bound on open connections; `in_use` reaching it means acquires start to wait
+    pub max_connections: u32,
+}
+
 /// Creates the application router with all routes configured
 ///
 /// # Routes
-/// - `GET /health` - Health check endpoint
+/// - `GET /health` - Health check endpoint (liveness, no dependencies touched)
+/// - `GET /health/ready` - Readiness check that verifies the database responds
+/// - `GET /health/pool` - Connection pool size, idle and in-use counts
+/// - `GET /metrics` - Request counters and latency histograms in Prometheus format
 /// - `GET /projects` - List all projects with advanced filtering and pagination
+/// - `GET /projects/stats` - Project count, average rating and per-language / per-technology counts
 /// - `GET /projects/{id}` - Get a specific project with relations
+/// - `GET /projects/{id}/similar` - Projects sharing the most technologies with a project
 /// - `POST /projects` - Create a new project
+/// - `POST /projects/bulk` - Create many projects in one transaction
 /// - `PUT /projects/{id}` - Update a project
-/// - `DELETE /projects/{id}` - Delete a project
-/// - `GET /technologies` - List all technologies
+/// - `PATCH /projects/{id}` - Partially update a project (`null` clears the rating)
+/// - `DELETE /projects/{id}` - Soft-delete a project
+/// - `POST /projects/{id}/restore` - Restore a soft-deleted project
+/// - `PATCH /projects/ratings` - Update the ratings of many projects at once
+/// - `PUT /projects/{id}/users` - Replace a project's members
+/// - `PUT /projects/{id}/users/{user_id}/role` - Change one member's role
+/// - `POST /projects/{id}/technologies` - Attach one technology to a project
+/// - `DELETE /projects/{id}/technologies/{tech_id}` - Detach one technology from a project
+/// - `POST /projects/{id}/technologies/copy-from/{source_id}` - Copy another project's technologies
+/// - `GET /technologies` - List technologies (paginated, supports `search`)
+/// - `GET /technologies/{id}` - Get a specific technology
+/// - `GET /technologies/unused` - List technologies not used by any project
 /// - `POST /technologies` - Create a new technology
-/// - `GET /users` - List all users
+/// - `PUT /technologies/{id}` - Update a technology
+/// - `DELETE /technologies/{id}` - Delete a technology not used by any project
+/// - `GET /technologies/merge/preview` - Preview merging one technology into another
+/// - `POST /technologies/merge` - Merge one technology into another
+/// - `GET /users` - List users (paginated, `search` over name and email)
 /// - `POST /users` - Create a new user
+/// - `GET /users/{id}` - Get a specific user
+/// - `PUT /users/{id}` - Update a user's name or email
+/// - `DELETE /users/{id}` - Delete a user and their memberships
+/// - `GET /meta` - Valid roles, sortable fields and validation limits
+/// - `GET /api-keys` - List API keys (admin only, metadata only)
+/// - `POST /api-keys` - Mint an API key, returning the plaintext key once (admin only)
 /// - `GET /swagger-ui` - Swagger UI documentation
+/// - `GET /api-docs/v1/openapi.json` - OpenAPI spec (also at `/api-docs/openapi.json`)
+///
+/// Single-resource responses (`GET /projects/{id}` and the `POST` creators) accept
+/// `?envelope=true` to be wrapped as `{ "data": ... }`.
+///
+/// When a [`crate::middleware::JwtAuth`] extension is layered on top, write routes
+/// require an `Authorization: Bearer <jwt>` header (reads too if configured). A
+/// valid `X-API-Key` header is accepted instead of a token.
 pub fn create_router(state: AppState) -> Router {
+    let metrics = Metrics::default();
+    let spec = openapi_spec(state.config.public_base_url.as_deref());
+
     // Create the API router
     let api_router = Router::new()
         // Health check
         .route("/health", get(health_check))
+        .route("/health/ready", get(readiness_check))
+        .route("/health/pool", get(pool_health))
+        .route("/metrics", get(metrics_endpoint))
+        .route("/meta", get(handlers::get_meta))
         // Projects routes
         .route("/projects", get(handlers::list_projects))
-        .route("/projects", post(handlers::create_project))
-        .route("/projects/{id}", get(handlers::get_project))
+        .route("/projects", post(handlers::create_project).layer(middleware::from_fn(envelope_middleware)))
+        .route("/projects/bulk", post(handlers::create_projects_bulk))
+        .route("/projects/validate", post(handlers::validate_project))
+        .route("/projects/stats", get(handlers::get_project_stats))
+        .route("/projects/export", get(handlers::export_projects))
+        .route("/projects/{id}", get(handlers::get_project).layer(middleware::from_fn(envelope_middleware)))
         .route("/projects/{id}", put(handlers::update_project))
+        .route("/projects/{id}", patch(handlers::update_project))
         .route("/projects/{id}", delete(handlers::delete_project))
+        .route("/projects/{id}/restore", post(handlers::restore_project))
+        .route("/projects/{id}/similar", get(handlers::get_similar_projects))
+        .route("/projects/{id}/reviews", get(handlers::list_project_reviews))
+        .route("/projects/{id}/reviews", post(handlers::create_project_review))
+        .route("/projects/ratings", patch(handlers::update_project_ratings))
+        .route("/projects/{id}/users", put(handlers::replace_project_users))
+        .route("/projects/{id}/users/{user_id}/role", put(handlers::update_project_user_role))
+        .route("/projects/{id}/technologies", post(handlers::add_project_technology))
+        .route(
+            "/projects/{id}/technologies/{tech_id}",
+            delete(handlers::remove_project_technology),
+        )
+        .route(
+            "/projects/{id}/technologies/copy-from/{source_id}",
+            post(handlers::copy_project_technologies),
+        )
         // Technologies routes
         .route("/technologies", get(handlers::list_technologies))
-        .route("/technologies", post(handlers::create_technology))
+        .route("/technologies", post(handlers::create_technology).layer(middleware::from_fn(envelope_middleware)))
+        .route("/technologies/{id}", get(handlers::get_technology))
+        .route("/technologies/{id}", put(handlers::update_technology))
+        .route("/technologies/{id}", delete(handlers::delete_technology))
+        .route("/technologies/{id}/projects", get(handlers::get_technology_projects))
+        .route("/technologies/unused", get(handlers::list_unused_technologies))
+        .route("/technologies/resolve", post(handlers::resolve_technologies))
+        .route("/technologies/merge", post(handlers::merge_technologies))
+        .route("/technologies/merge/preview", get(handlers::preview_merge_technologies))
         // Users routes
         .route("/users", get(handlers::list_users))
-        .route("/users", post(handlers::create_user))
+        .route("/users", post(handlers::create_user).layer(middleware::from_fn(envelope_middleware)))
+        .route("/users/{id}", get(handlers::get_user))
+        .route("/users/{id}", put(handlers::update_user))
+        .route("/users/{id}", delete(handlers::delete_user))
+        .route("/users/{id}/projects", get(handlers::get_user_projects))
+        // API key routes
+        .route("/api-keys", get(handlers::list_api_keys))
+        .route("/api-keys", post(handlers::create_api_key));
+
+    // Imports take whole exports, so they get a larger body limit than the other routes
+    let import_router = Router::new().route("/projects/import", post(handlers::import_projects));
+
+    let api_router = with_body_limit(api_router, state.config.max_body_bytes)
+        .merge(with_body_limit(import_router, state.config.max_import_bytes))
+        // Verify bearer tokens (writes always, reads when configured) once a JwtAuth is provided
+        .layer(middleware::from_fn(auth_middleware))
+        // Resolve X-API-Key headers first so a valid key stands in for a bearer token
+        .layer(middleware::from_fn_with_state(state.clone(), api_key_middleware))
+        // Abort requests running past the configured timeout with 504, still counted by metrics
+        .layer(middleware::from_fn_with_state(RequestTimeout(state.config.request_timeout), timeout_middleware))
+        // Count requests and time handlers per route template
+        .layer(middleware::from_fn(metrics_middleware))
+        .layer(Extension(metrics))
+        // Assign a correlation id before anything else runs, echoed as X-Request-Id
+        .layer(middleware::from_fn(request_id_middleware))
+        // Trim error responses of these routes and their middleware to the configured detail
+        // level; the rate limiter is layered later and trims its own (see `with_rate_limiter`)
+        .layer(middleware::from_fn_with_state(state.config.error_detail, error_detail_middleware))
         // Share state across all routes
         .with_state(state);
 
-    // Merge with Swagger UI (which doesn't need state)
-    api_router.merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
+    // Merge with Swagger UI (which doesn't need state); the unversioned spec path keeps
+    // working for clients generated before the versioned one existed
+    let legacy_spec = spec.clone();
+    api_router
+        .merge(SwaggerUi::new("/swagger-ui").url(OPENAPI_PATH, spec))
+        .route("/api-docs/openapi.json", get(move || async move { Json(legacy_spec) }))
+}
+
+/// Applies request decompression and a `max_bytes` body limit to the routes of `router`
+fn with_body_limit(router: Router<AppState>, max_bytes: usize) -> Router<AppState> {
+    router
+        // Transparently inflate gzip-compressed request bodies before extractors run
+        .layer(middleware::from_fn(decompress_request_middleware))
+        // Refuse oversized bodies with 413 before they are decompressed or deserialized
+        .layer(middleware::from_fn_with_state(BodyLimit(max_bytes), body_limit_middleware))
+        .layer(DefaultBodyLimit::max(max_bytes))
+}
+
+/// Rate limits every route of `app` with `rate_limiter`, answering `429 Too Many Requests` once a bucket is empty
+///
+/// The middleware sits inside the extension layer, so it finds the limiter in the
+/// request extensions. It runs outside the router's own error detail layer, so
+/// its 429s are trimmed to `error_detail` here. The server applies this to the
+/// whole router.
+pub fn with_rate_limiter(app: Router, rate_limiter: SharedRateLimiter, error_detail: ErrorDetail) -> Router {
+    app.layer(middleware::from_fn(rate_limit_middleware))
+        .layer(Extension(rate_limiter))
+        .layer(middleware::from_fn_with_state(error_detail, error_detail_middleware))
 }
 
 /// Health check endpoint
@@ -124,6 +332,71 @@ async fn health_check() -> Json<HealthResponse> {
     })
 }
 
+/// Readiness check endpoint
+///
+/// Runs `SELECT 1` against the database so load balancers stop routing to an
+/// instance whose pool is dead, while `/health` stays a cheap liveness check
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "API and database are ready", body = HealthResponse),
+        (status = 503, description = "Database unavailable", body = ErrorResponse)
+    )
+)]
+async fn readiness_check(State(state): State<AppState>) -> Result<Json<HealthResponse>, AppError> {
+    sqlx::query("SELECT 1").execute(&state.db).await.map_err(|error| {
+        tracing::error!("Readiness check failed: {}", error);
+        AppError::ServiceUnavailable(format!("Database is not reachable: {}", error))
+    })?;
+
+    Ok(Json(HealthResponse {
+        status: "READY".to_string(),
+    }))
+}
+
+/// Connection pool health endpoint
+///
+/// Reports how many pooled connections are open, idle and checked out, so a
+/// saturated pool can be told apart from a failing database when acquire
+/// timeouts surface as database errors
+#[utoipa::path(
+    get,
+    path = "/health/pool",
+    tag = "health",
+    responses(
+        (status = 200, description = "Connection pool statistics", body = PoolHealth)
+    )
+)]
+async fn pool_health(State(state): State<AppState>) -> Json<PoolHealth> {
+    let size = state.db.size();
+    let idle = u32::try_from(state.db.num_idle()).unwrap_or(u32::MAX).min(size);
+
+    Json(PoolHealth {
+        size,
+        idle,
+        in_use: size - idle,
+        max_connections: state.db.options().get_max_connections(),
+    })
+}
+
+/// Prometheus metrics endpoint
+///
+/// Renders request counters and handler latency histograms in the Prometheus
+/// text exposition format
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "health",
+    responses(
+        (status = 200, description = "Metrics in Prometheus text format", body = String, content_type = "text/plain")
+    )
+)]
+async fn metrics_endpoint(Extension(metrics): Extension<Metrics>) -> impl IntoResponse {
+    ([(CONTENT_TYPE, "text/plain; version=0.0.4")], metrics.render())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,7 +406,7 @@ mod tests {
     };
     use serde_json::json;
     use tower::ServiceExt;
-    use crate::state::tests::new_test_db;
+    use crate::state::{AppConfig, tests::new_test_db};
 
     #[tokio::test]
     async fn test_health_check() {
@@ -153,6 +426,79 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_readiness_check() {
+        let state = new_test_db().await;
+        let app = create_router(state.clone());
+        let request = || Request::builder().uri("/health/ready").body(Body::empty()).unwrap();
+
+        let response = app.clone().oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // A closed pool makes the instance unready
+        state.db.close().await;
+        let response = app.oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "SERVICE_UNAVAILABLE");
+    }
+
+    #[tokio::test]
+    async fn test_pool_health_reports_in_use_connections() {
+        let state = new_test_db().await;
+        let app = create_router(state.clone());
+        let pool_stats = || async {
+            let request = Request::builder().uri("/health/pool").body(Body::empty()).unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        };
+
+        let in_use = |stats: &serde_json::Value| stats["in_use"].as_u64().unwrap();
+        // Connections are handed back to the pool asynchronously; let setup settle
+        let settle = || tokio::time::sleep(std::time::Duration::from_millis(50));
+
+        settle().await;
+        let before = pool_stats().await;
+
+        // A held connection shows up as in use until it is returned
+        let connection = state.db.acquire().await.unwrap();
+        let during = pool_stats().await;
+        assert_eq!(in_use(&during), in_use(&before) + 1);
+        assert_eq!(during["size"].as_u64().unwrap(), during["idle"].as_u64().unwrap() + in_use(&during));
+        assert!(during["max_connections"].as_u64().unwrap() >= in_use(&during));
+
+        drop(connection);
+        settle().await;
+        assert_eq!(in_use(&pool_stats().await), in_use(&before));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_counts_requests() {
+        let state = new_test_db().await;
+        let app = create_router(state);
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        app.clone().oneshot(get("/health")).await.unwrap();
+        app.clone().oneshot(get("/health")).await.unwrap();
+        app.clone()
+            .oneshot(get(&format!("/projects/{}", uuid::Uuid::new_v4())))
+            .await
+            .unwrap();
+
+        let response = app.oneshot(get("/metrics")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let text = String::from_utf8(bytes.to_vec()).unwrap();
+
+        assert!(text.contains("http_requests_total{method=\"GET\",route=\"/health\",status=\"200\"} 2"));
+        // Labelled by route template, not the concrete id
+        assert!(text.contains("http_requests_total{method=\"GET\",route=\"/projects/{id}\",status=\"404\"} 1"));
+        assert!(text.contains("http_request_duration_seconds_count{method=\"GET\",route=\"/health\"} 2"));
+    }
+
     #[tokio::test]
     async fn test_create_project_endpoint() {
         let state = new_test_db().await;
@@ -180,6 +526,39 @@ mod tests {
         assert_eq!(response.status(), StatusCode::CREATED);
     }
 
+    #[tokio::test]
+    async fn test_create_project_reports_invalid_fields() {
+        let state = new_test_db().await;
+        let app = create_router(state);
+
+        let request_body = serde_json::json!({
+            "name": "",
+            "description": "Has problems",
+            "repository_url": "not a url",
+            "language": "Rust"
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/projects")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        assert_eq!(body["fields"]["name"][0], "Name must be between 1 and 255 characters");
+        assert_eq!(body["fields"]["repository_url"][0], "Repository URL must be a valid URL");
+        assert!(body["fields"].get("description").is_none());
+    }
+
     #[tokio::test]
     async fn test_swagger_ui_available() {
         let state = new_test_db().await;
@@ -198,4 +577,186 @@ mod tests {
         // Swagger UI should redirect or return content
         assert!(response.status() == StatusCode::OK || response.status() == StatusCode::MOVED_PERMANENTLY);
     }
+
+    #[tokio::test]
+    async fn test_rate_limited_router_returns_429() {
+        use crate::middleware::RateLimiter;
+        use std::sync::Arc;
+
+        // Same layering as the server: no refill, two reads and one write per client
+        let limiter: SharedRateLimiter = Arc::new(RateLimiter::new(0, 2).with_write_burst_size(1));
+        let app = with_rate_limiter(create_router(new_test_db().await), limiter.clone(), ErrorDetail::Full);
+        let get = || Request::builder().uri("/health").body(Body::empty()).unwrap();
+
+        for remaining in ["1", "0"] {
+            let response = app.clone().oneshot(get()).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()["x-ratelimit-remaining"], remaining);
+        }
+        let response = app.clone().oneshot(get()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()["x-ratelimit-limit"], "2");
+        assert!(response.headers().contains_key("retry-after"));
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "RATE_LIMITED");
+        assert_eq!(body["error"], "Rate limit exceeded. Please try again later.");
+
+        // At the minimal detail level the 429 carries only the generic message
+        let minimal = with_rate_limiter(create_router(new_test_db().await), limiter, ErrorDetail::Minimal);
+        let response = minimal.oneshot(get()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(response.headers().contains_key("retry-after"));
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "RATE_LIMITED");
+        assert_eq!(body["error"], "Too many requests");
+
+        // Writes draw from their own bucket, and another client is unaffected
+        let post = |ip: &str| {
+            Request::builder()
+                .method("POST")
+                .uri("/users")
+                .header("content-type", "application/json")
+                .header("x-forwarded-for", ip)
+                .body(Body::from(r#"{"name": "Alice", "email": "alice@example.com"}"#))
+                .unwrap()
+        };
+        let response = app.clone().oneshot(post("10.0.0.1")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let response = app.clone().oneshot(post("10.0.0.1")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        let response = app.oneshot(post("10.0.0.2")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_openapi_spec_lists_public_base_url() {
+        let state = new_test_db().await.with_app_config(AppConfig {
+            public_base_url: Some("https://api.example.com".to_string()),
+            ..Default::default()
+        });
+        let app = create_router(state);
+
+        for uri in [OPENAPI_PATH, "/api-docs/openapi.json"] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{}", uri);
+
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let spec: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+            assert_eq!(spec["servers"], json!([{ "url": "https://api.example.com" }]), "{}", uri);
+            assert!(spec["paths"].get("/projects").is_some());
+        }
+
+        // Without a configured URL the spec has no servers block
+        assert!(openapi_spec(None).servers.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_patch_project_rating_null_vs_omitted() {
+        let state = new_test_db().await;
+        let app = create_router(state);
+
+        async fn send(app: &Router, method: &str, uri: &str, body: serde_json::Value) -> serde_json::Value {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method(method)
+                        .uri(uri)
+                        .header("content-type", "application/json")
+                        .body(Body::from(body.to_string()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert!(response.status().is_success());
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice(&bytes).unwrap()
+        }
+
+        let created = send(&app, "POST", "/projects", json!({
+            "name": "Patchable",
+            "description": "PATCH semantics",
+            "repository_url": "https://github.com/test/patch",
+            "language": "Rust",
+            "rating": 4.0
+        }))
+        .await;
+        let uri = format!("/projects/{}", created["id"].as_str().unwrap());
+
+        // Omitted rating is left unchanged
+        let patched = send(&app, "PATCH", &uri, json!({})).await;
+        assert_eq!(patched["rating"], 4.0);
+
+        // Explicit null clears it
+        let patched = send(&app, "PATCH", &uri, json!({ "rating": null })).await;
+        assert!(patched["rating"].is_null());
+
+        // And it can be set again
+        let patched = send(&app, "PATCH", &uri, json!({ "rating": 2.5 })).await;
+        assert_eq!(patched["rating"], 2.5);
+    }
+
+    #[tokio::test]
+    async fn test_tech_alias_deprecation_headers() {
+        let state = new_test_db().await;
+        let app = create_router(state);
+
+        let get = |uri: &'static str| {
+            let app = app.clone();
+            async move {
+                app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                    .await
+                    .unwrap()
+            }
+        };
+
+        // Deprecated alias: still honored, but flagged
+        let response = get("/projects?tech=rust").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["deprecation"], "true");
+        assert_eq!(response.headers()["sunset"], crate::models::pagination::TECH_ALIAS_SUNSET);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(body["warnings"][0].as_str().unwrap().contains("'tech'"));
+
+        // Canonical name: no deprecation signal
+        let response = get("/projects?technology=rust").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get("deprecation").is_none());
+        assert!(response.headers().get("sunset").is_none());
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(body.get("warnings").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_list_projects_rejects_unknown_sort() {
+        let state = new_test_db().await;
+        let app = create_router(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/projects?sort=bogus")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "VALIDATION_ERROR");
+        let message = body["error"].as_str().unwrap();
+        for field in crate::models::pagination::PROJECT_SORT_FIELDS {
+            assert!(message.contains(field));
+        }
+    }
 }
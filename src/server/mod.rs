@@ -0,0 +1,292 @@
+use axum::{Router, extract::Request};
+use hyper::body::Incoming;
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo, TokioTimer},
+    server::{conn::auto, graceful::GracefulShutdown},
+    service::TowerToHyperService,
+};
+use std::future::Future;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tower::ServiceExt;
+
+/// Connection-level server tuning
+///
+/// Controls protocol support and keep-alive behaviour of the hyper connection
+/// builder used by [`serve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerConfig {
+    /// Accept HTTP/2 (prior knowledge) in addition to HTTP/1.1
+    pub http2: bool,
+    /// Keep HTTP/1.1 connections open between requests
+    pub http1_keep_alive: bool,
+    /// How long an HTTP/1.1 connection may sit idle waiting for request headers
+    pub idle_timeout: Duration,
+    /// Interval between HTTP/2 keep-alive pings (`None` disables pings)
+    pub http2_keep_alive_interval: Option<Duration>,
+    /// How long to wait for an HTTP/2 keep-alive ping acknowledgement
+    pub http2_keep_alive_timeout: Duration,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            http2: true,
+            http1_keep_alive: true,
+            idle_timeout: Duration::from_secs(60),
+            http2_keep_alive_interval: Some(Duration::from_secs(20)),
+            http2_keep_alive_timeout: Duration::from_secs(20),
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Builds the hyper connection builder with this configuration applied
+    pub fn builder(&self) -> auto::Builder<TokioExecutor> {
+        let mut builder = auto::Builder::new(TokioExecutor::new());
+
+        builder
+            .http1()
+            .timer(TokioTimer::new())
+            .keep_alive(self.http1_keep_alive)
+            .header_read_timeout(self.idle_timeout);
+
+        builder
+            .http2()
+            .timer(TokioTimer::new())
+            .keep_alive_interval(self.http2_keep_alive_interval)
+            .keep_alive_timeout(self.http2_keep_alive_timeout);
+
+        if self.http2 {
+            builder
+        } else {
+            builder.http1_only()
+        }
+    }
+}
+
+/// Serves `app` on `listener` until `shutdown` resolves
+///
+/// Once `shutdown` completes the listener stops accepting connections and
+/// in-flight connections are allowed to finish before this function returns.
+/// The peer address is inserted into each request's extensions as a
+/// [`std::net::SocketAddr`] so middleware such as the rate limiter can key on it.
+pub async fn serve<F>(
+    listener: TcpListener,
+    app: Router,
+    config: ServerConfig,
+    shutdown: F,
+) -> std::io::Result<()>
+where
+    F: Future<Output = ()> + Send,
+{
+    let builder = config.builder();
+    let graceful = GracefulShutdown::new();
+    tokio::pin!(shutdown);
+
+    loop {
+        let (stream, remote_addr) = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    tracing::warn!("Failed to accept connection: {}", e);
+                    continue;
+                }
+            },
+            _ = &mut shutdown => break,
+        };
+
+        let service = app.clone().map_request(move |mut request: Request<Incoming>| {
+            request.extensions_mut().insert(remote_addr);
+            request
+        });
+
+        let connection = builder
+            .serve_connection(TokioIo::new(stream), TowerToHyperService::new(service))
+            .into_owned();
+        let connection = graceful.watch(connection);
+
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::debug!("Connection from {} closed with error: {}", remote_addr, e);
+            }
+        });
+    }
+
+    drop(listener);
+    tracing::info!("Waiting for in-flight connections to finish");
+    graceful.shutdown().await;
+
+    Ok(())
+}
+
+/// Resolves once the process receives SIGINT (Ctrl+C) or, on Unix, SIGTERM
+///
+/// Pass it as the `shutdown` future of [`serve`] so a rolling deploy lets
+/// in-flight requests finish instead of cutting them off.
+pub async fn shutdown_signal() {
+    let interrupt = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for SIGINT: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    wait_for_shutdown(interrupt, terminate).await;
+}
+
+/// Waits for whichever of the interrupt / terminate triggers fires first
+async fn wait_for_shutdown<I, T>(interrupt: I, terminate: T)
+where
+    I: Future<Output = ()>,
+    T: Future<Output = ()>,
+{
+    tokio::select! {
+        _ = interrupt => tracing::info!("Received SIGINT, shutting down gracefully"),
+        _ = terminate => tracing::info!("Received SIGTERM, shutting down gracefully"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+    use std::net::SocketAddr;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+
+    async fn spawn_server(config: ServerConfig) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route("/health", get(|| async { "OK" }));
+
+        tokio::spawn(serve(listener, app, config, std::future::pending()));
+        addr
+    }
+
+    #[test]
+    fn test_builder_protocols() {
+        assert!(ServerConfig::default().builder().is_http2_available());
+
+        let http1_only = ServerConfig {
+            http2: false,
+            ..Default::default()
+        };
+        let builder = http1_only.builder();
+        assert!(builder.is_http1_available());
+        assert!(!builder.is_http2_available());
+    }
+
+    #[tokio::test]
+    async fn test_idle_timeout_is_applied() {
+        let addr = spawn_server(ServerConfig {
+            idle_timeout: Duration::from_millis(200),
+            ..Default::default()
+        })
+        .await;
+
+        // A keep-alive connection left idle after its first request is closed by the server
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut buf = Vec::new();
+        let read = tokio::time::timeout(Duration::from_secs(5), stream.read_to_end(&mut buf))
+            .await
+            .expect("idle connection was not closed");
+        assert!(read.is_ok());
+        assert!(String::from_utf8_lossy(&buf).starts_with("HTTP/1.1 200"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_shutdown_resolves_on_trigger() {
+        let (trigger, triggered) = tokio::sync::oneshot::channel::<()>();
+        let waiting = tokio::spawn(wait_for_shutdown(
+            async {
+                triggered.await.ok();
+            },
+            std::future::pending(),
+        ));
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!waiting.is_finished());
+
+        trigger.send(()).unwrap();
+        tokio::time::timeout(Duration::from_secs(5), waiting)
+            .await
+            .expect("shutdown future did not resolve")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_in_flight_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = Router::new().route(
+            "/slow",
+            get(|| async {
+                tokio::time::sleep(Duration::from_millis(300)).await;
+                "done"
+            }),
+        );
+        let (trigger, triggered) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn(serve(listener, app, ServerConfig::default(), async {
+            triggered.await.ok();
+        }));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /slow HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // Shut down while the request is still being handled; it still completes
+        trigger.send(()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.ends_with("done"));
+
+        tokio::time::timeout(Duration::from_secs(5), server)
+            .await
+            .expect("server did not stop")
+            .unwrap()
+            .unwrap();
+        assert!(TcpStream::connect(addr).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_serves_http1_requests() {
+        let addr = spawn_server(ServerConfig::default()).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.ends_with("OK"));
+    }
+}
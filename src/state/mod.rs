@@ -1,13 +1,227 @@
-use sqlx::{SqlitePool, sqlite::SqlitePoolOptions};
+use futures::{StreamExt, TryStreamExt, future::BoxFuture, stream};
+use sqlx::{
+    Sqlite, SqlitePool, Transaction,
+    sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions},
+};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::str::FromStr;
 use std::time::Duration;
 
+use crate::error::{AppError, ErrorDetail};
+use crate::middleware::{DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_IMPORT_BYTES, DEFAULT_REQUEST_TIMEOUT};
+use crate::models::pagination::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE, MAX_PAGE_SIZE_LIMIT, PageOverflow};
+use crate::models::project::{RATING_MAX, RATING_MIN, search_blob};
+
+pub mod retention;
+
+/// Caller role allowed to manage API keys
+pub const ADMIN_ROLE: &str = "admin";
+
+/// Role of the authenticated caller, inserted into request extensions by the auth layer
+///
+/// Requests without it are treated as public callers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallerRole(pub String);
+
+/// Runtime settings shared by handlers
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    /// Maximum number of DB operations a single request may run concurrently,
+    /// so one bulk request cannot monopolize the connection pool
+    pub max_concurrent_db_ops: usize,
+    /// Default page size per caller role (e.g. `admin` → 50); an explicit
+    /// `page_size` still takes precedence
+    pub role_page_sizes: HashMap<String, u32>,
+    /// Lowercased languages whose projects must carry a rating (none by default)
+    pub rating_required_languages: HashSet<String>,
+    /// Rating of projects with neither reviews nor a manual rating (`null` when `None`)
+    pub default_rating: Option<f64>,
+    /// Maximum number of projects that may exist (unlimited when `None`)
+    pub max_projects: Option<u64>,
+    /// Largest `page_size` (and similar limits) a client may request
+    pub max_page_size: u32,
+    /// What to do with a `page` past the last one: serve the last page or refuse it
+    pub page_overflow: PageOverflow,
+    /// Largest request body accepted, in bytes; bigger ones get `413 Payload Too Large`
+    pub max_body_bytes: usize,
+    /// Largest `POST /projects/import` body, in bytes; an export is usually far
+    /// bigger than any other request, so it gets its own limit
+    pub max_import_bytes: usize,
+    /// Longest a request may take before it is aborted with `504 Gateway Timeout`
+    pub request_timeout: Duration,
+    /// Refuse (`409 Conflict`) instead of warn about a `repository_url` another project already uses
+    pub reject_duplicate_repository_urls: bool,
+    /// Externally visible base URL (e.g. behind a reverse proxy), advertised in the
+    /// OpenAPI `servers` block; generated clients use relative paths without it
+    pub public_base_url: Option<String>,
+    /// How much of an error's message and database details responses expose
+    pub error_detail: ErrorDetail,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_db_ops: 4,
+            role_page_sizes: HashMap::new(),
+            rating_required_languages: HashSet::new(),
+            default_rating: None,
+            max_projects: None,
+            max_page_size: MAX_PAGE_SIZE,
+            page_overflow: PageOverflow::default(),
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            max_import_bytes: DEFAULT_MAX_IMPORT_BYTES,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            reject_duplicate_repository_urls: false,
+            public_base_url: None,
+            error_detail: ErrorDetail::default(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Default page size for the given caller, falling back to [`DEFAULT_PAGE_SIZE`]
+    pub fn default_page_size(&self, role: Option<&CallerRole>) -> u32 {
+        role.and_then(|role| self.role_page_sizes.get(&role.0))
+            .copied()
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+
+    /// Whether projects in `language` must have a rating (case-insensitive)
+    pub fn requires_rating(&self, language: &str) -> bool {
+        self.rating_required_languages
+            .contains(&language.trim().to_lowercase())
+    }
+}
+
+/// SQLite connection pool settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolConfig {
+    /// Maximum number of open connections
+    pub max_connections: u32,
+    /// Connections kept open even when idle
+    pub min_connections: u32,
+    /// How long a request waits for a free connection before failing
+    pub acquire_timeout: Duration,
+    /// Close connections idle for this long (never when `None`)
+    pub idle_timeout: Option<Duration>,
+    /// Enforce foreign key constraints (`PRAGMA foreign_keys`)
+    pub foreign_keys: bool,
+    /// Use write-ahead logging (`PRAGMA journal_mode=WAL`) so reads don't block on writers;
+    /// in-memory databases keep their memory journal
+    pub wal: bool,
+    /// How long a connection waits on a locked database before failing (`PRAGMA busy_timeout`)
+    pub busy_timeout: Duration,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: 5,
+            min_connections: 0,
+            acquire_timeout: Duration::from_secs(3),
+            idle_timeout: Some(Duration::from_secs(600)),
+            foreign_keys: true,
+            wal: true,
+            busy_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Opens a connection pool on `database_url` with `pool` settings, without running migrations
+///
+/// `min_connections` is capped at `max_connections`. The PRAGMAs in `pool` are
+/// applied to every connection the pool opens. Used for [`AppState`] and for side
+/// pools such as the database rate limiter's.
+pub async fn connect_pool(database_url: &str, pool: &PoolConfig) -> Result<SqlitePool, sqlx::Error> {
+    let mut options = SqliteConnectOptions::from_str(database_url)?
+        .foreign_keys(pool.foreign_keys)
+        .busy_timeout(pool.busy_timeout);
+    if pool.wal {
+        options = options.journal_mode(SqliteJournalMode::Wal);
+    }
+
+    SqlitePoolOptions::new()
+        .max_connections(pool.max_connections)
+        .min_connections(pool.min_connections.min(pool.max_connections))
+        .acquire_timeout(pool.acquire_timeout)
+        .idle_timeout(pool.idle_timeout)
+        .connect_with(options)
+        .await
+}
+
+/// Parses a comma-separated language list such as `Rust, Go` into lowercased entries
+pub fn parse_language_set(value: &str) -> HashSet<String> {
+    value
+        .split(',')
+        .map(|language| language.trim().to_lowercase())
+        .filter(|language| !language.is_empty())
+        .collect()
+}
+
+/// Parses a page size cap, which must lie within `1..=MAX_PAGE_SIZE_LIMIT`
+pub fn parse_max_page_size(value: &str) -> Result<u32, String> {
+    value
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .filter(|size| (1..=MAX_PAGE_SIZE_LIMIT).contains(size))
+        .ok_or_else(|| format!("Invalid max page size '{}', expected 1..={}", value.trim(), MAX_PAGE_SIZE_LIMIT))
+}
+
+/// Parses a default project rating, which must lie within `RATING_MIN..=RATING_MAX`
+pub fn parse_default_rating(value: &str) -> Result<f64, String> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|rating| (RATING_MIN..=RATING_MAX).contains(rating))
+        .ok_or_else(|| format!("Invalid default rating '{}', expected {}..={}", value.trim(), RATING_MIN, RATING_MAX))
+}
+
+/// Parses a `role=size` list such as `admin=50,internal=25`
+pub fn parse_role_page_sizes(value: &str) -> Result<HashMap<String, u32>, String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (role, size) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid role page size entry: {}", entry))?;
+            let size = size
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|size| *size > 0)
+                .ok_or_else(|| format!("Invalid page size for role '{}': {}", role.trim(), size))?;
+            Ok((role.trim().to_string(), size))
+        })
+        .collect()
+}
+
+/// Sample data for development databases, applied by [`AppState::seed_test_data`]
+const SEED_TEST_DATA: &str = include_str!("../../seeds/seed_test_data.sql");
+
+/// Schema migrations
+///
+/// Databases created before the seed data left the migrations still list it as
+/// applied, so missing migrations are tolerated.
+fn migrator() -> sqlx::migrate::Migrator {
+    let mut migrator = sqlx::migrate!("./migrations");
+    migrator.set_ignore_missing(true);
+    migrator
+}
+
 /// Application state shared across handlers
 ///
-/// Contains the database connection pool for SQLite
+/// Contains the database connection pool for SQLite and runtime settings
 #[derive(Clone)]
 pub struct AppState {
     /// SQLite connection pool
     pub db: SqlitePool,
+    /// Runtime settings
+    pub config: AppConfig,
 }
 
 impl AppState {
@@ -28,18 +242,162 @@ impl AppState {
     /// }
     /// ```
     pub async fn new(database_url: &str) -> Result<Self, sqlx::Error> {
-        let db = SqlitePoolOptions::new()
-            .max_connections(5)
-            .acquire_timeout(Duration::from_secs(3))
-            .connect(database_url)
-            .await?;
+        Self::with_config(database_url, PoolConfig::default()).await
+    }
+
+    /// Creates a new AppState whose connection pool uses `pool` settings
+    ///
+    /// `min_connections` is capped at `max_connections`. The PRAGMAs in `pool`
+    /// are applied to every connection the pool opens.
+    pub async fn with_config(database_url: &str, pool: PoolConfig) -> Result<Self, sqlx::Error> {
+        let db = connect_pool(database_url, &pool).await?;
 
         // Run migrations
-        sqlx::migrate!("./migrations")
-            .run(&db)
-            .await?;
+        migrator().run(&db).await?;
+
+        let state = Self {
+            db,
+            config: AppConfig::default(),
+        };
+        let backfilled = state.backfill_search_blobs().await?;
+        if backfilled > 0 {
+            tracing::info!("Backfilled search_blob for {} projects", backfilled);
+        }
+        Ok(state)
+    }
+
+    /// Creates a new AppState like [`AppState::new`] and fills it with the sample data
+    pub async fn new_with_seed(database_url: &str) -> Result<Self, sqlx::Error> {
+        let state = Self::new(database_url).await?;
+        state.seed_test_data().await?;
+        Ok(state)
+    }
+
+    /// Loads the sample technologies, users and projects from `seeds/seed_test_data.sql`
+    ///
+    /// Only an empty database is seeded, so calling this on every start is safe;
+    /// returns whether the data was inserted.
+    pub async fn seed_test_data(&self) -> Result<bool, sqlx::Error> {
+        let has_data: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM projects) OR EXISTS(SELECT 1 FROM technologies) OR EXISTS(SELECT 1 FROM users)",
+        )
+        .fetch_one(&self.db)
+        .await?;
+        if has_data {
+            tracing::info!("Database already has data, skipping seed data");
+            return Ok(false);
+        }
+
+        let mut tx = self.db.begin().await?;
+        sqlx::raw_sql(SEED_TEST_DATA).execute(&mut *tx).await?;
+        tx.commit().await?;
+        self.backfill_search_blobs().await?;
 
-        Ok(Self { db })
+        tracing::info!("Seeded database with test data");
+        Ok(true)
+    }
+
+    /// Replaces the runtime settings
+    pub fn with_app_config(mut self, config: AppConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Fills `search_blob` for projects stored without one
+    ///
+    /// Rows from before the column existed, or inserted by raw SQL (such as the
+    /// seed data), only match ASCII case-insensitive searches until this runs.
+    /// `trg_projects_updated_at` only fires on content columns, so `updated_at` is
+    /// left alone. Returns the number of projects filled.
+    pub async fn backfill_search_blobs(&self) -> Result<u64, sqlx::Error> {
+        let mut tx = self.db.begin().await?;
+
+        let projects: Vec<(String, String, String)> =
+            sqlx::query_as("SELECT id, name, description FROM projects WHERE search_blob IS NULL")
+                .fetch_all(&mut *tx)
+                .await?;
+        if projects.is_empty() {
+            return Ok(0);
+        }
+
+        for (id, name, description) in &projects {
+            sqlx::query("UPDATE projects SET search_blob = ? WHERE id = ?")
+                .bind(search_blob(name, description))
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+
+        Ok(projects.len() as u64)
+    }
+
+    /// Brings stored ratings in line with `default_rating`
+    ///
+    /// The effective rating is stored with each project, so projects with neither
+    /// reviews nor a manual rating are updated when the default changes between
+    /// runs. Returns the number of projects updated.
+    pub async fn apply_default_rating(&self) -> Result<u64, sqlx::Error> {
+        let updated = sqlx::query(
+            "UPDATE projects SET rating = ?
+             WHERE manual_rating IS NULL AND rating IS NOT ?
+             AND NOT EXISTS (SELECT 1 FROM reviews WHERE reviews.project_id = projects.id)",
+        )
+        .bind(self.config.default_rating)
+        .bind(self.config.default_rating)
+        .execute(&self.db)
+        .await?
+        .rows_affected();
+        Ok(updated)
+    }
+
+    /// Runs `op` for every item with at most `max_concurrent_db_ops` in flight
+    ///
+    /// Results are returned in input order; the first error aborts the batch.
+    pub async fn bounded_db_ops<I, F, Fut, T>(
+        &self,
+        items: I,
+        op: F,
+    ) -> Result<Vec<T>, AppError>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Fut,
+        Fut: Future<Output = Result<T, AppError>>,
+    {
+        let limit = self.config.max_concurrent_db_ops.max(1);
+        stream::iter(items).map(op).buffered(limit).try_collect().await
+    }
+
+    /// Runs `f` inside a database transaction
+    ///
+    /// The transaction commits when `f` returns `Ok` and rolls back when it
+    /// returns `Err`, so a failing step never leaves earlier writes behind.
+    ///
+    /// ```ignore
+    /// let count = state
+    ///     .with_transaction(|tx| Box::pin(async move {
+    ///         sqlx::query("DELETE FROM project_users").execute(&mut **tx).await?;
+    ///         Ok(1)
+    ///     }))
+    ///     .await?;
+    /// ```
+    pub async fn with_transaction<F, T>(&self, f: F) -> Result<T, AppError>
+    where
+        F: for<'c> FnOnce(&'c mut Transaction<'static, Sqlite>) -> BoxFuture<'c, Result<T, AppError>>,
+    {
+        let mut tx = self.db.begin().await?;
+        match f(&mut tx).await {
+            Ok(value) => {
+                tx.commit().await?;
+                Ok(value)
+            }
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    tracing::error!("Failed to roll back transaction: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
     }
 }
 
@@ -80,21 +438,13 @@ pub mod tests {
             .await
             .unwrap();
 
-        // Run migrations
-        sqlx::migrate!("./migrations")
-            .run(&db)
-            .await
-            .unwrap();
-
-        // Clear all seed data from tables to ensure clean test state
-        // This removes data inserted by seed_test_data.sql migration
-        sqlx::query("DELETE FROM project_users").execute(&db).await.unwrap();
-        sqlx::query("DELETE FROM project_technologies").execute(&db).await.unwrap();
-        sqlx::query("DELETE FROM projects").execute(&db).await.unwrap();
-        sqlx::query("DELETE FROM users").execute(&db).await.unwrap();
-        sqlx::query("DELETE FROM technologies").execute(&db).await.unwrap();
+        // Run migrations (schema only, no seed data)
+        migrator().run(&db).await.unwrap();
 
-        AppState { db }
+        AppState {
+            db,
+            config: AppConfig::default(),
+        }
     }
 
     #[tokio::test]
@@ -115,4 +465,276 @@ pub mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_name_key_migration_merges_case_variants() {
+        let database_url = format!("sqlite:file:name_key_{}?mode=memory&cache=shared", uuid::Uuid::new_v4().simple());
+        let db = SqlitePoolOptions::new().connect(&database_url).await.unwrap();
+
+        // Schema as it was before technologies had a name_key
+        let mut before = migrator();
+        before.migrations = before
+            .migrations
+            .iter()
+            .filter(|migration| migration.version < 20250110000000)
+            .cloned()
+            .collect::<Vec<_>>()
+            .into();
+        before.run(&db).await.unwrap();
+
+        sqlx::raw_sql(
+            "INSERT INTO technologies (id, name, created_at) VALUES
+                 ('t1', 'React', '2024-01-01T00:00:00Z'),
+                 ('t2', 'react', '2024-02-01T00:00:00Z'),
+                 ('t3', 'REACT', '2024-03-01T00:00:00Z'),
+                 ('t4', 'Vue', '2024-01-01T00:00:00Z');
+             INSERT INTO projects (id, name, description, repository_url, language, created_at, updated_at) VALUES
+                 ('p1', 'One', '', 'https://github.com/test/one', 'Rust', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z'),
+                 ('p2', 'Two', '', 'https://github.com/test/two', 'Rust', '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z');
+             INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES
+                 ('p1', 't1', '2024-01-01T00:00:00Z'),
+                 ('p1', 't2', '2024-01-01T00:00:00Z'),
+                 ('p2', 't3', '2024-01-01T00:00:00Z'),
+                 ('p2', 't4', '2024-01-01T00:00:00Z');",
+        )
+        .execute(&db)
+        .await
+        .unwrap();
+
+        migrator().run(&db).await.unwrap();
+
+        // The oldest variant survives and picks up the links of the others
+        let technologies: Vec<(String, String, String)> =
+            sqlx::query_as("SELECT id, name, name_key FROM technologies ORDER BY id")
+                .fetch_all(&db)
+                .await
+                .unwrap();
+        assert_eq!(
+            technologies,
+            vec![
+                ("t1".to_string(), "React".to_string(), "react".to_string()),
+                ("t4".to_string(), "Vue".to_string(), "vue".to_string()),
+            ]
+        );
+        let links: Vec<(String, String)> =
+            sqlx::query_as("SELECT project_id, technology_id FROM project_technologies ORDER BY project_id, technology_id")
+                .fetch_all(&db)
+                .await
+                .unwrap();
+        assert_eq!(
+            links,
+            vec![
+                ("p1".to_string(), "t1".to_string()),
+                ("p2".to_string(), "t1".to_string()),
+                ("p2".to_string(), "t4".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_new_state_has_no_seed_data() {
+        let database_url = format!("sqlite:file:empty_{}?mode=memory&cache=shared", uuid::Uuid::new_v4().simple());
+        let state = AppState::new(&database_url).await.unwrap();
+
+        for table in ["projects", "technologies", "users", "project_technologies", "project_users"] {
+            let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+                .fetch_one(&state.db)
+                .await
+                .unwrap();
+            assert_eq!(count, 0, "{} has seeded rows", table);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_with_seed_loads_sample_data_once() {
+        let database_url = format!("sqlite:file:seeded_{}?mode=memory&cache=shared", uuid::Uuid::new_v4().simple());
+        let state = AppState::new_with_seed(&database_url).await.unwrap();
+
+        let count = |table: &'static str| {
+            let db = state.db.clone();
+            async move {
+                sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM {}", table))
+                    .fetch_one(&db)
+                    .await
+                    .unwrap()
+            }
+        };
+        assert_eq!(count("technologies").await, 20);
+        assert_eq!(count("users").await, 8);
+        assert_eq!(count("projects").await, 12);
+
+        // A database with data is left alone
+        assert!(!state.seed_test_data().await.unwrap());
+        assert_eq!(count("projects").await, 12);
+    }
+
+    #[tokio::test]
+    async fn test_with_config_sizes_pool() {
+        let database_url = format!("sqlite:file:pool_{}?mode=memory&cache=shared", uuid::Uuid::new_v4().simple());
+        let state = AppState::with_config(
+            &database_url,
+            PoolConfig {
+                max_connections: 3,
+                min_connections: 1,
+                acquire_timeout: Duration::from_millis(200),
+                idle_timeout: None,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let mut held = Vec::new();
+        for _ in 0..3 {
+            held.push(state.db.acquire().await.unwrap());
+        }
+        assert_eq!(state.db.size(), 3);
+
+        // A fourth acquisition waits for the configured timeout, then fails
+        assert!(matches!(state.db.acquire().await, Err(sqlx::Error::PoolTimedOut)));
+
+        // Releasing one makes room again
+        held.pop();
+        assert!(state.db.acquire().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_with_config_applies_pragmas() {
+        let connect = |pool: PoolConfig| async move {
+            let database_url = format!("sqlite:file:pragmas_{}?mode=memory&cache=shared", uuid::Uuid::new_v4().simple());
+            AppState::with_config(&database_url, pool).await.unwrap()
+        };
+        let insert_orphan = |state: AppState| async move {
+            sqlx::query("INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, CURRENT_TIMESTAMP)")
+                .bind(uuid::Uuid::new_v4().to_string())
+                .bind(uuid::Uuid::new_v4().to_string())
+                .execute(&state.db)
+                .await
+        };
+
+        let state = connect(PoolConfig {
+            busy_timeout: Duration::from_millis(1500),
+            ..Default::default()
+        })
+        .await;
+        let foreign_keys: i64 = sqlx::query_scalar("PRAGMA foreign_keys").fetch_one(&state.db).await.unwrap();
+        assert_eq!(foreign_keys, 1);
+        let busy_timeout: i64 = sqlx::query_scalar("PRAGMA busy_timeout").fetch_one(&state.db).await.unwrap();
+        assert_eq!(busy_timeout, 1500);
+
+        // Associations pointing at missing rows are rejected
+        let error = insert_orphan(state).await.unwrap_err();
+        assert!(error.to_string().contains("FOREIGN KEY constraint failed"), "{}", error);
+
+        // ...unless enforcement is switched off
+        let state = connect(PoolConfig {
+            foreign_keys: false,
+            ..Default::default()
+        })
+        .await;
+        assert!(insert_orphan(state).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_with_transaction_commits_and_rolls_back() {
+        let state = new_test_db().await;
+        let insert = "INSERT INTO users (id, name, email, created_at) VALUES (?, ?, ?, CURRENT_TIMESTAMP)";
+
+        let inserted = state
+            .with_transaction(|tx| {
+                Box::pin(async move {
+                    sqlx::query(insert)
+                        .bind("kept")
+                        .bind("Kept")
+                        .bind("kept@example.com")
+                        .execute(&mut **tx)
+                        .await?;
+                    Ok(1)
+                })
+            })
+            .await
+            .unwrap();
+        assert_eq!(inserted, 1);
+
+        let result: Result<(), AppError> = state
+            .with_transaction(|tx| {
+                Box::pin(async move {
+                    sqlx::query(insert)
+                        .bind("dropped")
+                        .bind("Dropped")
+                        .bind("dropped@example.com")
+                        .execute(&mut **tx)
+                        .await?;
+                    Err(AppError::ValidationError("abort".to_string()))
+                })
+            })
+            .await;
+        assert!(matches!(result, Err(AppError::ValidationError(_))));
+
+        let ids: Vec<String> = sqlx::query_scalar("SELECT id FROM users ORDER BY id")
+            .fetch_all(&state.db)
+            .await
+            .unwrap();
+        assert_eq!(ids, vec!["kept"]);
+    }
+
+    #[tokio::test]
+    async fn test_bounded_db_ops_caps_concurrency() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let state = new_test_db().await.with_app_config(AppConfig {
+            max_concurrent_db_ops: 3,
+            ..Default::default()
+        });
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_seen = Arc::new(AtomicUsize::new(0));
+
+        let results = state
+            .bounded_db_ops(0..20, |i| {
+                let in_flight = in_flight.clone();
+                let max_seen = max_seen.clone();
+                let db = state.db.clone();
+                async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_seen.fetch_max(current, Ordering::SeqCst);
+
+                    let (value,): (i64,) = sqlx::query_as("SELECT ?")
+                        .bind(i)
+                        .fetch_one(&db)
+                        .await?;
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    Ok(value)
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(results, (0..20).collect::<Vec<i64>>());
+        assert_eq!(max_seen.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_parse_max_page_size() {
+        assert_eq!(parse_max_page_size(" 500 "), Ok(500));
+        assert_eq!(parse_max_page_size("10000"), Ok(MAX_PAGE_SIZE_LIMIT));
+        assert!(parse_max_page_size("0").is_err());
+        assert!(parse_max_page_size("10001").is_err());
+        assert!(parse_max_page_size("lots").is_err());
+        assert_eq!(AppConfig::default().max_page_size, 100);
+    }
+
+    #[test]
+    fn test_parse_role_page_sizes() {
+        let sizes = parse_role_page_sizes("admin=50, internal = 25").unwrap();
+        assert_eq!(sizes.get("admin"), Some(&50));
+        assert_eq!(sizes.get("internal"), Some(&25));
+
+        assert!(parse_role_page_sizes("").unwrap().is_empty());
+        assert!(parse_role_page_sizes("admin").is_err());
+        assert!(parse_role_page_sizes("admin=0").is_err());
+    }
 }
@@ -0,0 +1,211 @@
+//! Purging of soft-deleted projects once their retention period has passed
+
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use std::time::Duration;
+use tokio::{sync::oneshot, task::JoinHandle};
+
+/// How long soft-deleted projects are kept (and restorable) by default: 30 days
+pub const DEFAULT_RETENTION: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// How often the purge task runs by default: hourly
+pub const DEFAULT_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Settings of the background purge of soft-deleted projects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionConfig {
+    /// Soft-deleted projects older than this are deleted for good
+    pub retention: Duration,
+    /// Time between two purges
+    pub interval: Duration,
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            retention: DEFAULT_RETENTION,
+            interval: DEFAULT_PURGE_INTERVAL,
+        }
+    }
+}
+
+/// Hard-deletes projects soft-deleted more than `retention` before `now`
+///
+/// Their technology, member and review rows are deleted in the same transaction,
+/// so the purge does not depend on `PRAGMA foreign_keys` for the cascade.
+/// Returns the number of projects removed.
+pub async fn purge_expired_projects(
+    db: &SqlitePool,
+    retention: Duration,
+    now: DateTime<Utc>,
+) -> Result<u64, sqlx::Error> {
+    let retention = chrono::Duration::from_std(retention).unwrap_or(chrono::Duration::MAX);
+    let Some(cutoff) = now.checked_sub_signed(retention) else {
+        return Ok(0);
+    };
+
+    let mut tx = db.begin().await?;
+    for table in ["project_technologies", "project_users", "reviews"] {
+        sqlx::query(&format!(
+            "DELETE FROM {} WHERE project_id IN
+             (SELECT id FROM projects WHERE deleted_at IS NOT NULL AND deleted_at < ?)",
+            table
+        ))
+        .bind(cutoff)
+        .execute(&mut *tx)
+        .await?;
+    }
+    let purged = sqlx::query("DELETE FROM projects WHERE deleted_at IS NOT NULL AND deleted_at < ?")
+        .bind(cutoff)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    tx.commit().await?;
+
+    Ok(purged)
+}
+
+/// Handle to the background task started by [`PurgeTask::spawn`]
+pub struct PurgeTask {
+    stop: oneshot::Sender<()>,
+    handle: JoinHandle<()>,
+}
+
+impl PurgeTask {
+    /// Purges expired projects now and then every `config.interval` until stopped
+    ///
+    /// A failed purge is logged and retried on the next tick.
+    pub fn spawn(db: SqlitePool, config: RetentionConfig) -> Self {
+        let (stop, mut stopped) = oneshot::channel();
+        let handle = tokio::spawn(async move {
+            let mut ticks = tokio::time::interval(config.interval);
+            loop {
+                tokio::select! {
+                    _ = &mut stopped => break,
+                    _ = ticks.tick() => {}
+                }
+                match purge_expired_projects(&db, config.retention, Utc::now()).await {
+                    Ok(0) => {}
+                    Ok(purged) => tracing::info!("Purged {} expired soft-deleted projects", purged),
+                    Err(e) => tracing::error!("Failed to purge expired soft-deleted projects: {}", e),
+                }
+            }
+        });
+        Self { stop, handle }
+    }
+
+    /// Stops the task, waiting for a purge in progress to finish
+    pub async fn shutdown(self) {
+        let _ = self.stop.send(());
+        if let Err(e) = self.handle.await {
+            tracing::error!("Purge task failed: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::tests::new_test_db;
+    use uuid::Uuid;
+
+    async fn insert_project(db: &SqlitePool, name: &str, deleted_at: Option<DateTime<Utc>>) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO projects (id, name, description, repository_url, language, created_at, updated_at, deleted_at)
+             VALUES (?, ?, '', 'https://github.com/test/retention', 'Rust', ?, ?, ?)",
+        )
+        .bind(id.to_string())
+        .bind(name)
+        .bind(Utc::now())
+        .bind(Utc::now())
+        .bind(deleted_at)
+        .execute(db)
+        .await
+        .unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn test_purge_removes_only_expired_projects() {
+        let db = new_test_db().await.db;
+        let retention = Duration::from_secs(7 * 24 * 60 * 60);
+        let deleted = Utc::now();
+
+        let old = insert_project(&db, "Old", Some(deleted - chrono::Duration::days(10))).await;
+        let recent = insert_project(&db, "Recent", Some(deleted)).await;
+        let live = insert_project(&db, "Live", None).await;
+
+        let technology = Uuid::new_v4();
+        sqlx::query("INSERT INTO technologies (id, name, created_at) VALUES (?, 'Rust', ?)")
+            .bind(technology.to_string())
+            .bind(Utc::now())
+            .execute(&db)
+            .await
+            .unwrap();
+        for project in [old, recent] {
+            sqlx::query("INSERT INTO project_technologies (project_id, technology_id, created_at) VALUES (?, ?, ?)")
+                .bind(project.to_string())
+                .bind(technology.to_string())
+                .bind(Utc::now())
+                .execute(&db)
+                .await
+                .unwrap();
+        }
+
+        // Only the project deleted ten days ago is past the one-week retention
+        let purged = purge_expired_projects(&db, retention, Utc::now()).await.unwrap();
+        assert_eq!(purged, 1);
+
+        let remaining: Vec<String> = sqlx::query_scalar("SELECT id FROM projects ORDER BY name")
+            .fetch_all(&db)
+            .await
+            .unwrap();
+        assert_eq!(remaining, vec![live.to_string(), recent.to_string()]);
+        let links: Vec<String> = sqlx::query_scalar("SELECT project_id FROM project_technologies")
+            .fetch_all(&db)
+            .await
+            .unwrap();
+        assert_eq!(links, vec![recent.to_string()]);
+
+        // Once the clock moves past its retention too, the recent one goes as well
+        let later = deleted + chrono::Duration::days(8);
+        let purged = purge_expired_projects(&db, retention, later).await.unwrap();
+        assert_eq!(purged, 1);
+        let remaining: Vec<String> = sqlx::query_scalar("SELECT id FROM projects")
+            .fetch_all(&db)
+            .await
+            .unwrap();
+        assert_eq!(remaining, vec![live.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_purge_task_stops_on_shutdown() {
+        let db = new_test_db().await.db;
+        insert_project(&db, "Expired", Some(Utc::now() - chrono::Duration::days(1))).await;
+
+        let task = PurgeTask::spawn(
+            db.clone(),
+            RetentionConfig {
+                retention: Duration::from_secs(60),
+                interval: Duration::from_secs(3600),
+            },
+        );
+        // The first tick fires immediately
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM projects").fetch_one(&db).await.unwrap();
+                if count == 0 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("expired project was not purged");
+
+        tokio::time::timeout(Duration::from_secs(5), task.shutdown())
+            .await
+            .expect("purge task did not stop");
+    }
+}